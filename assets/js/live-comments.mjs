@@ -0,0 +1,64 @@
+export class LiveCommentsElement extends HTMLElement {
+    connectedCallback() {
+        if (!window.EventSource || this._source) {
+            return;
+        }
+        this._source = new EventSource(this.getAttribute("src"));
+        this._source.addEventListener("comment", this._commentEvent.bind(this));
+    }
+    disconnectedCallback() {
+        if (this._source) {
+            this._source.close();
+            this._source = null;
+        }
+    }
+    _commentEvent(e) {
+        // The comment might already be here, if this page was loaded after it was posted.
+        if (document.getElementById(e.lastEventId)) {
+            return;
+        }
+        const template = document.createElement("template");
+        template.innerHTML = e.data;
+        const comment = template.content.firstElementChild;
+        const parent = comment && comment.dataset.parent && document.getElementById(comment.dataset.parent);
+        // A reply to a comment that isn't on this page goes at the end, like any other new comment.
+        if (!parent) {
+            this.appendChild(template.content);
+            return;
+        }
+        // Like comment_thread.rs, replies past the deepest level go alongside their parent.
+        if (depth(parent) >= MAX_DEPTH) {
+            parent.parentElement.appendChild(template.content);
+        } else {
+            let replies = parent.nextElementSibling;
+            if (!replies || !replies.matches("details.comment-replies")) {
+                replies = document.createElement("details");
+                replies.className = "comment-replies";
+                replies.open = true;
+                replies.appendChild(document.createElement("summary"));
+                parent.after(replies);
+            }
+            replies.appendChild(template.content);
+        }
+        for (let replies = comment.closest("details.comment-replies"); replies; replies = replies.parentElement.closest("details.comment-replies")) {
+            const count = replies.querySelectorAll("aside[id]").length;
+            replies.querySelector("summary").textContent = count + (count === 1 ? " reply" : " replies");
+        }
+    }
+}
+
+// The same as MAX_DEPTH in comment_thread.rs.
+const MAX_DEPTH = 6;
+
+function depth(comment) {
+    let depth = 0;
+    for (let replies = comment.closest("details.comment-replies"); replies; replies = replies.parentElement.closest("details.comment-replies")) {
+        depth += 1;
+    }
+    return depth;
+}
+
+if (!window.customElements.get('live-comments')) {
+    window.LiveCommentsElement = LiveCommentsElement;
+    window.customElements.define('live-comments', LiveCommentsElement);
+}
@@ -7,6 +7,7 @@ import SmartPunctElement from './smart-punct.mjs'
 import ImgLightbox from './img-lightbox.mjs'
 import SubscriptionsMenuElement from './subscriptions-menu.mjs'
 import TagsTypeaheadElement from './tags-typeahead.mjs'
+import LiveCommentsElement from './live-comments.mjs'
 
 if (window.devicePixelRatio && devicePixelRatio >= 2) {
   var testElem = document.createElement('div');
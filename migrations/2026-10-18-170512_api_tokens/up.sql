@@ -0,0 +1,13 @@
+CREATE TABLE api_tokens (
+  uuid BIGINT PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  name VARCHAR NOT NULL,
+  token_hash BYTEA NOT NULL,
+  scope_read BOOL NOT NULL DEFAULT 't',
+  scope_vote BOOL NOT NULL DEFAULT 'f',
+  scope_post BOOL NOT NULL DEFAULT 'f',
+  scope_moderate BOOL NOT NULL DEFAULT 'f',
+  created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+  last_used_at TIMESTAMP
+);
+CREATE INDEX idx_api_tokens_user ON api_tokens (user_id);
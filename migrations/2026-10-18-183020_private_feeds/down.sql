@@ -0,0 +1 @@
+DROP TABLE private_feed_tokens;
@@ -0,0 +1,5 @@
+CREATE TABLE private_feed_tokens (
+  uuid BIGINT PRIMARY KEY,
+  user_id INTEGER NOT NULL UNIQUE REFERENCES users(id),
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,3 @@
+DROP TABLE pending_logins;
+DROP TABLE totp_recovery_codes;
+DROP TABLE user_totp;
@@ -0,0 +1,18 @@
+CREATE TABLE user_totp (
+  user_id INTEGER PRIMARY KEY REFERENCES users(id),
+  secret BYTEA NOT NULL,
+  enabled BOOL NOT NULL DEFAULT 'f',
+  last_used_step BIGINT,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE TABLE totp_recovery_codes (
+  id SERIAL PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  code_hash BYTEA NOT NULL
+);
+CREATE INDEX idx_totp_recovery_codes_user ON totp_recovery_codes (user_id);
+CREATE TABLE pending_logins (
+  uuid BIGINT PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+DROP INDEX idx_user_sessions_user;
+ALTER TABLE user_sessions DROP COLUMN name;
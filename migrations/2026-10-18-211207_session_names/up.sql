@@ -0,0 +1,3 @@
+ALTER TABLE user_sessions
+  ADD COLUMN name VARCHAR;
+CREATE INDEX idx_user_sessions_user ON user_sessions (user_id, last_seen_at);
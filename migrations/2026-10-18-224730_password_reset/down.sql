@@ -0,0 +1,4 @@
+DROP TABLE email_verification_tokens;
+DROP TABLE password_reset_tokens;
+DROP INDEX idx_users_email;
+ALTER TABLE users DROP COLUMN email;
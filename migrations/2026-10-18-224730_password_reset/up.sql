@@ -0,0 +1,15 @@
+ALTER TABLE users ADD COLUMN email VARCHAR;
+CREATE UNIQUE INDEX idx_users_email ON users (email);
+CREATE TABLE password_reset_tokens (
+  token_hash BYTEA PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_password_reset_tokens_user ON password_reset_tokens (user_id);
+CREATE TABLE email_verification_tokens (
+  token_hash BYTEA PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  email VARCHAR NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_email_verification_tokens_user ON email_verification_tokens (user_id);
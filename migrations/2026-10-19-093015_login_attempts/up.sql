@@ -0,0 +1,12 @@
+CREATE TABLE login_attempts (
+  id SERIAL PRIMARY KEY,
+  kind VARCHAR NOT NULL,
+  username VARCHAR NOT NULL,
+  user_id INTEGER REFERENCES users(id),
+  ip_address VARCHAR NOT NULL,
+  succeeded BOOL NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_login_attempts_username ON login_attempts (username, created_at);
+CREATE INDEX idx_login_attempts_ip_address ON login_attempts (ip_address, created_at);
+CREATE INDEX idx_login_attempts_created_at ON login_attempts (created_at);
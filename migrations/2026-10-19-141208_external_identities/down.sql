@@ -0,0 +1,2 @@
+DROP TABLE pending_oidc_logins;
+DROP TABLE external_identities;
@@ -0,0 +1,17 @@
+CREATE TABLE external_identities (
+  id SERIAL PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  issuer VARCHAR NOT NULL,
+  subject VARCHAR NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+  UNIQUE (issuer, subject)
+);
+CREATE INDEX idx_external_identities_user ON external_identities (user_id);
+CREATE TABLE pending_oidc_logins (
+  state VARCHAR PRIMARY KEY,
+  nonce VARCHAR NOT NULL,
+  code_verifier VARCHAR NOT NULL,
+  invite_token BIGINT,
+  link_user_id INTEGER REFERENCES users(id),
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,5 @@
+DROP INDEX idx_comments_created_at;
+DROP TRIGGER add_comment_index_insert ON comments;
+DROP TRIGGER add_comment_index_update ON comments;
+DROP FUNCTION add_comment_index();
+DROP TABLE comment_search_index;
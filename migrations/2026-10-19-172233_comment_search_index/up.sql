@@ -0,0 +1,24 @@
+CREATE TABLE comment_search_index
+(
+  comment_id INTEGER PRIMARY KEY REFERENCES comments(id),
+  search_index TSVECTOR NOT NULL
+);
+
+CREATE FUNCTION add_comment_index() RETURNS trigger AS $emp_stamp$
+BEGIN
+  DELETE FROM comment_search_index WHERE comment_id = NEW.id;
+  INSERT INTO comment_search_index (comment_id, search_index)
+    VALUES (NEW.id, to_tsvector(coalesce(NEW.text,'')));
+  RETURN NEW;
+END;
+$emp_stamp$ LANGUAGE plpgsql;
+
+CREATE TRIGGER add_comment_index_insert AFTER INSERT ON comments FOR EACH ROW EXECUTE PROCEDURE add_comment_index();
+CREATE TRIGGER add_comment_index_update AFTER UPDATE OF text ON comments FOR EACH ROW EXECUTE PROCEDURE add_comment_index();
+
+INSERT INTO comment_search_index (comment_id, search_index)
+SELECT id, to_tsvector(coalesce(text,''))
+FROM comments;
+
+CREATE INDEX comment_search_index_gin_idx ON comment_search_index USING gin(search_index);
+CREATE INDEX idx_comments_created_at ON comments (created_at);
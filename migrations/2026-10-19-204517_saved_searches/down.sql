@@ -0,0 +1,2 @@
+ALTER TABLE notifications DROP COLUMN saved_search_id;
+DROP TABLE saved_searches;
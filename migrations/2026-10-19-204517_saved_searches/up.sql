@@ -0,0 +1,16 @@
+CREATE TABLE saved_searches (
+  id SERIAL PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  name VARCHAR NOT NULL,
+  search_params VARCHAR NOT NULL,
+  keywords VARCHAR NOT NULL DEFAULT '',
+  title VARCHAR NOT NULL DEFAULT '',
+  and_tags INTEGER[] NOT NULL DEFAULT '{}',
+  or_tags INTEGER[] NOT NULL DEFAULT '{}',
+  hide_tags INTEGER[] NOT NULL DEFAULT '{}',
+  or_domains INTEGER[] NOT NULL DEFAULT '{}',
+  for_user_id INTEGER NOT NULL DEFAULT 0,
+  created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_saved_searches_user ON saved_searches (user_id);
+ALTER TABLE notifications ADD COLUMN saved_search_id INTEGER REFERENCES saved_searches(id) ON DELETE CASCADE;
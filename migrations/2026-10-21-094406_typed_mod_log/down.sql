@@ -0,0 +1,4 @@
+DROP INDEX idx_moderation_target_user_id;
+DROP INDEX idx_moderation_action;
+ALTER TABLE moderation DROP COLUMN target_user_id;
+ALTER TABLE moderation DROP COLUMN action;
@@ -0,0 +1,22 @@
+ALTER TABLE moderation ADD COLUMN action VARCHAR NOT NULL DEFAULT '';
+ALTER TABLE moderation ADD COLUMN target_user_id INTEGER REFERENCES users(id);
+UPDATE moderation SET action = COALESCE(payload->>'type', '');
+
+-- Older entries only name the post by its UUID, written the way it appears in URLs.
+CREATE FUNCTION pg_temp.decode_base32(encoded TEXT) RETURNS BIGINT AS $$
+  SELECT CASE WHEN n >= 9223372036854775808 THEN n - 18446744073709551616 ELSE n END::BIGINT
+  FROM (
+    SELECT COALESCE(SUM((STRPOS('23456789BCDFGHJKLMNPQRSTVWXYZ_$.', SUBSTR(digits, i, 1)) - 1)::NUMERIC * POWER(32::NUMERIC, i - 1)), 0) AS n
+    FROM (SELECT TRANSLATE(UPPER(encoded), '0OE- ,', '..$__.') AS digits) AS d, GENERATE_SERIES(1, LENGTH(digits)) AS i
+  ) AS decoded
+$$ LANGUAGE SQL IMMUTABLE;
+
+UPDATE moderation SET target_user_id = comments.created_by
+FROM comments
+WHERE moderation.payload ? 'comment_id' AND comments.id = (moderation.payload->>'comment_id')::INTEGER;
+UPDATE moderation SET target_user_id = posts.submitted_by
+FROM posts
+WHERE NOT moderation.payload ? 'comment_id' AND moderation.payload ? 'post_uuid' AND posts.uuid = pg_temp.decode_base32(moderation.payload->>'post_uuid');
+
+CREATE INDEX idx_moderation_action ON moderation (action);
+CREATE INDEX idx_moderation_target_user_id ON moderation (target_user_id);
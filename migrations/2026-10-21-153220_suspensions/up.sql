@@ -0,0 +1,11 @@
+CREATE TABLE suspensions (
+  id SERIAL PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users(id),
+  starts_at TIMESTAMP NOT NULL DEFAULT NOW(),
+  ends_at TIMESTAMP NOT NULL,
+  reason VARCHAR NOT NULL,
+  created_by INTEGER NOT NULL REFERENCES users(id),
+  lifted_at TIMESTAMP,
+  lifted_by INTEGER REFERENCES users(id)
+);
+CREATE INDEX idx_suspensions_user_id ON suspensions (user_id, ends_at);
@@ -0,0 +1,4 @@
+ALTER TABLE flags DROP COLUMN reason;
+ALTER TABLE flags DROP COLUMN note;
+ALTER TABLE comment_flags DROP COLUMN reason;
+ALTER TABLE comment_flags DROP COLUMN note;
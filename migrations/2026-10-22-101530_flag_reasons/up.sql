@@ -0,0 +1,4 @@
+ALTER TABLE flags ADD COLUMN reason VARCHAR NOT NULL DEFAULT 'other';
+ALTER TABLE flags ADD COLUMN note VARCHAR;
+ALTER TABLE comment_flags ADD COLUMN reason VARCHAR NOT NULL DEFAULT 'other';
+ALTER TABLE comment_flags ADD COLUMN note VARCHAR;
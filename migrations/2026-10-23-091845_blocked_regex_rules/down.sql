@@ -0,0 +1,7 @@
+DROP TABLE blocked_regex_generation;
+DELETE FROM blocked_regexes a USING blocked_regexes b WHERE a.regex = b.regex AND a.id > b.id;
+ALTER TABLE blocked_regexes DROP COLUMN scope;
+ALTER TABLE blocked_regexes DROP COLUMN action;
+ALTER TABLE blocked_regexes DROP COLUMN message;
+ALTER TABLE blocked_regexes DROP COLUMN hits;
+ALTER TABLE blocked_regexes DROP COLUMN last_hit_at;
@@ -0,0 +1,13 @@
+ALTER TABLE blocked_regexes ADD COLUMN scope VARCHAR NOT NULL DEFAULT 'excerpt';
+ALTER TABLE blocked_regexes ADD COLUMN action VARCHAR NOT NULL DEFAULT 'shadowban';
+ALTER TABLE blocked_regexes ADD COLUMN message VARCHAR NOT NULL DEFAULT '';
+ALTER TABLE blocked_regexes ADD COLUMN hits INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE blocked_regexes ADD COLUMN last_hit_at TIMESTAMP;
+-- Every old rule looked at both post excerpts and comments, so each one becomes two rules.
+-- A comment match used to drop the user to -2 instead of -3, so those rules lock instead of shadowbanning.
+INSERT INTO blocked_regexes (regex, scope, action) SELECT regex, 'comment', 'lock' FROM blocked_regexes;
+ALTER TABLE blocked_regexes ALTER COLUMN scope DROP DEFAULT;
+ALTER TABLE blocked_regexes ALTER COLUMN action DROP DEFAULT;
+-- Bumped along with every change to the rules, so each server process knows when to load them again.
+CREATE TABLE blocked_regex_generation (generation BIGINT NOT NULL);
+INSERT INTO blocked_regex_generation (generation) VALUES (0);
@@ -0,0 +1,139 @@
+/*!
+The JSON API lives under `/api/v1`. It mirrors the HTML listing routes, and accepts the same
+query parameters they do. Ranked listings (hottest, top, random, and anything with keywords)
+paginate with `page`, while chronological listings paginate with an `after` cursor;
+every response says which one to use next.
+
+The routes themselves sit next to the HTML ones in main.rs. This is what they send back.
+*/
+
+use crate::models::{CommentInfo, CommentSearch, CommentSearchOrderBy, CommentSearchResult, PostInfo, PostSearch, PostSearchOrderBy, User};
+use more_interesting_base32::Base32;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Posts {
+    pub posts: Vec<PostInfo>,
+    pub next_after: Option<Base32>,
+    pub next_page: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct Comments {
+    pub comments: Vec<CommentSearchResult>,
+    pub next_after: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct PostWithComments {
+    pub post: PostInfo,
+    pub comments: Vec<CommentInfo>,
+}
+
+/// Anonymous posts still carry their submitter, since moderators need to see it.
+/// Everybody else gets it blanked out, just like the HTML does.
+pub fn scrub_post(user: &User, mut post: PostInfo) -> PostInfo {
+    if post.anon && user.trust_level < 3 {
+        post.submitted_by = 0;
+        post.submitted_by_username = String::new();
+        post.submitted_by_username_urlencode = String::new();
+    }
+    post
+}
+
+/// Where the next page of posts starts, given the last post on this one: either an `after` cursor
+/// or a `page` number, never both. Neither, if this page was empty.
+pub fn next_posts(search: &PostSearch, last: Option<Base32>) -> (Option<Base32>, Option<i32>) {
+    let uses_cursor = search.keywords == "" && search.order_by == PostSearchOrderBy::Newest;
+    match last {
+        None => (None, None),
+        Some(uuid) if uses_cursor => (Some(uuid), None),
+        Some(_) => (None, Some(search.search_page + 1)),
+    }
+}
+
+/// Ranked comment results are paged with `page`, so there's no cursor to hand out.
+pub fn next_comments(search: &CommentSearch, last: Option<i32>) -> Option<i32> {
+    if search.order_by == CommentSearchOrderBy::Newest || search.keywords == "" {
+        last
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+    fn post(anon: bool) -> PostInfo {
+        PostInfo {
+            id: 1,
+            uuid: Base32::from(1u64),
+            title: String::from("title"),
+            title_html: String::from("title"),
+            url: None,
+            visible: true,
+            private: false,
+            hotness: 0.0,
+            stellar_age: 0,
+            score: 0,
+            comment_count: 0,
+            blog_post: false,
+            created_at: NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0),
+            created_at_relative: String::new(),
+            submitted_by: 7,
+            submitted_by_username: String::from("someone"),
+            submitted_by_username_urlencode: String::from("someone"),
+            starred_by_me: false,
+            flagged_by_me: false,
+            hidden_by_me: false,
+            comment_readpoint: None,
+            excerpt_html: None,
+            headline_html: None,
+            banner_title: None,
+            banner_desc: None,
+            noindex: false,
+            locked: false,
+            anon,
+        }
+    }
+    fn user(trust_level: i32) -> User {
+        User { trust_level, ..User::default() }
+    }
+    #[test]
+    fn anon_submitter_is_hidden() {
+        let scrubbed = scrub_post(&user(2), post(true));
+        assert_eq!(scrubbed.submitted_by, 0);
+        assert_eq!(scrubbed.submitted_by_username, "");
+        assert_eq!(scrubbed.submitted_by_username_urlencode, "");
+    }
+    #[test]
+    fn moderators_see_anon_submitter() {
+        assert_eq!(scrub_post(&user(3), post(true)).submitted_by_username, "someone");
+        assert_eq!(scrub_post(&user(0), post(false)).submitted_by_username, "someone");
+    }
+    #[test]
+    fn newest_posts_use_cursor() {
+        let search = PostSearch { order_by: PostSearchOrderBy::Newest, ..PostSearch::with_my_user_id(0) };
+        let last = Base32::from(5u64);
+        assert_eq!(next_posts(&search, Some(last)), (Some(last), None));
+        assert_eq!(next_posts(&search, None), (None, None));
+    }
+    #[test]
+    fn ranked_posts_use_page() {
+        let last = Some(Base32::from(5u64));
+        let hottest = PostSearch { search_page: 2, ..PostSearch::with_my_user_id(0) };
+        assert_eq!(next_posts(&hottest, last), (None, Some(3)));
+        let keywords = PostSearch { order_by: PostSearchOrderBy::Newest, keywords: String::from("foo"), ..PostSearch::with_my_user_id(0) };
+        assert_eq!(next_posts(&keywords, last), (None, Some(1)));
+    }
+    #[test]
+    fn comment_cursor() {
+        let newest = CommentSearch::with_my_user_id(0);
+        assert_eq!(next_comments(&newest, Some(9)), Some(9));
+        let relevance = CommentSearch { order_by: CommentSearchOrderBy::Relevance, keywords: String::from("foo"), ..CommentSearch::with_my_user_id(0) };
+        assert_eq!(next_comments(&relevance, Some(9)), None);
+        let relevance_without_keywords = CommentSearch { order_by: CommentSearchOrderBy::Relevance, ..CommentSearch::with_my_user_id(0) };
+        assert_eq!(next_comments(&relevance_without_keywords, Some(9)), Some(9));
+    }
+}
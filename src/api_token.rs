@@ -0,0 +1,57 @@
+/*!
+Personal API tokens look like `<uuid>-<secret>`, where the uuid is the token's primary key and the
+secret comes from `password::generate_token`.
+
+Passwords need a slow hash because people pick guessable ones. These secrets are long and random,
+so a plain SHA-256 is just as hard to reverse, and it's cheap enough to check on every request.
+*/
+
+use crate::password::token_hash;
+use more_interesting_base32::Base32;
+use ring::constant_time::verify_slices_are_equal;
+
+/// What the user gets to copy, and later sends in an `Authorization: Bearer` header.
+pub fn token_string(uuid: Base32, secret: &str) -> String {
+    format!("{}-{}", uuid, secret)
+}
+
+/// Split a token string into the uuid to look up, and the secret to check.
+pub fn parse(token_string: &str) -> Option<(Base32, &str)> {
+    let mut split = token_string.splitn(2, '-');
+    let uuid: Base32 = split.next()?.parse().ok()?;
+    let secret = split.next().filter(|secret| !secret.is_empty())?;
+    Some((uuid, secret))
+}
+
+pub fn verify_secret(secret: &str, stored_hash: &[u8]) -> bool {
+    verify_slices_are_equal(&token_hash(secret), stored_hash).is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::password::generate_token;
+    #[test]
+    fn round_trip() {
+        let uuid = Base32::from(12345u64);
+        let secret = generate_token();
+        assert_eq!(secret.len(), 64);
+        let token = token_string(uuid, &secret);
+        assert_eq!(parse(&token), Some((uuid, &secret[..])));
+    }
+    #[test]
+    fn malformed() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("not base32!-abc"), None);
+        assert_eq!(parse(&Base32::from(1u64).to_string()), None);
+        assert_eq!(parse(&format!("{}-", Base32::from(1u64))), None);
+    }
+    #[test]
+    fn verify() {
+        let secret = generate_token();
+        let stored_hash = token_hash(&secret);
+        assert!(verify_secret(&secret, &stored_hash));
+        assert!(!verify_secret(&generate_token(), &stored_hash));
+        assert!(!verify_secret(&secret, &stored_hash[1..]));
+    }
+}
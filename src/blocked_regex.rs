@@ -0,0 +1,239 @@
+/*!
+Rules that catch spam by matching a regex against something people submit.
+
+Each rule looks at one part of a submission, its scope, and says what to do when it matches.
+If more than one rule matches, the strictest action wins. Shadowbanning is stricter than
+rejecting, because a rejection tells the spammer to try something else.
+
+Compiling every rule on every request adds up, so the rules for each scope are compiled into
+one `RegexSet` and cached. Every change to the rules bumps a generation number in the database,
+in the same transaction, and the cache is only used while that number matches, so a change
+made through one server process reaches all of them.
+*/
+
+use lazy_static::lazy_static;
+use regex::RegexSet;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Scope {
+    Title,
+    Excerpt,
+    Url,
+    Comment,
+    Username,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 5] = [Scope::Title, Scope::Excerpt, Scope::Url, Scope::Comment, Scope::Username];
+    /// What goes in the database and the form.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scope::Title => "title",
+            Scope::Excerpt => "excerpt",
+            Scope::Url => "url",
+            Scope::Comment => "comment",
+            Scope::Username => "username",
+        }
+    }
+    pub fn label(self) -> &'static str {
+        match self {
+            Scope::Title => "Post title",
+            Scope::Excerpt => "Post excerpt",
+            Scope::Url => "Post URL",
+            Scope::Comment => "Comment",
+            Scope::Username => "Username at signup",
+        }
+    }
+    pub fn parse(s: &str) -> Option<Scope> {
+        Scope::ALL.iter().cloned().find(|scope| scope.as_str() == s)
+    }
+}
+
+/// Declared from least to most strict.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Action {
+    /// Hide it until a moderator approves it. For a username, the account starts out locked.
+    Queue,
+    /// Turn it away, and tell them why.
+    Reject,
+    /// Drop the user's trust level to -2, which locks their account.
+    Lock,
+    /// Drop the user's trust level to -3.
+    Shadowban,
+}
+
+impl Action {
+    pub const ALL: [Action; 4] = [Action::Reject, Action::Queue, Action::Lock, Action::Shadowban];
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::Queue => "queue",
+            Action::Reject => "reject",
+            Action::Lock => "lock",
+            Action::Shadowban => "shadowban",
+        }
+    }
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::Queue => "Send to mod queue",
+            Action::Reject => "Reject with message",
+            Action::Lock => "Lock account",
+            Action::Shadowban => "Shadowban",
+        }
+    }
+    pub fn parse(s: &str) -> Option<Action> {
+        Action::ALL.iter().cloned().find(|action| action.as_str() == s)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    pub id: i32,
+    pub regex: String,
+    pub scope: Scope,
+    pub action: Action,
+    /// Shown to the user when the action is `Reject`.
+    pub message: String,
+}
+
+/// What to do about a submission, and which rules said so.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Verdict {
+    pub action: Action,
+    pub message: String,
+    pub rule_ids: Vec<i32>,
+}
+
+impl Verdict {
+    /// Combine the verdicts for different parts of the same submission.
+    pub fn and(self, mut other: Verdict) -> Verdict {
+        other.rule_ids.extend(self.rule_ids.iter().cloned());
+        if self.action >= other.action {
+            Verdict { rule_ids: other.rule_ids, ..self }
+        } else {
+            other
+        }
+    }
+}
+
+/// Used when a rejecting rule doesn't have a message of its own.
+pub const DEFAULT_MESSAGE: &str = "This looks like spam, so it wasn't posted";
+
+#[derive(Debug, Default)]
+pub struct RuleSet {
+    /// One entry for each scope that has rules, holding the set and the rule behind each pattern.
+    scopes: Vec<(Scope, RegexSet, Vec<Rule>)>,
+}
+
+impl RuleSet {
+    /// Rules that don't compile are left out, instead of breaking every other rule.
+    pub fn new(rules: Vec<Rule>) -> RuleSet {
+        let mut scopes = Vec::new();
+        for &scope in Scope::ALL.iter() {
+            let rules: Vec<Rule> = rules.iter()
+                .filter(|rule| rule.scope == scope && RegexSet::new([&rule.regex]).is_ok())
+                .cloned()
+                .collect();
+            if rules.is_empty() {
+                continue;
+            }
+            let set = RegexSet::new(rules.iter().map(|rule| &rule.regex)).expect("each of these compiled by itself");
+            scopes.push((scope, set, rules));
+        }
+        RuleSet { scopes }
+    }
+    pub fn check(&self, scope: Scope, text: &str) -> Option<Verdict> {
+        let (_, set, rules) = self.scopes.iter().find(|(s, _, _)| *s == scope)?;
+        let matched: Vec<&Rule> = set.matches(text).into_iter().map(|i| &rules[i]).collect();
+        let strictest = matched.iter().max_by_key(|rule| rule.action)?;
+        let message = if strictest.message.is_empty() { DEFAULT_MESSAGE.to_owned() } else { strictest.message.clone() };
+        Some(Verdict {
+            action: strictest.action,
+            message,
+            rule_ids: matched.iter().map(|rule| rule.id).collect(),
+        })
+    }
+}
+
+lazy_static! {
+    static ref CACHE: RwLock<Option<(i64, Arc<RuleSet>)>> = RwLock::new(None);
+}
+
+/// The compiled rules, if they were loaded at this generation.
+pub fn cached(generation: i64) -> Option<Arc<RuleSet>> {
+    let cache = CACHE.read().unwrap();
+    cache.as_ref().filter(|(cached_generation, _)| *cached_generation == generation).map(|(_, rules)| rules.clone())
+}
+
+/// Read the generation before loading the rules, and pass it here.
+///
+/// If the rules change in between, the rules loaded might be newer than the generation,
+/// but never older, so the worst that happens is they get loaded again next time.
+pub fn store(generation: i64, rules: Arc<RuleSet>) {
+    *CACHE.write().unwrap() = Some((generation, rules));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn rule(id: i32, regex: &str, scope: Scope, action: Action) -> Rule {
+        Rule { id, regex: regex.to_owned(), scope, action, message: String::new() }
+    }
+    #[test]
+    fn round_trip() {
+        for &scope in Scope::ALL.iter() {
+            assert_eq!(Scope::parse(scope.as_str()), Some(scope));
+        }
+        for &action in Action::ALL.iter() {
+            assert_eq!(Action::parse(action.as_str()), Some(action));
+        }
+        assert_eq!(Scope::parse("everything"), None);
+    }
+    #[test]
+    fn only_checks_its_scope() {
+        let rules = RuleSet::new(vec![rule(1, "casino", Scope::Comment, Action::Queue)]);
+        assert_eq!(rules.check(Scope::Comment, "best casino online").map(|v| v.rule_ids), Some(vec![1]));
+        assert_eq!(rules.check(Scope::Title, "best casino online"), None);
+        assert_eq!(rules.check(Scope::Comment, "nothing to see"), None);
+    }
+    #[test]
+    fn strictest_wins() {
+        let mut rules = vec![
+            rule(1, "buy", Scope::Excerpt, Action::Queue),
+            rule(2, "pills", Scope::Excerpt, Action::Reject),
+            rule(3, "[", Scope::Excerpt, Action::Shadowban),
+        ];
+        rules[1].message = String::from("No pills");
+        let rules = RuleSet::new(rules);
+        let verdict = rules.check(Scope::Excerpt, "buy pills").unwrap();
+        assert_eq!(verdict.action, Action::Reject);
+        assert_eq!(verdict.message, "No pills");
+        assert_eq!(verdict.rule_ids, vec![1, 2]);
+        assert_eq!(rules.check(Scope::Excerpt, "buy").unwrap().message, DEFAULT_MESSAGE);
+    }
+    #[test]
+    fn cache_generation() {
+        let rules = Arc::new(RuleSet::new(vec![rule(1, "casino", Scope::Comment, Action::Queue)]));
+        store(5, rules);
+        assert!(cached(5).unwrap().check(Scope::Comment, "casino").is_some());
+        // Another process changed the rules.
+        assert!(cached(6).is_none());
+        store(6, Arc::new(RuleSet::default()));
+        assert!(cached(6).unwrap().check(Scope::Comment, "casino").is_none());
+        assert!(cached(5).is_none());
+    }
+    #[test]
+    fn lock_is_between_reject_and_shadowban() {
+        assert!(Action::Lock > Action::Reject);
+        assert!(Action::Lock < Action::Shadowban);
+    }
+    #[test]
+    fn combine_verdicts() {
+        let queue = Verdict { action: Action::Queue, message: String::from("a"), rule_ids: vec![1] };
+        let shadowban = Verdict { action: Action::Shadowban, message: String::from("b"), rule_ids: vec![2] };
+        let combined = queue.clone().and(shadowban.clone());
+        assert_eq!(combined.action, Action::Shadowban);
+        assert_eq!(combined.rule_ids, vec![2, 1]);
+        assert_eq!(shadowban.and(queue).action, Action::Shadowban);
+    }
+}
@@ -0,0 +1,94 @@
+/*!
+Find the address a request really came from, for throttling and the failed login log.
+
+Behind a reverse proxy, every connection comes from the proxy, and the address it got the request
+from is at the end of `X-Forwarded-For`. Anybody can send that header, so it only counts when the
+connection comes from one of the `trusted_proxy` addresses. Each trusted proxy appends its own hop,
+so the right-most address that isn't a trusted proxy is the client; anything left of that was
+made up by whoever sent the request.
+*/
+
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::request::FromRequest;
+use rocket::Request;
+use std::net::IpAddr;
+use crate::SiteConfig;
+
+/// Parse the `trusted_proxy` setting: addresses separated by spaces or commas.
+pub fn parse_trusted_proxies(setting: &str) -> Result<Vec<IpAddr>, String> {
+    setting.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|address| !address.is_empty())
+        .map(|address| address.parse().map_err(|_| format!("trusted_proxy: {:?} is not an IP address", address)))
+        .collect()
+}
+
+pub fn client_ip(remote: Option<IpAddr>, forwarded_for: &[&str], trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let mut ip = remote?;
+    let hops = forwarded_for.iter().rev().flat_map(|header| header.rsplit(','));
+    for hop in hops {
+        if !trusted_proxies.contains(&ip) {
+            break;
+        }
+        match hop.trim().parse() {
+            Ok(hop) => ip = hop,
+            // Past a hop that can't be read, the header can't be trusted, so stop at the proxy.
+            Err(_) => break,
+        }
+    }
+    Some(ip)
+}
+
+pub struct ClientIp {
+    pub ip: Option<IpAddr>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ClientIp {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<ClientIp, (Status, ()), ()> {
+        let trusted_proxies = request.rocket().state::<SiteConfig>()
+            .and_then(|config| parse_trusted_proxies(&config.trusted_proxy).ok())
+            .unwrap_or(Vec::new());
+        let forwarded_for: Vec<&str> = request.headers().get("x-forwarded-for").collect();
+        let remote = request.remote().map(|remote| remote.ip());
+        Outcome::Success(ClientIp { ip: client_ip(remote, &forwarded_for, &trusted_proxies) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+    #[test]
+    fn parse_setting() {
+        assert_eq!(parse_trusted_proxies(""), Ok(vec![]));
+        assert_eq!(parse_trusted_proxies("127.0.0.1, ::1 10.0.0.2"), Ok(vec![ip("127.0.0.1"), ip("::1"), ip("10.0.0.2")]));
+        assert!(parse_trusted_proxies("localhost").is_err());
+    }
+    #[test]
+    fn untrusted_remote_ignores_header() {
+        assert_eq!(client_ip(Some(ip("203.0.113.9")), &["198.51.100.1"], &[]), Some(ip("203.0.113.9")));
+        assert_eq!(client_ip(Some(ip("203.0.113.9")), &["198.51.100.1"], &[ip("127.0.0.1")]), Some(ip("203.0.113.9")));
+        assert_eq!(client_ip(None, &["198.51.100.1"], &[ip("127.0.0.1")]), None);
+    }
+    #[test]
+    fn rightmost_untrusted_hop() {
+        let trusted = [ip("127.0.0.1"), ip("10.0.0.2")];
+        assert_eq!(client_ip(Some(ip("127.0.0.1")), &["198.51.100.1"], &trusted), Some(ip("198.51.100.1")));
+        // The client made up the first address.
+        assert_eq!(client_ip(Some(ip("127.0.0.1")), &["1.2.3.4, 198.51.100.1"], &trusted), Some(ip("198.51.100.1")));
+        // Two proxies: a load balancer, then the one on localhost.
+        assert_eq!(client_ip(Some(ip("127.0.0.1")), &["1.2.3.4, 198.51.100.1, 10.0.0.2"], &trusted), Some(ip("198.51.100.1")));
+        assert_eq!(client_ip(Some(ip("127.0.0.1")), &["1.2.3.4", "198.51.100.1"], &trusted), Some(ip("198.51.100.1")));
+    }
+    #[test]
+    fn bad_header() {
+        let trusted = [ip("127.0.0.1")];
+        assert_eq!(client_ip(Some(ip("127.0.0.1")), &[], &trusted), Some(ip("127.0.0.1")));
+        assert_eq!(client_ip(Some(ip("127.0.0.1")), &["198.51.100.1, garbage"], &trusted), Some(ip("127.0.0.1")));
+    }
+}
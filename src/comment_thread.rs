@@ -0,0 +1,186 @@
+/*!
+Lay out a comment thread as nested replies.
+
+Comments are stored flat, each with an optional parent. This puts every reply right after its
+parent (and the parent's earlier replies), and works out how the template should open and close
+the collapsible reply boxes around them. Comments without a parent, including every comment
+from before replies had parents, stay at the top level in the order they came in, so an old
+thread lays out exactly the way it always did.
+
+Nesting stops at `MAX_DEPTH`; replies deeper than that are shown alongside their parent.
+
+Big threads are split into pages. A page never ends partway through a top-level comment's
+replies, so the reply boxes on every page are complete, and a reply is always on the same page
+as the comment it answers.
+*/
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+pub const MAX_DEPTH: usize = 6;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    /// Where this comment was in the list passed to `arrange`.
+    pub index: usize,
+    pub depth: usize,
+    /// Whether a reply box starts right after this comment.
+    pub opens_replies: bool,
+    /// How many comments go in that box, including replies to replies.
+    pub reply_count: usize,
+    /// How many reply boxes end right after this comment.
+    pub closes_replies: usize,
+}
+
+/// Takes `(id, parent_id)` pairs in the order they were posted.
+///
+/// A parent that isn't in the list, or that comes after its reply, is treated as missing.
+pub fn arrange(comments: &[(i32, Option<i32>)]) -> Vec<Position> {
+    arrange_by_key(comments, |index| index)
+}
+
+/// Like `arrange`, but the top-level comments are sorted by `key`, which gets each one's index.
+/// Replies always stay in the order they were posted.
+pub fn arrange_by_key<K: Ord, F: FnMut(usize) -> K>(comments: &[(i32, Option<i32>)], mut key: F) -> Vec<Position> {
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); comments.len()];
+    let mut roots = Vec::new();
+    let mut seen = HashMap::new();
+    for (index, &(id, parent_id)) in comments.iter().enumerate() {
+        let parent = parent_id.and_then(|parent_id| seen.get(&parent_id).cloned());
+        seen.insert(id, index);
+        match parent {
+            Some(parent) => children[parent].push(index),
+            None => roots.push(index),
+        }
+    }
+    roots.sort_by_cached_key(|&index| key(index));
+    let mut positions = Vec::with_capacity(comments.len());
+    let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|index| (index, 0)).collect();
+    while let Some((index, depth)) = stack.pop() {
+        let child_depth = (depth + 1).min(MAX_DEPTH);
+        stack.extend(children[index].iter().rev().map(|&child| (child, child_depth)));
+        positions.push(Position {
+            index,
+            depth,
+            opens_replies: !children[index].is_empty() && depth < MAX_DEPTH,
+            reply_count: 0,
+            closes_replies: 0,
+        });
+    }
+    let mut open: Vec<usize> = Vec::new();
+    for i in 0..positions.len() {
+        if positions[i].opens_replies {
+            open.push(i);
+        }
+        let next_depth = positions.get(i + 1).map(|next| next.depth).unwrap_or(0);
+        while open.len() > next_depth {
+            let opened = open.pop().unwrap();
+            positions[opened].reply_count = i - opened;
+            positions[i].closes_replies += 1;
+        }
+    }
+    positions
+}
+
+/// Split arranged comments into pages of about `page_size`.
+///
+/// A page only ends between top-level comments, so a long enough reply chain makes a longer page.
+/// There's always at least one page, even if it's empty.
+pub fn paginate(positions: &[Position], page_size: usize) -> Vec<Range<usize>> {
+    let mut pages = Vec::new();
+    let mut start = 0;
+    for (i, position) in positions.iter().enumerate() {
+        if position.depth == 0 && i - start >= page_size.max(1) {
+            pages.push(start..i);
+            start = i;
+        }
+    }
+    pages.push(start..positions.len());
+    pages
+}
+
+/// Where to move the reader's readpoint to after they've seen one page.
+///
+/// Pages aren't in id order once replies are threaded, so the newest comment on a page can have
+/// unread comments older than it on other pages. This only goes as far as every comment since the
+/// old readpoint has been seen, and returns `None` if that doesn't move it.
+pub fn advance_readpoint(readpoint: Option<i32>, all_ids: &[i32], seen_ids: &[i32]) -> Option<i32> {
+    let start = readpoint.unwrap_or(0);
+    let mut unread: Vec<i32> = all_ids.iter().cloned().filter(|&id| id > start).collect();
+    unread.sort();
+    unread.into_iter()
+        .take_while(|id| seen_ids.contains(id))
+        .last()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn layout(comments: &[(i32, Option<i32>)]) -> Vec<(i32, usize, usize, usize)> {
+        arrange(comments).into_iter()
+            .map(|p| (comments[p.index].0, p.depth, p.reply_count, p.closes_replies))
+            .collect()
+    }
+    #[test]
+    fn test_flat() {
+        let comments = &[(1, None), (2, None), (3, None)];
+        assert_eq!(layout(comments), vec![(1, 0, 0, 0), (2, 0, 0, 0), (3, 0, 0, 0)]);
+    }
+    #[test]
+    fn test_nested() {
+        let comments = &[(1, None), (2, None), (3, Some(1)), (4, Some(3)), (5, Some(1)), (6, Some(2))];
+        assert_eq!(layout(comments), vec![
+            (1, 0, 3, 0),
+            (3, 1, 1, 0),
+            (4, 2, 0, 1),
+            (5, 1, 0, 1),
+            (2, 0, 1, 0),
+            (6, 1, 0, 1),
+        ]);
+    }
+    #[test]
+    fn test_missing_parent() {
+        // 2's parent was deleted or hidden, and 4 claims a parent that came after it.
+        let comments = &[(1, None), (2, Some(99)), (4, Some(5)), (5, None)];
+        assert_eq!(layout(comments), vec![(1, 0, 0, 0), (2, 0, 0, 0), (4, 0, 0, 0), (5, 0, 0, 0)]);
+    }
+    #[test]
+    fn test_max_depth() {
+        let comments: Vec<(i32, Option<i32>)> = (0..10).map(|i| (i, if i == 0 { None } else { Some(i - 1) })).collect();
+        let positions = arrange(&comments);
+        assert_eq!(positions.iter().map(|p| p.depth).max(), Some(MAX_DEPTH));
+        let opened = positions.iter().filter(|p| p.opens_replies).count();
+        let closed: usize = positions.iter().map(|p| p.closes_replies).sum();
+        assert_eq!(opened, MAX_DEPTH);
+        assert_eq!(closed, MAX_DEPTH);
+        assert_eq!(positions[0].reply_count, 9);
+    }
+    #[test]
+    fn test_arrange_by_key() {
+        // Newest first, but the replies stay oldest first.
+        let comments = &[(1, None), (2, Some(1)), (3, None), (4, Some(1))];
+        let ids: Vec<i32> = arrange_by_key(comments, std::cmp::Reverse).into_iter().map(|p| comments[p.index].0).collect();
+        assert_eq!(ids, vec![3, 1, 2, 4]);
+    }
+    #[test]
+    fn test_advance_readpoint() {
+        let all = [1, 2, 3, 4, 5, 6];
+        // Oldest first, reading pages in order.
+        assert_eq!(advance_readpoint(None, &all, &[1, 2, 3]), Some(3));
+        assert_eq!(advance_readpoint(Some(3), &all, &[4, 5, 6]), Some(6));
+        // Page one has a new reply to an old comment; the comments between are still unread.
+        assert_eq!(advance_readpoint(Some(3), &all, &[1, 6, 2]), None);
+        assert_eq!(advance_readpoint(Some(3), &all, &[4, 6]), Some(4));
+        // Already read.
+        assert_eq!(advance_readpoint(Some(6), &all, &[5, 6]), None);
+        assert_eq!(advance_readpoint(None, &[], &[]), None);
+    }
+    #[test]
+    fn test_paginate() {
+        let comments = &[(1, None), (2, Some(1)), (3, Some(1)), (4, None), (5, None), (6, None)];
+        let positions = arrange(comments);
+        assert_eq!(paginate(&positions, 2), vec![0..3, 3..5, 5..6]);
+        assert_eq!(paginate(&positions, 100), vec![0..6]);
+        assert_eq!(paginate(&[], 100), vec![0..0]);
+    }
+}
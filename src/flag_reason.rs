@@ -0,0 +1,127 @@
+/*!
+Why something got flagged, and when enough flags should hide it.
+
+Every flag carries one of a few fixed reasons, stored as a short string, plus an optional note.
+Flags from before reasons existed are stored as `other`, and so is anything unrecognized.
+
+Besides the flat `FLAG_INVISIBLE_THRESHOLD` that counts every flag, each reason can have its own
+threshold that only counts flags from trusted users. When either one is reached, the post or
+comment is hidden and lands in the mod queue.
+*/
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlagReason {
+    Spam,
+    Abuse,
+    OffTopic,
+    Other,
+}
+
+impl FlagReason {
+    /// In the order they're shown, both in the flag form and on the admin pages.
+    pub const ALL: [FlagReason; 4] = [FlagReason::Spam, FlagReason::Abuse, FlagReason::OffTopic, FlagReason::Other];
+    /// What goes in the database and the form.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FlagReason::Spam => "spam",
+            FlagReason::Abuse => "abuse",
+            FlagReason::OffTopic => "off_topic",
+            FlagReason::Other => "other",
+        }
+    }
+    pub fn label(self) -> &'static str {
+        match self {
+            FlagReason::Spam => "Spam",
+            FlagReason::Abuse => "Abuse",
+            FlagReason::OffTopic => "Off-topic",
+            FlagReason::Other => "Other",
+        }
+    }
+    pub fn parse(s: &str) -> FlagReason {
+        FlagReason::ALL.iter()
+            .cloned()
+            .find(|reason| reason.as_str() == s)
+            .unwrap_or(FlagReason::Other)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FlagThresholds {
+    /// Only flags from users with at least this trust level count toward the thresholds.
+    pub trusted_level: i32,
+    /// Trusted flags with each reason that hide the item. Zero means never.
+    pub spam: i64,
+    pub abuse: i64,
+    pub off_topic: i64,
+    pub other: i64,
+}
+
+impl FlagThresholds {
+    pub fn for_reason(&self, reason: FlagReason) -> i64 {
+        match reason {
+            FlagReason::Spam => self.spam,
+            FlagReason::Abuse => self.abuse,
+            FlagReason::OffTopic => self.off_topic,
+            FlagReason::Other => self.other,
+        }
+    }
+    /// Whether this flag is the one that tips it over, so the item only gets hidden once.
+    ///
+    /// Only a trusted flag can tip it over. Otherwise, once a moderator approves an item,
+    /// anybody could hide it again by adding an untrusted flag while the trusted count sits at the threshold.
+    pub fn is_reached(&self, reason: FlagReason, flagger_trust_level: i32, trusted_count: i64) -> bool {
+        let threshold = self.for_reason(reason);
+        threshold != 0 && flagger_trust_level >= self.trusted_level && trusted_count == threshold
+    }
+}
+
+/// Sort flags into one group per reason, in the order of `FlagReason::ALL`, leaving out empty ones.
+pub fn group_by_reason<T>(flags: Vec<T>, reason: impl Fn(&T) -> &str) -> Vec<(&'static str, Vec<T>)> {
+    let mut groups: Vec<(&'static str, Vec<T>)> = FlagReason::ALL.iter().map(|r| (r.label(), Vec::new())).collect();
+    for flag in flags {
+        let r = FlagReason::parse(reason(&flag));
+        let i = FlagReason::ALL.iter().position(|&x| x == r).unwrap();
+        groups[i].1.push(flag);
+    }
+    groups.retain(|group| !group.1.is_empty());
+    groups
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn round_trip() {
+        for &reason in FlagReason::ALL.iter() {
+            assert_eq!(FlagReason::parse(reason.as_str()), reason);
+        }
+    }
+    #[test]
+    fn unknown_is_other() {
+        assert_eq!(FlagReason::parse(""), FlagReason::Other);
+        assert_eq!(FlagReason::parse("Spam"), FlagReason::Other);
+    }
+    #[test]
+    fn zero_threshold_is_off() {
+        let thresholds = FlagThresholds { trusted_level: 2, spam: 2, abuse: 0, off_topic: 0, other: 0 };
+        assert!(!thresholds.is_reached(FlagReason::Spam, 2, 1));
+        assert!(thresholds.is_reached(FlagReason::Spam, 2, 2));
+        assert!(!thresholds.is_reached(FlagReason::Spam, 2, 3));
+        assert!(!thresholds.is_reached(FlagReason::Abuse, 2, 0));
+    }
+    #[test]
+    fn untrusted_flag_does_not_tip() {
+        let thresholds = FlagThresholds { trusted_level: 2, spam: 2, abuse: 0, off_topic: 0, other: 0 };
+        assert!(!thresholds.is_reached(FlagReason::Spam, 1, 2));
+        assert!(thresholds.is_reached(FlagReason::Spam, 3, 2));
+    }
+    #[test]
+    fn grouping() {
+        let flags = vec![("other", 1), ("spam", 2), ("bogus", 3), ("spam", 4)];
+        let groups = group_by_reason(flags, |f| f.0);
+        assert_eq!(groups, vec![
+            ("Spam", vec![("spam", 2), ("spam", 4)]),
+            ("Other", vec![("other", 1), ("bogus", 3)]),
+        ]);
+    }
+}
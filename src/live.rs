@@ -0,0 +1,109 @@
+/*!
+Tell people who have a post open about new comments, while they're reading it.
+
+This is an in-process broadcast, so it only reaches readers connected to the same server
+process that accepted the comment. Every comment that becomes visible, either when it's posted
+or when a moderator approves it, gets sent here by `comment_on_post` or `approve_comment`,
+and each reader's event stream picks out the ones for its own post.
+*/
+
+use lazy_static::lazy_static;
+use rocket::request::FromRequest;
+use rocket::{Orbit, Request, Rocket};
+use rocket::outcome::Outcome;
+use rocket::http::Status;
+use rocket::tokio::sync::broadcast;
+
+/// Readers further behind than this many comments, site-wide, skip the ones they missed.
+const CAPACITY: usize = 64;
+
+#[derive(Clone, Copy, Debug)]
+pub struct NewComment {
+    pub post_id: i32,
+    pub comment_id: i32,
+}
+
+lazy_static! {
+    static ref SENDER: broadcast::Sender<NewComment> = broadcast::channel(CAPACITY).0;
+}
+
+/// Call this after a comment becomes visible.
+pub fn send(post_id: i32, comment_id: i32) {
+    // It's not an error if nobody is listening.
+    let _ = SENDER.send(NewComment { post_id, comment_id });
+}
+
+/// The comments on one post, as they become visible.
+pub struct Receiver {
+    post_id: i32,
+    receiver: broadcast::Receiver<NewComment>,
+}
+
+impl Receiver {
+    /// Wait for the next comment on this post, or `None` once nothing more can be sent.
+    pub async fn recv(&mut self) -> Option<i32> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(new_comment) if new_comment.post_id == self.post_id => return Some(new_comment.comment_id),
+                Ok(_) => continue,
+                // The reader can reload to get the ones they missed.
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+pub fn subscribe(post_id: i32) -> Receiver {
+    Receiver { post_id, receiver: SENDER.subscribe() }
+}
+
+/// The running server, so an event stream can get a database connection each time it needs one,
+/// instead of holding one for as long as the page is open.
+pub struct App<'r> {
+    pub rocket: &'r Rocket<Orbit>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for App<'r> {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<App<'r>, (Status, ()), ()> {
+        Outcome::Success(App { rocket: request.rocket() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::tokio::time::{timeout, Duration};
+    // The sender is shared by every test, so each test uses its own post ids.
+    #[rocket::async_test]
+    async fn only_this_post() {
+        let mut receiver = subscribe(1001);
+        send(1002, 1);
+        send(1001, 2);
+        send(1001, 3);
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, Some(3));
+        assert!(timeout(Duration::from_millis(10), receiver.recv()).await.is_err());
+    }
+    #[rocket::async_test]
+    async fn nobody_listening() {
+        send(2001, 1);
+        let mut receiver = subscribe(2001);
+        send(2001, 2);
+        assert_eq!(receiver.recv().await, Some(2));
+    }
+    #[rocket::async_test]
+    async fn lagged() {
+        let mut receiver = subscribe(3001);
+        for comment_id in 0..(CAPACITY as i32 * 2) {
+            send(3001, comment_id);
+        }
+        // The oldest ones were dropped, but the rest still come through.
+        let first = receiver.recv().await.unwrap();
+        assert!(first > 0);
+        assert_eq!(receiver.recv().await, Some(first + 1));
+    }
+}
@@ -0,0 +1,215 @@
+/*!
+Outgoing mail.
+
+Everything that sends mail goes through the `MailSender` trait, so the transport is picked in
+the site config. `smtp` actually delivers it. `maildir` writes each message to a maildir, or to
+stdout if no path is given, which is what you want when running locally.
+*/
+
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::request::FromRequest;
+use rocket::Request;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use chrono::Utc;
+use crate::SiteConfig;
+
+#[derive(Clone, Debug)]
+pub struct Message {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum MailError {
+    Io(io::Error),
+    Address(String),
+    Smtp(String),
+}
+
+impl Display for MailError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            MailError::Io(e) => write!(f, "failed to write mail: {}", e),
+            MailError::Address(e) => write!(f, "invalid mail address: {}", e),
+            MailError::Smtp(e) => write!(f, "failed to send mail: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for MailError {
+    fn from(e: io::Error) -> MailError {
+        MailError::Io(e)
+    }
+}
+
+pub trait MailSender: Send + Sync {
+    /// Deliver one message. This is allowed to block.
+    fn send(&self, from: &str, message: &Message) -> Result<(), MailError>;
+}
+
+pub struct SmtpSender {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+}
+
+impl MailSender for SmtpSender {
+    fn send(&self, from: &str, message: &Message) -> Result<(), MailError> {
+        use lettre::{SmtpTransport, Transport};
+        use lettre::transport::smtp::authentication::Credentials;
+        let email = lettre::Message::builder()
+            .from(from.parse().map_err(|e| MailError::Address(format!("{}", e)))?)
+            .to(message.to.parse().map_err(|e| MailError::Address(format!("{}", e)))?)
+            .subject(&message.subject)
+            .body(message.body.clone())
+            .map_err(|e| MailError::Smtp(format!("{}", e)))?;
+        let mut transport = SmtpTransport::relay(&self.host)
+            .map_err(|e| MailError::Smtp(format!("{}", e)))?
+            .port(self.port);
+        if self.username != "" {
+            transport = transport.credentials(Credentials::new(self.username.clone(), self.password.clone()));
+        }
+        transport.build()
+            .send(&email)
+            .map(|_| ())
+            .map_err(|e| MailError::Smtp(format!("{}", e)))
+    }
+}
+
+pub struct MaildirSender {
+    /// If this is empty, messages are printed to stdout instead.
+    pub path: String,
+}
+
+impl MaildirSender {
+    fn format(from: &str, message: &Message) -> String {
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+            from,
+            message.to,
+            message.subject,
+            Utc::now().to_rfc2822(),
+            message.body.replace("\n", "\r\n"),
+        )
+    }
+}
+
+impl MailSender for MaildirSender {
+    fn send(&self, from: &str, message: &Message) -> Result<(), MailError> {
+        let formatted = MaildirSender::format(from, message);
+        if self.path == "" {
+            let stdout = io::stdout();
+            let mut stdout = stdout.lock();
+            stdout.write_all(formatted.as_bytes())?;
+            return Ok(());
+        }
+        // Write to tmp, then rename into new, so that readers never see half a message.
+        let root = PathBuf::from(&self.path);
+        for dir in &["tmp", "new", "cur"] {
+            fs::create_dir_all(root.join(dir))?;
+        }
+        let unique: u64 = ::rand::random();
+        let filename = format!("{}.{}_{:x}.more-interesting", Utc::now().timestamp(), process::id(), unique);
+        let tmp = root.join("tmp").join(&filename);
+        fs::write(&tmp, formatted.as_bytes())?;
+        fs::rename(&tmp, root.join("new").join(&filename))?;
+        Ok(())
+    }
+}
+
+/// Build the mail transport described by the site config,
+/// or `None` if this site doesn't send mail.
+pub fn from_config(config: &SiteConfig) -> Option<Arc<dyn MailSender>> {
+    match &config.mail_transport[..] {
+        "smtp" => Some(Arc::new(SmtpSender {
+            host: config.smtp_host.clone(),
+            port: config.smtp_port,
+            username: config.smtp_username.clone(),
+            password: config.smtp_password.clone(),
+        })),
+        "maildir" => Some(Arc::new(MaildirSender {
+            path: config.maildir_path.clone(),
+        })),
+        _ => None,
+    }
+}
+
+/// A request guard for routes that send mail.
+///
+/// It fails if mail isn't configured, so take an `Option<Mailer>` if that's not fatal.
+pub struct Mailer {
+    from: String,
+    sender: Arc<dyn MailSender>,
+}
+
+impl Mailer {
+    pub async fn send(&self, message: Message) -> Result<(), MailError> {
+        let from = self.from.clone();
+        let sender = self.sender.clone();
+        rocket::tokio::task::spawn_blocking(move || sender.send(&from, &message))
+            .await
+            .map_err(|e| MailError::Io(io::Error::new(io::ErrorKind::Other, e)))?
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Mailer {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Mailer, (Status, ()), ()> {
+        let config = request.rocket().state::<SiteConfig>();
+        match config.and_then(|config| Some((config.mail_from.clone(), from_config(config)?))) {
+            Some((from, sender)) => Outcome::Success(Mailer { from, sender }),
+            None => Outcome::Failure((Status::ServiceUnavailable, ())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn message() -> Message {
+        Message {
+            to: String::from("someone@example.com"),
+            subject: String::from("Reset your password"),
+            body: String::from("First line\nSecond line\n"),
+        }
+    }
+    #[test]
+    fn format_uses_crlf() {
+        let formatted = MaildirSender::format("site@example.com", &message());
+        assert!(formatted.starts_with("From: site@example.com\r\nTo: someone@example.com\r\nSubject: Reset your password\r\n"));
+        assert!(formatted.ends_with("\r\n\r\nFirst line\r\nSecond line\r\n\r\n"));
+        assert!(!formatted.replace("\r\n", "").contains('\n'));
+    }
+    #[test]
+    fn maildir_delivers_to_new() {
+        let root = std::env::temp_dir().join(format!("more-interesting-mail-{:x}", ::rand::random::<u64>()));
+        let sender = MaildirSender { path: root.to_str().unwrap().to_owned() };
+        sender.send("site@example.com", &message()).unwrap();
+        let delivered: Vec<PathBuf> = fs::read_dir(root.join("new")).unwrap().map(|entry| entry.unwrap().path()).collect();
+        assert_eq!(delivered.len(), 1);
+        assert!(fs::read_to_string(&delivered[0]).unwrap().contains("Subject: Reset your password\r\n"));
+        assert_eq!(fs::read_dir(root.join("tmp")).unwrap().count(), 0);
+        fs::remove_dir_all(&root).unwrap();
+    }
+    #[test]
+    fn transport_comes_from_config() {
+        let mut config = SiteConfig::default();
+        assert!(from_config(&config).is_none());
+        config.mail_transport = String::from("maildir");
+        assert!(from_config(&config).is_some());
+        config.mail_transport = String::from("smtp");
+        assert!(from_config(&config).is_some());
+        config.mail_transport = String::from("carrier pigeon");
+        assert!(from_config(&config).is_none());
+    }
+}
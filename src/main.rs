@@ -19,12 +19,30 @@ mod prettify;
 mod pid_file_fairing;
 mod sql_types;
 mod forever;
+mod totp;
+mod user_agent;
+mod mail;
+mod throttle;
+mod client_ip;
+mod oidc;
+mod search_query;
+mod saved_search;
+mod ranking;
+mod comment_thread;
+mod live;
+mod mod_action;
+mod flag_reason;
+mod blocked_regex;
+mod api;
+mod api_token;
+mod notification;
 
 use askama::Template;
 use forever::CacheForever;
 use rocket::form::{Form, FromForm};
 use rocket::request::{self, FlashMessage, Request};
 use rocket::response::{self, Response, Responder, Redirect, Flash, content};
+use rocket::response::stream::{Event, EventStream};
 use rocket::http::{CookieJar, Cookie, ContentType, MediaType};
 pub use models::MoreInterestingConn;
 use models::PollInfo;
@@ -37,8 +55,11 @@ use rocket::http::{SameSite, Status};
 use rocket::fs::FileServer;
 use serde::{Serialize, Serializer, Deserialize};
 use std::borrow::Cow;
-use crate::models::{SiteCustomization, NotificationInfo, NewNotification, NewSubscription, PostSearch, PostSearchOrderBy, UserSession, PostInfo, NewStar, NewHide, NewHideComment, NewUser, CommentInfo, NewPost, NewComment, NewStarComment, NewTag, Tag, Comment, ModerationInfo, NewFlag, NewFlagComment, LegacyCommentInfo, CommentSearchResult, DomainSynonym, DomainSynonymInfo, NewDomain};
+use crate::models::{SiteCustomization, NotificationInfo, NewNotification, NewSubscription, PostSearch, PostSearchOrderBy, UserSession, PostInfo, NewStar, NewHide, NewHideComment, NewUser, CommentInfo, NewPost, NewComment, NewStarComment, NewTag, Tag, Comment, ModerationInfo, NewFlag, NewFlagComment, LegacyCommentInfo, CommentSearch, CommentSearchOrderBy, CommentSearchResult, NewSavedSearch, DomainSynonym, DomainSynonymInfo, DomainRestriction, NewDomain, ModLogSearch};
 use crate::template::AdminPageId;
+use crate::mod_action::ModAction;
+use crate::flag_reason::{FlagReason, FlagThresholds};
+use crate::blocked_regex::{Scope, Verdict};
 pub use crate::template::ModQueueItem;
 use more_interesting_base32::Base32;
 use url::Url;
@@ -48,7 +69,10 @@ use crate::pid_file_fairing::PidFileFairing;
 use rocket::fairing;
 use rocket::State;
 use std::str::FromStr;
+use std::sync::Arc;
 use crate::session::{LoginSession, ModeratorSession, UserAgentString, ReferrerString};
+use crate::mail::Mailer;
+use crate::client_ip::ClientIp;
 use chrono::{NaiveDate, Duration, Utc, Months};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use lazy_static::lazy_static;
@@ -77,12 +101,179 @@ pub struct SiteConfig {
     init_username: String,
     #[serde(default)]
     init_password: String,
+    #[serde(default)]
+    require_2fa_for_moderators: bool,
+    /// Log out sessions that haven't been used in this many days. Zero means never.
+    #[serde(default)]
+    session_inactivity_days: i64,
+    /// How to send mail: `smtp`, `maildir`, or empty to not send any.
+    /// Password reset is only offered when this is set.
+    #[serde(default)]
+    mail_transport: String,
+    #[serde(default)]
+    mail_from: String,
+    #[serde(default)]
+    smtp_host: String,
+    #[serde(default = "make_smtp_port")]
+    smtp_port: u16,
+    #[serde(default)]
+    smtp_username: String,
+    #[serde(default, skip_serializing)]
+    smtp_password: String,
+    /// With the maildir transport, an empty path prints mail to stdout.
+    #[serde(default)]
+    maildir_path: String,
+    /// Argon2id cost for new password hashes. Changing these upgrades each user's hash when they next log in.
+    #[serde(default = "make_argon2_memory_kib")]
+    argon2_memory_kib: u32,
+    #[serde(default = "make_argon2_iterations")]
+    argon2_iterations: u32,
+    #[serde(default = "make_argon2_parallelism")]
+    argon2_parallelism: u32,
+    /// Failed password attempts on one account before it has to wait between tries.
+    /// Each failure after that doubles the wait, starting from `login_backoff_seconds`.
+    #[serde(default = "make_login_free_attempts")]
+    login_free_attempts: u32,
+    #[serde(default = "make_login_backoff_seconds")]
+    login_backoff_seconds: i64,
+    /// Failed attempts within `login_lockout_minutes` that lock an account out. Zero means never.
+    #[serde(default = "make_login_lockout_attempts")]
+    login_lockout_attempts: u32,
+    #[serde(default = "make_login_lockout_minutes")]
+    login_lockout_minutes: i64,
+    /// Same as `login_lockout_attempts`, but counting every account tried from one IP address.
+    #[serde(default = "make_login_ip_lockout_attempts")]
+    login_ip_lockout_attempts: u32,
+    /// Reverse proxies whose `X-Forwarded-For` header is believed, separated by spaces or commas.
+    /// Leave this empty if the site is reachable without going through one. See `client_ip.rs`.
+    #[serde(default)]
+    trusted_proxy: String,
+    /// An OpenID Connect provider to offer single sign-on through. Empty turns it off.
+    #[serde(default)]
+    oidc_issuer: String,
+    #[serde(default)]
+    oidc_client_id: String,
+    #[serde(default, skip_serializing)]
+    oidc_client_secret: String,
+    #[serde(default = "make_oidc_scopes")]
+    oidc_scopes: String,
+    /// Shown on the login button, as in "Log in with ...".
+    #[serde(default = "make_oidc_provider_name")]
+    oidc_provider_name: String,
+    /// Allow an `http` issuer. Only for testing against a local mock provider; see `oidc.rs`.
+    #[serde(default)]
+    oidc_allow_http: bool,
+    /// How to order the front page: `stellar`, `wall-clock`, or `wilson`. See `ranking.rs`.
+    #[serde(default = "make_ranking")]
+    ranking: String,
+    /// How fast posts sink as other posts get stars, with the `stellar` ranking.
+    #[serde(default = "make_ranking_stellar_gravity")]
+    ranking_stellar_gravity: f64,
+    /// How fast posts sink as hours go by, with the `wall-clock` ranking.
+    #[serde(default = "make_ranking_wall_clock_gravity")]
+    ranking_wall_clock_gravity: f64,
+    /// Extra points for blog posts, as if they had this many more stars.
+    #[serde(default = "make_ranking_blog_boost")]
+    ranking_blog_boost: f64,
+    /// Points for each comment, and points taken away for each flag.
+    #[serde(default)]
+    ranking_comment_weight: f64,
+    #[serde(default)]
+    ranking_flag_weight: f64,
+    #[serde(default = "make_ranking_wilson_z")]
+    ranking_wilson_z: f64,
+    #[serde(default = "make_ranking_wilson_half_life_hours")]
+    ranking_wilson_half_life_hours: f64,
+    /// Only flags from users with at least this trust level count toward the `flag_hide_*` thresholds.
+    #[serde(default = "make_flag_trusted_level")]
+    flag_trusted_level: i32,
+    /// Trusted flags with each reason that hide a post or comment into the mod queue. Zero means never.
+    #[serde(default)]
+    flag_hide_spam: i64,
+    #[serde(default)]
+    flag_hide_abuse: i64,
+    #[serde(default)]
+    flag_hide_off_topic: i64,
+    #[serde(default)]
+    flag_hide_other: i64,
 }
 
 fn make_localhost() -> Url {
     Url::parse("http://localhost").unwrap()
 }
 
+fn make_smtp_port() -> u16 {
+    587
+}
+
+fn make_argon2_memory_kib() -> u32 {
+    password::Argon2Params::default().memory_kib
+}
+
+fn make_argon2_iterations() -> u32 {
+    password::Argon2Params::default().iterations
+}
+
+fn make_argon2_parallelism() -> u32 {
+    password::Argon2Params::default().parallelism
+}
+
+fn make_login_free_attempts() -> u32 {
+    3
+}
+
+fn make_login_backoff_seconds() -> i64 {
+    1
+}
+
+fn make_login_lockout_attempts() -> u32 {
+    10
+}
+
+fn make_login_lockout_minutes() -> i64 {
+    15
+}
+
+fn make_login_ip_lockout_attempts() -> u32 {
+    50
+}
+
+fn make_oidc_scopes() -> String {
+    String::from("openid profile email")
+}
+
+fn make_oidc_provider_name() -> String {
+    String::from("single sign-on")
+}
+
+fn make_ranking() -> String {
+    String::from("stellar")
+}
+
+fn make_ranking_stellar_gravity() -> f64 {
+    ranking::RankingParams::default().stellar_gravity
+}
+
+fn make_ranking_wall_clock_gravity() -> f64 {
+    ranking::RankingParams::default().wall_clock_gravity
+}
+
+fn make_ranking_blog_boost() -> f64 {
+    ranking::RankingParams::default().blog_boost
+}
+
+fn make_ranking_wilson_z() -> f64 {
+    ranking::RankingParams::default().wilson_z
+}
+
+fn make_ranking_wilson_half_life_hours() -> f64 {
+    ranking::RankingParams::default().wilson_half_life_hours
+}
+
+fn make_flag_trusted_level() -> i32 {
+    2
+}
+
 impl Default for SiteConfig {
     fn default() -> Self {
         SiteConfig {
@@ -95,6 +286,83 @@ impl Default for SiteConfig {
             pid_file: String::new(),
             init_username: String::new(),
             init_password: String::new(),
+            require_2fa_for_moderators: false,
+            session_inactivity_days: 0,
+            mail_transport: String::new(),
+            mail_from: String::new(),
+            smtp_host: String::new(),
+            smtp_port: make_smtp_port(),
+            smtp_username: String::new(),
+            smtp_password: String::new(),
+            maildir_path: String::new(),
+            argon2_memory_kib: make_argon2_memory_kib(),
+            argon2_iterations: make_argon2_iterations(),
+            argon2_parallelism: make_argon2_parallelism(),
+            login_free_attempts: make_login_free_attempts(),
+            login_backoff_seconds: make_login_backoff_seconds(),
+            login_lockout_attempts: make_login_lockout_attempts(),
+            login_lockout_minutes: make_login_lockout_minutes(),
+            login_ip_lockout_attempts: make_login_ip_lockout_attempts(),
+            trusted_proxy: String::new(),
+            oidc_issuer: String::new(),
+            oidc_client_id: String::new(),
+            oidc_client_secret: String::new(),
+            oidc_scopes: make_oidc_scopes(),
+            oidc_provider_name: make_oidc_provider_name(),
+            oidc_allow_http: false,
+            ranking: make_ranking(),
+            ranking_stellar_gravity: make_ranking_stellar_gravity(),
+            ranking_wall_clock_gravity: make_ranking_wall_clock_gravity(),
+            ranking_blog_boost: make_ranking_blog_boost(),
+            ranking_comment_weight: 0.0,
+            ranking_flag_weight: 0.0,
+            ranking_wilson_z: make_ranking_wilson_z(),
+            ranking_wilson_half_life_hours: make_ranking_wilson_half_life_hours(),
+            flag_trusted_level: make_flag_trusted_level(),
+            flag_hide_spam: 0,
+            flag_hide_abuse: 0,
+            flag_hide_off_topic: 0,
+            flag_hide_other: 0,
+        }
+    }
+}
+
+impl SiteConfig {
+    fn account_throttle(&self) -> throttle::Policy {
+        throttle::Policy {
+            free_attempts: self.login_free_attempts,
+            backoff_seconds: self.login_backoff_seconds,
+            lockout_attempts: self.login_lockout_attempts,
+            window: Duration::minutes(self.login_lockout_minutes),
+        }
+    }
+    /// A whole office can share one address, so this one doesn't back off; it only locks out.
+    fn ip_throttle(&self) -> throttle::Policy {
+        throttle::Policy {
+            free_attempts: self.login_ip_lockout_attempts,
+            backoff_seconds: 0,
+            lockout_attempts: self.login_ip_lockout_attempts,
+            window: Duration::minutes(self.login_lockout_minutes),
+        }
+    }
+    fn ranking_params(&self) -> ranking::RankingParams {
+        ranking::RankingParams {
+            stellar_gravity: self.ranking_stellar_gravity,
+            wall_clock_gravity: self.ranking_wall_clock_gravity,
+            blog_boost: self.ranking_blog_boost,
+            comment_weight: self.ranking_comment_weight,
+            flag_weight: self.ranking_flag_weight,
+            wilson_z: self.ranking_wilson_z,
+            wilson_half_life_hours: self.ranking_wilson_half_life_hours,
+        }
+    }
+    fn flag_thresholds(&self) -> FlagThresholds {
+        FlagThresholds {
+            trusted_level: self.flag_trusted_level,
+            spam: self.flag_hide_spam,
+            abuse: self.flag_hide_abuse,
+            off_topic: self.flag_hide_off_topic,
+            other: self.flag_hide_other,
         }
     }
 }
@@ -193,13 +461,14 @@ impl MaybeRedirect {
             None => Err(Status::Created)
         }
     }
-    pub fn maybe_redirect_vote<T: FnOnce() -> String>(self, hash: T) -> VoteResponse {
+    /// `suffix` goes after the redirect's path; it's the fragment, and maybe a query string before it.
+    pub fn maybe_redirect_vote<T: FnOnce() -> String>(self, suffix: T) -> VoteResponse {
         let p = match self.redirect {
             Some(b) if b == Base32::zero() => String::from("."),
             Some(b) => b.to_string(),
             None => return VoteResponse::C(Status::Created),
         };
-        VoteResponse::B(content::RawHtml(format!("<!DOCTYPE html><meta name=viewport content=width=device-width><h1><a href={}#{}>Redirect OK</a></h1><script>window.location = document.getElementsByTagName('a')[0].href</script>", escape(&p), escape(&hash()))))
+        VoteResponse::B(content::RawHtml(format!("<!DOCTYPE html><meta name=viewport content=width=device-width><h1><a href={}{}>Redirect OK</a></h1><script>window.location = document.getElementsByTagName('a')[0].href</script>", escape(&p), escape(&suffix()))))
     }
 }
 
@@ -233,6 +502,18 @@ It helps keep the URLs short and easy-to-remember.
 * `http://example.instance/assets` is where the static files are
 */
 
+const MAX_FLAG_NOTE_CHARS: usize = 500;
+
+/// A blank note is no note, and a long one gets cut off instead of rejecting the flag.
+fn flag_note(note: &Option<String>) -> Option<String> {
+    let note = note.as_deref().unwrap_or("").trim();
+    if note.is_empty() {
+        None
+    } else {
+        Some(note.chars().take(MAX_FLAG_NOTE_CHARS).collect())
+    }
+}
+
 #[derive(FromForm)]
 struct VoteForm {
     rm_star: Option<Base32>,
@@ -241,10 +522,12 @@ struct VoteForm {
     add_flag: Option<Base32>,
     rm_hide: Option<Base32>,
     add_hide: Option<Base32>,
+    flag_reason: Option<String>,
+    flag_note: Option<String>,
 }
 
 #[post("/vote?<redirect..>", data = "<p>")]
-async fn vote(conn: MoreInterestingConn, login: LoginSession, redirect: MaybeRedirect, p: Form<VoteForm>, customization: Customization) -> VoteResponse {
+async fn vote(conn: MoreInterestingConn, login: LoginSession, redirect: MaybeRedirect, p: Form<VoteForm>, customization: Customization, config: &State<SiteConfig>) -> VoteResponse {
     let user = login.user;
     let (post, result) = match (p.add_star, p.rm_star, p.add_flag, p.rm_flag, p.add_hide, p.rm_hide) {
         (Some(u), None, None, None, None, None) => {
@@ -275,10 +558,11 @@ async fn vote(conn: MoreInterestingConn, login: LoginSession, redirect: MaybeRed
                 Err(_) => return VoteResponse::C(Status::NotFound),
             };
             let id = post.id;
+            let reason = FlagReason::parse(p.flag_reason.as_deref().unwrap_or(""));
             (post, conn.add_flag(&NewFlag {
                 user_id: user.id,
                 post_id: id,
-            }).await)
+            }, reason, flag_note(&p.flag_note), config.flag_thresholds()).await)
         }
         (None, None, None, Some(u), None, None) => {
             let post = match conn.get_post_info_by_uuid(user.id, u).await {
@@ -323,7 +607,7 @@ async fn vote(conn: MoreInterestingConn, login: LoginSession, redirect: MaybeRed
             conn.change_user_trust_level(user.id, 1).await.expect("if voting works, then so should switching trust level")
         }
         if redirect.redirect.is_some() {
-            redirect.maybe_redirect_vote(|| format!("{}", post.uuid))
+            redirect.maybe_redirect_vote(|| format!("#{}", post.uuid))
         } else {
             let starred_by = conn.get_post_starred_by(post.id).await.unwrap_or(Vec::new());
             VoteResponse::B(content::RawHtml(template::ViewStar {
@@ -343,10 +627,12 @@ struct VoteCommentForm {
     rm_flag_comment: Option<i32>,
     add_hide_comment: Option<i32>,
     rm_hide_comment: Option<i32>,
+    flag_reason: Option<String>,
+    flag_note: Option<String>,
 }
 
 #[post("/vote-comment?<redirect..>", data = "<c>")]
-async fn vote_comment(conn: MoreInterestingConn, login: LoginSession, redirect: MaybeRedirect, c: Form<VoteCommentForm>, customization: Customization) -> VoteResponse {
+async fn vote_comment(conn: MoreInterestingConn, login: LoginSession, redirect: MaybeRedirect, c: Form<VoteCommentForm>, customization: Customization, config: &State<SiteConfig>) -> VoteResponse {
     let user = login.user;
     let (id, result) = match (c.add_star_comment, c.rm_star_comment, c.add_flag_comment, c.rm_flag_comment, c.add_hide_comment, c.rm_hide_comment) {
         (Some(i), None, None, None, None, None) => (i, conn.add_star_comment(&NewStarComment{
@@ -360,7 +646,7 @@ async fn vote_comment(conn: MoreInterestingConn, login: LoginSession, redirect:
         (None, None, Some(i), None, None, None) if user.trust_level >= 1 => (i, conn.add_flag_comment(&NewFlagComment{
             user_id: user.id,
             comment_id: i,
-        }).await),
+        }, FlagReason::parse(c.flag_reason.as_deref().unwrap_or("")), flag_note(&c.flag_note), config.flag_thresholds()).await),
         (None, None, None, Some(i), None, None) => (i, conn.rm_flag_comment(&NewFlagComment{
             user_id: user.id,
             comment_id: i,
@@ -382,7 +668,7 @@ async fn vote_comment(conn: MoreInterestingConn, login: LoginSession, redirect:
             conn.change_user_trust_level(user.id, 1).await.expect("if voting works, then so should switching trust level")
         }
         if redirect.redirect.is_some() {
-            redirect.maybe_redirect_vote(|| format!("{}", id))
+            redirect.maybe_redirect_vote(|| format!("?comment={}#{}", id, id))
         } else {
             let starred_by = conn.get_comment_starred_by(id).await.unwrap_or(Vec::new());
             VoteResponse::B(content::RawHtml(template::ViewStarComment {
@@ -406,9 +692,17 @@ struct IndexParams {
     before_date: Option<String>,
     after_date: Option<String>,
     user: Option<String>,
+    /// Moderators can preview the front page with a different ranking strategy.
+    rank: Option<String>,
 }
 
 async fn parse_index_params(conn: &MoreInterestingConn, user: &User, params: Option<IndexParams>) -> Option<(PostSearch, Vec<Tag>)> {
+    parse_index_params_checked(conn, user, params).await?.ok()
+}
+
+/// Like `parse_index_params`, but a bad query in the search box comes back as an error message to show,
+/// instead of a 404. Unknown tags and domains in the other fields are still a 404.
+async fn parse_index_params_checked(conn: &MoreInterestingConn, user: &User, params: Option<IndexParams>) -> Option<Result<(PostSearch, Vec<Tag>), String>> {
     let mut tags = vec![];
     let mut search = PostSearch::with_my_user_id(user.id);
     if let Some(after_uuid) = params.as_ref().and_then(|params| params.after.as_ref()) {
@@ -454,7 +748,13 @@ async fn parse_index_params(conn: &MoreInterestingConn, user: &User, params: Opt
         }
     }
     if let Some(query) = params.as_ref().and_then(|params| params.q.as_ref()) {
-        search.keywords = query.to_string();
+        let query = match search_query::parse(query) {
+            Ok(query) => query,
+            Err(e) => return Some(Err(e.to_string())),
+        };
+        if let Err(e) = apply_search_query(conn, &mut search, &mut tags, query).await {
+            return Some(Err(e));
+        }
     }
     if let Some(query) = params.as_ref().and_then(|params| params.title.as_ref()) {
         search.title = query.to_string();
@@ -485,7 +785,47 @@ async fn parse_index_params(conn: &MoreInterestingConn, user: &User, params: Opt
     if params.and_then(|p| p.subscriptions).unwrap_or(false) {
         search.subscriptions = true;
     }
-    Some((search, tags))
+    Some(Ok((search, tags)))
+}
+
+/// Look up the names in a search box query, and add them to the search.
+async fn apply_search_query(conn: &MoreInterestingConn, search: &mut PostSearch, tags: &mut Vec<Tag>, query: search_query::Query) -> Result<(), String> {
+    for tag_name in &query.and_tags {
+        let tag = conn.get_tag_by_name(tag_name).await.map_err(|_| format!("There is no tag named \"{}\"", tag_name))?;
+        search.and_tags.push(tag.id);
+        tags.push(tag);
+    }
+    for tag_name in &query.or_tags {
+        let tag = conn.get_tag_by_name(tag_name).await.map_err(|_| format!("There is no tag named \"{}\"", tag_name))?;
+        search.or_tags.push(tag.id);
+        tags.push(tag);
+    }
+    for tag_name in &query.hide_tags {
+        let tag = conn.get_tag_by_name(tag_name).await.map_err(|_| format!("There is no tag named \"{}\"", tag_name))?;
+        search.hide_tags.push(tag.id);
+    }
+    for domain_name in &query.domains {
+        let domain = conn.get_domain_by_hostname(domain_name).await.map_err(|_| format!("No posts link to \"{}\"", domain_name))?;
+        search.or_domains.push(domain.id);
+    }
+    if let Some(username) = &query.by {
+        let user = conn.get_user_by_username(username).await.map_err(|_| format!("There is no user named \"{}\"", username))?;
+        search.for_user_id = user.id;
+    }
+    search.keywords = query.keywords;
+    if !query.title.is_empty() {
+        search.title = query.title;
+    }
+    if query.before.is_some() {
+        search.before_date = query.before;
+    }
+    if query.after.is_some() {
+        search.after_date = query.after;
+    }
+    if query.locked.is_some() {
+        search.locked = query.locked;
+    }
+    Ok(())
 }
 
 #[get("/?<params..>")]
@@ -494,9 +834,19 @@ async fn index(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Op
 
     let mut tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
     let mut domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
-    let (search, tags) = parse_index_params(&conn, &user, params).await?;
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
+    let rank_param = params.as_ref().and_then(|params| params.rank.clone()).filter(|_| user.trust_level >= 3);
+    let (search, tags, query_error) = match parse_index_params_checked(&conn, &user, params).await? {
+        Ok((search, tags)) => (search, tags, None),
+        Err(e) => (PostSearch::with_my_user_id(user.id), Vec::new(), Some(e)),
+    };
+    let ranking = match rank_param {
+        Some(ref rank) => Some(ranking::by_name(rank, config.ranking_params())?),
+        None => None,
+    };
     let search = PostSearch {
         blog_post: Some(false),
+        ranking,
         .. search
     };
     let blog_search = PostSearch {
@@ -504,7 +854,7 @@ async fn index(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Op
         limit: 4,
         .. search.clone()
     };
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let is_home = tag_param == "" && domain == "" && keywords_param == "" && title_param == "";
     let before_date_param = search.before_date;
@@ -520,13 +870,18 @@ async fn index(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Op
             conn.search_posts(&blog_search),
             notifications);
         (posts.ok()?, extra_blog_posts.ok()?, notifications.unwrap_or(Vec::new()))
+    } else if query_error.is_some() {
+        (Vec::new(), Vec::new(), notifications.await.unwrap_or(Vec::new()))
     } else {
         let (posts, notifications) = futures::join!(posts, notifications);
         (posts.ok()?, Vec::new(), notifications.unwrap_or(Vec::new()))
     };
 
     Some(template::Index {
-        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        alert: query_error
+            .or_else(|| rank_param.map(|rank| format!("Previewing the front page with the {} ranking", rank)))
+            .or_else(|| flash.map(|f| f.message().to_owned()))
+            .unwrap_or_else(String::new),
         config: config.inner().clone(),
         next_search_page: search.search_page + 1,
         customization, before_date_param, after_date_param,
@@ -541,13 +896,14 @@ async fn index(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Op
 async fn blog_index(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Option<FlashMessage<'_>>, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<template::Blog> {
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
 
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
     let (search, tags) = parse_index_params(&conn, &user, params).await?;
     let search = PostSearch {
         blog_post: Some(true),
         order_by: PostSearchOrderBy::Newest,
         .. search
     };
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
@@ -573,43 +929,188 @@ async fn advanced_search(conn: MoreInterestingConn, login: Option<LoginSession>,
 
     let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
     let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
-    let (search, tags) = parse_index_params(&conn, &user, params).await?;
-    let keywords_param = search.keywords.clone();
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
+    let (search, tags, query_error) = match parse_index_params_checked(&conn, &user, params).await? {
+        Ok((search, tags)) => (search, tags, None),
+        Err(e) => (PostSearch::with_my_user_id(user.id), Vec::new(), Some(e)),
+    };
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
+    let has_criteria = search.keywords != "" || search.title != "" || !tags.is_empty() || !search.hide_tags.is_empty() ||
+        !search.or_domains.is_empty() || search.for_user_id != 0 || search.before_date.is_some() ||
+        search.after_date.is_some() || search.locked.is_some();
+    let posts = if has_criteria && query_error.is_none() {
+        conn.search_posts(&search).await.ok()?
+    } else {
+        Vec::new()
+    };
+    let saved_searches = conn.list_saved_searches(user.id).await.unwrap_or(Vec::new());
     let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
+    // Dates and locking don't mean much for a post that was just submitted, so those can't be saved.
+    let can_save_search = has_criteria && query_error.is_none() &&
+        search.before_date.is_none() && search.after_date.is_none() && search.locked.is_none();
     Some(template::Search {
         title: "Advanced Search",
-        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        alert: query_error.or_else(|| flash.map(|f| f.message().to_owned())).unwrap_or_else(String::new),
         config: config.inner().clone(),
         next_search_page: search.search_page + 1,
-        noindex: false,
+        noindex: has_criteria,
         customization, before_date_param, after_date_param,
         user, tags, session, tag_param, domain, keywords_param,
-        title_param,
-        notifications,
+        title_param, posts, saved_searches,
+        can_save_search, notifications,
     })
 }
 
+#[derive(FromForm)]
+struct SaveSearchForm {
+    name: String,
+    q: Option<String>,
+    tag: Option<String>,
+    domain: Option<String>,
+    title: Option<String>,
+}
+
+#[post("/save-search", data = "<form>")]
+async fn save_search(conn: MoreInterestingConn, login: LoginSession, form: Form<SaveSearchForm>) -> Flash<Redirect> {
+    let form = form.into_inner();
+    let search_params = {
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        for &(key, value) in &[("q", &form.q), ("tag", &form.tag), ("domain", &form.domain), ("title", &form.title)] {
+            if let Some(value) = value.as_ref().filter(|value| !value.is_empty()) {
+                serializer.append_pair(key, value);
+            }
+        }
+        serializer.finish()
+    };
+    let back = format!("/search?{}", search_params);
+    let name = form.name.trim().to_owned();
+    if name == "" {
+        return Flash::error(Redirect::to(back), "Give the search a name to save it");
+    }
+    let params = IndexParams {
+        q: form.q,
+        tag: form.tag,
+        domain: form.domain,
+        title: form.title,
+        after: None,
+        page: None,
+        subscriptions: None,
+        before_date: None,
+        after_date: None,
+        user: None,
+        rank: None,
+    };
+    let search = match parse_index_params_checked(&conn, &login.user, Some(params)).await {
+        Some(Ok((search, _tags))) => search,
+        Some(Err(e)) => return Flash::error(Redirect::to(back), e),
+        None => return Flash::error(Redirect::to(back), "That search has a tag or domain that doesn't exist"),
+    };
+    if search.keywords == "" && search.title == "" && search.and_tags.is_empty() && search.or_tags.is_empty() &&
+        search.hide_tags.is_empty() && search.or_domains.is_empty() && search.for_user_id == 0 {
+        return Flash::error(Redirect::to(back), "That search would match every post; add some search terms first");
+    }
+    if search.before_date.is_some() || search.after_date.is_some() || search.locked.is_some() {
+        return Flash::error(Redirect::to(back), "Searches with before:, after: or is:locked can't be saved");
+    }
+    let new = NewSavedSearch {
+        user_id: login.user.id,
+        name, search_params,
+        keywords: search.keywords,
+        title: search.title,
+        and_tags: search.and_tags,
+        or_tags: search.or_tags,
+        hide_tags: search.hide_tags,
+        or_domains: search.or_domains,
+        for_user_id: search.for_user_id,
+    };
+    match conn.create_saved_search(new).await {
+        Ok(true) => Flash::success(Redirect::to(back), "Search saved; you'll get a notification when a new post matches it"),
+        Ok(false) => Flash::error(Redirect::to(back), format!("You can't have more than {} saved searches", models::MAX_SAVED_SEARCHES)),
+        Err(e) => {
+            warn!("Failed to save search: {:?}", e);
+            Flash::error(Redirect::to(back), "Failed to save search")
+        }
+    }
+}
+
+#[derive(FromForm)]
+struct DeleteSavedSearchForm {
+    saved_search: i32,
+}
+
+#[post("/delete-saved-search", data = "<form>")]
+async fn delete_saved_search(conn: MoreInterestingConn, login: LoginSession, form: Form<DeleteSavedSearchForm>) -> Flash<Redirect> {
+    match conn.delete_saved_search(login.user.id, form.saved_search).await {
+        Ok(()) => Flash::success(Redirect::to("/search"), "Saved search deleted"),
+        Err(e) => {
+            warn!("Failed to delete saved search: {:?}", e);
+            Flash::error(Redirect::to("/search"), "Failed to delete saved search")
+        }
+    }
+}
+
 #[derive(FromForm)]
 struct SearchCommentsParams {
     user: Option<String>,
+    post: Option<Base32>,
     after: Option<i32>,
+    q: Option<String>,
+    tag: Option<String>,
+    before_date: Option<String>,
+    after_date: Option<String>,
+    page: Option<i32>,
+    order: Option<String>,
+}
+
+/// Turn the query string into a comment search.
+///
+/// Tags here always match if any of them match, whether they're separated by spaces or pipes,
+/// since a comment search is usually about finding one discussion, not narrowing a feed.
+async fn parse_search_comments_params(conn: &MoreInterestingConn, user: &User, params: &Option<SearchCommentsParams>) -> Option<CommentSearch> {
+    let mut search = CommentSearch::with_my_user_id(user.id);
+    let params = if let Some(params) = params { params } else { return Some(search) };
+    if let Some(username) = &params.user {
+        search.for_user_id = Some(conn.get_user_by_username(&username[..]).await.into_option()?.id);
+    }
+    if let Some(post_uuid) = params.post {
+        search.for_post_id = Some(conn.get_post_info_by_uuid(user.id, post_uuid).await.into_option()?.id);
+    }
+    if let Some(tag_names) = &params.tag {
+        for tag_name in tag_names.split(|c: char| c == '|' || c.is_whitespace()).filter(|&t| t != "") {
+            search.or_tags.push(conn.get_tag_by_name(tag_name).await.into_option()?.id);
+        }
+    }
+    if let Some(q) = &params.q {
+        search.keywords = q.trim().to_owned();
+    }
+    search.before_date = params.before_date.as_ref().and_then(|d| d.parse::<NaiveDate>().ok());
+    search.after_date = params.after_date.as_ref().and_then(|d| d.parse::<NaiveDate>().ok());
+    search.order_by = match params.order.as_deref() {
+        None | Some("") | Some("newest") => CommentSearchOrderBy::Newest,
+        Some("relevance") => CommentSearchOrderBy::Relevance,
+        Some(_) => return None,
+    };
+    search.after_id = params.after;
+    search.search_page = params.page.unwrap_or(0).max(0);
+    Some(search)
 }
 
 #[get("/comments?<params..>")]
 async fn search_comments(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Option<FlashMessage<'_>>, params: Option<SearchCommentsParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Either<template::ProfileComments, template::IndexComments>> {
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
-    if let Some(username) = params.as_ref().and_then(|params| params.user.as_ref()) {
-        let by_user = conn.get_user_by_username(&username[..]).await.into_option()?;
-        let comment_search_result = conn.search_comments(
-            Some(by_user.id),
-            params.as_ref().and_then(|params| params.after),
-            user.id
-        ).await.unwrap_or(Vec::new());
+    let search = parse_search_comments_params(&conn, &user, &params).await?;
+    let comment_search_result = conn.search_comments(&search).await.unwrap_or(Vec::new());
+    let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
+    let keywords_param = search.keywords.clone();
+    let tag_param = params.as_ref().and_then(|params| params.tag.clone()).unwrap_or_else(String::new);
+    let order_param = if search.order_by == CommentSearchOrderBy::Relevance { "relevance" } else { "" };
+    let next_search_page = search.search_page + 1;
+    if let Some(by_user_id) = search.for_user_id {
+        let by_user = conn.get_user_by_id(by_user_id).await.into_option()?;
         let title = by_user.username.clone();
-        let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
         Some(Either::A(template::ProfileComments {
             alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
             config: config.inner().clone(),
@@ -618,14 +1119,11 @@ async fn search_comments(conn: MoreInterestingConn, login: Option<LoginSession>,
             title, user, comment_search_result, session,
             noindex: true,
             notifications,
+            keywords_param, tag_param, order_param, next_search_page,
+            before_date_param: search.before_date,
+            after_date_param: search.after_date,
         }))
     } else {
-        let comment_search_result = conn.search_comments(
-            None,
-            params.as_ref().and_then(|params| params.after),
-            user.id
-        ).await.unwrap_or(Vec::new());
-        let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
         Some(Either::B(template::IndexComments {
             title: String::from("comments"),
             alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
@@ -634,21 +1132,18 @@ async fn search_comments(conn: MoreInterestingConn, login: Option<LoginSession>,
             user, comment_search_result, session,
             noindex: true,
             notifications,
+            keywords_param, tag_param, order_param, next_search_page,
+            before_date_param: search.before_date,
+            after_date_param: search.after_date,
         }))
     }
 }
 
-#[get("/top?<timespan>&<params..>")]
-async fn top(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, params: Option<IndexParams>, customization: Customization, timespan: Option<Timespan>) -> Option<template::IndexTop> {
-    let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
-    let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
-    let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
-    let (search, tags) = parse_index_params(&conn, &user, params).await?;
-    let mut search = PostSearch {
-        order_by: PostSearchOrderBy::Top,
-        blog_post: Some(false),
-        .. search
-    };
+/// Narrow a top-posts search down to the requested timespan.
+///
+/// If no timespan is given, this defaults to the last month, unless the search already
+/// has an explicit date range.
+fn apply_timespan(search: &mut PostSearch, timespan: Option<Timespan>) -> Timespan {
     let timespan = timespan.unwrap_or_else(|| if search.before_date.is_none() && search.after_date.is_none() { Timespan::Month } else { Timespan::All });
     match timespan {
         Timespan::Year => {
@@ -669,9 +1164,25 @@ async fn top(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Opti
         }
         Timespan::All => {}
     }
+    timespan
+}
+
+#[get("/top?<timespan>&<params..>")]
+async fn top(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, params: Option<IndexParams>, customization: Customization, timespan: Option<Timespan>) -> Option<template::IndexTop> {
+    let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
+    let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
+    let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
+    let (search, tags) = parse_index_params(&conn, &user, params).await?;
+    let mut search = PostSearch {
+        order_by: PostSearchOrderBy::Top,
+        blog_post: Some(false),
+        .. search
+    };
+    let timespan = apply_timespan(&mut search, timespan);
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let is_home = tag_param == "" && domain == "" && keywords_param == "";
     let posts = conn.search_posts(&search).await.ok()?;
@@ -695,6 +1206,7 @@ async fn subscriptions(conn: MoreInterestingConn, login: Option<LoginSession>, f
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
     let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
     let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
     let (search, tags) = parse_index_params(&conn, &user, params).await?;
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
@@ -704,7 +1216,7 @@ async fn subscriptions(conn: MoreInterestingConn, login: Option<LoginSession>, f
         blog_post: None,
         .. search
     };
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let is_home = tag_param == "" && domain == "" && keywords_param == "";
     let posts = conn.search_posts(&search).await.ok()?;
@@ -756,6 +1268,7 @@ async fn new(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Opti
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
     let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
     let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
     let (search, tags) = parse_index_params(&conn, &user, params).await?;
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
@@ -764,7 +1277,7 @@ async fn new(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Opti
         blog_post: Some(false),
         .. search
     };
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let is_home = tag_param == "" && domain == "" && keywords_param == "";
     let posts = conn.search_posts(&search).await.ok()?;
@@ -787,6 +1300,7 @@ async fn latest(conn: MoreInterestingConn, login: Option<LoginSession>, params:
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
     let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
     let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
     let (search, tags) = parse_index_params(&conn, &user, params).await?;
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
@@ -795,7 +1309,7 @@ async fn latest(conn: MoreInterestingConn, login: Option<LoginSession>, params:
         blog_post: Some(false),
         .. search
     };
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let is_home = tag_param == "" && domain == "" && keywords_param == "";
     let posts = conn.search_posts(&search).await.ok()?;
@@ -814,6 +1328,8 @@ async fn latest(conn: MoreInterestingConn, login: Option<LoginSession>, params:
 }
 
 pub const RSS: MediaType = MediaType::const_new("application", "rss+xml", &[]);
+pub const ATOM: MediaType = MediaType::const_new("application", "atom+xml", &[]);
+pub const JSON_FEED: MediaType = MediaType::const_new("application", "feed+json", &[]);
 
 pub struct Rss<R>(pub R);
 
@@ -825,84 +1341,412 @@ impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Rss<R> {
     }
 }
 
-#[get("/rss")]
-async fn rss(conn: MoreInterestingConn, config: &State<SiteConfig>, customization: Customization) -> Option<Rss<String>> {
-    let search = PostSearch {
-        order_by: PostSearchOrderBy::Newest,
-        blog_post: Some(false),
-        .. PostSearch::with_my_user_id(0)
-    };
-    let posts = conn.search_posts(&search).await.ok()?;
-    Some(Rss(template::Rss {
-        posts,
-        config: config.inner().clone(),
-        link: config.public_url.to_string(),
-        customization,
-    }.render().unwrap()))
+pub struct Atom<R>(pub R);
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Atom<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut r = self.0.respond_to(req)?;
+        r.set_header(ContentType::from(ATOM));
+        Ok(r)
+    }
+}
+
+pub struct JsonFeed<R>(pub R);
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for JsonFeed<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut r = self.0.respond_to(req)?;
+        r.set_header(ContentType::from(JSON_FEED));
+        Ok(r)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+#[derive(Responder)]
+enum Feed {
+    Rss(Rss<String>),
+    Atom(Atom<String>),
+    Json(JsonFeed<String>),
 }
 
-#[get("/blog.rss")]
-async fn blog_rss(conn: MoreInterestingConn, config: &State<SiteConfig>, customization: Customization) -> Option<Rss<String>> {
+/// Feeds take the same parameters as the HTML listings, but always show the newest posts
+/// as seen by a logged-out user.
+async fn post_feed(conn: MoreInterestingConn, params: Option<IndexParams>, config: &SiteConfig, customization: Customization, format: FeedFormat, blog_post: bool) -> Option<Feed> {
+    let mut feed_title = customization.title.clone();
+    if let Some(params) = &params {
+        let filters: Vec<String> = [("tag", &params.tag), ("domain", &params.domain), ("q", &params.q), ("user", &params.user)]
+            .iter()
+            .filter_map(|&(name, value)| Some(format!("{}:{}", name, value.as_ref()?)))
+            .collect();
+        if !filters.is_empty() {
+            feed_title = format!("{} ({})", feed_title, filters.join(" "));
+        }
+    }
+    let (search, _tags) = parse_index_params(&conn, &User::default(), params).await?;
     let search = PostSearch {
         order_by: PostSearchOrderBy::Newest,
-        blog_post: Some(true),
-        .. PostSearch::with_my_user_id(0)
+        blog_post: Some(blog_post),
+        .. search
     };
     let posts = conn.search_posts(&search).await.ok()?;
-    Some(Rss(template::BlogRss {
-        posts,
-        config: config.inner().clone(),
-        link: config.public_url.to_string(),
-        customization,
-    }.render().unwrap()))
+    let link = if blog_post {
+        config.public_url.join("blog").ok()?.to_string()
+    } else {
+        config.public_url.to_string()
+    };
+    let updated = posts.first().map(|post| post.created_at).unwrap_or_else(|| Utc::now().naive_utc());
+    Some(match format {
+        FeedFormat::Rss if blog_post => Feed::Rss(Rss(template::BlogRss {
+            config: config.clone(),
+            feed_title, link, posts, customization,
+        }.render().unwrap())),
+        FeedFormat::Rss => Feed::Rss(Rss(template::Rss {
+            config: config.clone(),
+            feed_title, link, posts, customization,
+        }.render().unwrap())),
+        FeedFormat::Atom => Feed::Atom(Atom(template::Atom {
+            config: config.clone(),
+            updated: template::filters::rfc3339(&updated).unwrap(),
+            feed_title, link, posts, customization,
+        }.render().unwrap())),
+        FeedFormat::Json => {
+            let items: Vec<serde_json::Value> = posts.iter().map(|post| {
+                let post_url = format!("{}{}", config.public_url, post.uuid);
+                let author = if post.anon { "anonymous" } else { &post.submitted_by_username[..] };
+                serde_json::json!({
+                    "id": post_url,
+                    "url": post_url,
+                    "external_url": post.url,
+                    "title": post.title,
+                    "content_html": post.excerpt_html.as_deref().unwrap_or(""),
+                    "date_published": template::filters::rfc3339(&post.created_at).unwrap(),
+                    "authors": [{
+                        "name": author,
+                    }],
+                })
+            }).collect();
+            let feed = serde_json::json!({
+                "version": "https://jsonfeed.org/version/1.1",
+                "title": feed_title,
+                "home_page_url": link,
+                "items": items,
+            });
+            Feed::Json(JsonFeed(serde_json::to_string(&feed).ok()?))
+        }
+    })
 }
 
-#[derive(FromForm)]
-struct ModLogParams {
-    after: Option<i32>,
+/// A feed of the comments on a single post.
+///
+/// Private posts don't get feeds, since the feed reader wouldn't be logged in.
+async fn comment_feed(conn: MoreInterestingConn, uuid: Base32, config: &SiteConfig, customization: Customization, format: FeedFormat) -> Option<Feed> {
+    let post_info = conn.get_post_info_by_uuid(0, uuid).await.ok()?;
+    if post_info.private || !post_info.visible {
+        return None;
+    }
+    let comments = conn.get_comments_from_post(post_info.id, 0).await.into_option()?;
+    let link = config.public_url.join(&post_info.uuid.to_string()).ok()?.to_string();
+    let updated = comments.last().map(|comment| comment.created_at).unwrap_or(post_info.created_at);
+    Some(match format {
+        FeedFormat::Rss => Feed::Rss(Rss(template::CommentsRss {
+            config: config.clone(),
+            link, post_info, comments, customization,
+        }.render().unwrap())),
+        FeedFormat::Atom => Feed::Atom(Atom(template::CommentsAtom {
+            config: config.clone(),
+            updated: template::filters::rfc3339(&updated).unwrap(),
+            link, post_info, comments, customization,
+        }.render().unwrap())),
+        FeedFormat::Json => {
+            let items: Vec<serde_json::Value> = comments.iter().map(|comment| {
+                let comment_url = format!("{}?comment={}#{}", link, comment.id, comment.id);
+                serde_json::json!({
+                    "id": comment_url,
+                    "url": comment_url,
+                    "content_html": comment.html,
+                    "date_published": template::filters::rfc3339(&comment.created_at).unwrap(),
+                    "authors": [{
+                        "name": comment.created_by_username,
+                    }],
+                })
+            }).collect();
+            let feed = serde_json::json!({
+                "version": "https://jsonfeed.org/version/1.1",
+                "title": format!("{} - {}", post_info.title, customization.title),
+                "home_page_url": link,
+                "items": items,
+            });
+            Feed::Json(JsonFeed(serde_json::to_string(&feed).ok()?))
+        }
+    })
 }
 
-#[get("/mod-log?<params..>")]
-async fn mod_log(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Option<FlashMessage<'_>>, params: Option<ModLogParams>, config: &State<SiteConfig>, customization: Customization) -> template::ModLog {
-    let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
-    let log = if let Some(after) = params.as_ref().and_then(|params| params.after) {
-        conn.get_mod_log_starting_with(after).await
-    } else {
-        conn.get_mod_log_recent().await
-    }.unwrap_or(Vec::new());
-    let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
-    template::ModLog {
-        title: String::from("mod log"),
-        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
-        config: config.inner().clone(),
-        noindex: true,
-        customization,
-        user, log, session,
-        notifications,
-    }
+#[get("/rss?<params..>")]
+async fn rss(conn: MoreInterestingConn, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    post_feed(conn, params, config.inner(), customization, FeedFormat::Rss, false).await
 }
 
-#[get("/post")]
-async fn create_post_form(conn: MoreInterestingConn, login: LoginSession, config: &State<SiteConfig>, customization: Customization) -> template::CreatePost {
-    let (user, session) = (login.user, login.session);
-    let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
+#[get("/atom?<params..>")]
+async fn atom(conn: MoreInterestingConn, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    post_feed(conn, params, config.inner(), customization, FeedFormat::Atom, false).await
+}
 
-    let submitted_by_username_urlencode = utf8_percent_encode(&user.username, NON_ALPHANUMERIC).to_string();
-    let submitted_by_username = user.username.clone();
+#[get("/feed.json?<params..>")]
+async fn json_feed(conn: MoreInterestingConn, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    post_feed(conn, params, config.inner(), customization, FeedFormat::Json, false).await
+}
 
-    template::CreatePost {
-        post: PostInfo {
-            title: String::new(),
-            url: None,
-            title_html: String::new(),
-            excerpt_html: None,
-            uuid: Base32::from(0i64),
-            id: 0,
-            visible: true,
-            private: false,
-            noindex: false,
-            locked: false,
+#[get("/blog.rss?<params..>")]
+async fn blog_rss(conn: MoreInterestingConn, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    post_feed(conn, params, config.inner(), customization, FeedFormat::Rss, true).await
+}
+
+#[get("/blog.atom?<params..>")]
+async fn blog_atom(conn: MoreInterestingConn, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    post_feed(conn, params, config.inner(), customization, FeedFormat::Atom, true).await
+}
+
+#[get("/blog.json?<params..>")]
+async fn blog_json_feed(conn: MoreInterestingConn, params: Option<IndexParams>, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    post_feed(conn, params, config.inner(), customization, FeedFormat::Json, true).await
+}
+
+#[get("/post/<uuid>/comments.rss")]
+async fn comments_rss(conn: MoreInterestingConn, uuid: Base32, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    comment_feed(conn, uuid, config.inner(), customization, FeedFormat::Rss).await
+}
+
+#[get("/post/<uuid>/comments.atom")]
+async fn comments_atom(conn: MoreInterestingConn, uuid: Base32, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    comment_feed(conn, uuid, config.inner(), customization, FeedFormat::Atom).await
+}
+
+#[get("/post/<uuid>/comments.json")]
+async fn comments_json_feed(conn: MoreInterestingConn, uuid: Base32, config: &State<SiteConfig>, customization: Customization) -> Option<Feed> {
+    comment_feed(conn, uuid, config.inner(), customization, FeedFormat::Json).await
+}
+
+/// New comments on a post, as they become visible, for readers who have it open.
+#[get("/post/<uuid>/live")]
+async fn live_comments<'r>(conn: MoreInterestingConn, login: Option<LoginSession>, uuid: Base32, app: live::App<'r>, mut shutdown: rocket::Shutdown, customization: Customization) -> Option<EventStream![Event + 'r]> {
+    let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
+    let post_info = conn.get_post_info_by_uuid(user.id, uuid).await.ok()?;
+    // The page can stay open for hours, so don't hold on to a database connection the whole time.
+    drop(conn);
+    let mut receiver = live::subscribe(post_info.id);
+    let mut seen_ids = Vec::new();
+    Some(EventStream! {
+        loop {
+            let comment_id = rocket::tokio::select! {
+                comment_id = receiver.recv() => match comment_id {
+                    Some(comment_id) => comment_id,
+                    None => break,
+                },
+                _ = &mut shutdown => break,
+            };
+            let conn = match MoreInterestingConn::get_one(app.rocket).await {
+                Some(conn) => conn,
+                None => continue,
+            };
+            // Like the post page, this leaves out comments that aren't visible, such as ones from shadowbanned users.
+            let comment = match conn.get_comment_info_by_id(comment_id, user.id).await {
+                Ok(comment) => comment,
+                Err(_) => continue,
+            };
+            let html = match (template::ViewComment { comment, post_info: &post_info, user: &user, session: &session, customization: &customization }).render() {
+                Ok(html) => html,
+                Err(e) => {
+                    warn!("Failed to render live comment {}: {:?}", comment_id, e);
+                    continue;
+                }
+            };
+            // A moderator can approve an old comment, so this can't just jump to the newest one.
+            if user.id != 0 {
+                seen_ids.push(comment_id);
+                conn.advance_readpoint(post_info.id, user.id, seen_ids.clone()).await.unwrap_or_else(|e| {
+                    warn!("Failed to set readpoint: {:?}", e);
+                });
+            }
+            yield Event::data(html).event("comment").id(comment_id.to_string());
+        }
+    })
+}
+
+#[get("/private-feed/<uuid>")]
+async fn private_feed(conn: MoreInterestingConn, uuid: Base32, config: &State<SiteConfig>, customization: Customization) -> Option<Rss<String>> {
+    let feed_user = conn.get_user_by_private_feed_token(uuid).await.ok()?;
+    if feed_user.banned || conn.get_active_suspension(feed_user.id).await.is_some() {
+        return None;
+    }
+    let (notifications, comments) = futures::join!(
+        conn.list_notifications(feed_user.id),
+        conn.list_unread_subscribed_comments(feed_user.id),
+    );
+    Some(Rss(template::PrivateFeedRss {
+        config: config.inner().clone(),
+        link: config.public_url.join("subscriptions").ok()?.to_string(),
+        notifications: notifications.unwrap_or(Vec::new()),
+        comments: comments.unwrap_or(Vec::new()),
+        feed_user, customization,
+    }.render().unwrap()))
+}
+
+#[post("/reset-private-feed")]
+async fn reset_private_feed(conn: MoreInterestingConn, login: LoginSession) -> Flash<Redirect> {
+    match conn.reset_private_feed_token(login.user.id).await {
+        Ok(_) => Flash::success(Redirect::to(uri!(get_settings)), "Created a new private feed URL; any old one no longer works"),
+        Err(e) => {
+            warn!("Failed to reset private feed: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to create private feed")
+        }
+    }
+}
+
+#[post("/revoke-private-feed")]
+async fn revoke_private_feed(conn: MoreInterestingConn, login: LoginSession) -> Flash<Redirect> {
+    match conn.revoke_private_feed_token(login.user.id).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Private feed turned off"),
+        Err(e) => {
+            warn!("Failed to revoke private feed: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to turn off private feed")
+        }
+    }
+}
+
+/// The most entries one export will hold.
+///
+/// Exports are this big so moderators can audit the whole log at once, which is why only they can make them.
+const MOD_LOG_EXPORT_LIMIT: i64 = 100_000;
+
+#[derive(FromForm)]
+struct ModLogParams {
+    after: Option<i32>,
+    action: Option<String>,
+    moderator: Option<String>,
+    target: Option<String>,
+    after_date: Option<String>,
+    before_date: Option<String>,
+}
+
+impl ModLogParams {
+    fn filter_query(&self) -> String {
+        let params = [
+            ("action", &self.action),
+            ("moderator", &self.moderator),
+            ("target", &self.target),
+            ("after_date", &self.after_date),
+            ("before_date", &self.before_date),
+        ];
+        params.iter()
+            .filter_map(|(name, value)| value.as_ref().filter(|value| *value != "").map(|value| (name, value)))
+            .map(|(name, value)| format!("{}={}", name, utf8_percent_encode(value, NON_ALPHANUMERIC)))
+            .collect::<Vec<String>>()
+            .join("&")
+    }
+}
+
+/// Returns `None` if the filters name a user who doesn't exist, since then nothing can match.
+async fn mod_log_search(conn: &MoreInterestingConn, params: &Option<ModLogParams>) -> Option<ModLogSearch> {
+    let mut search = ModLogSearch::default();
+    let params = if let Some(params) = params { params } else { return Some(search) };
+    search.before_id = params.after;
+    search.kind = params.action.clone().unwrap_or(String::new());
+    if let Some(moderator) = params.moderator.as_ref().filter(|moderator| *moderator != "") {
+        search.created_by = Some(conn.get_user_by_username(moderator).await.ok()?.id);
+    }
+    if let Some(target) = params.target.as_ref().filter(|target| *target != "") {
+        search.target_user_id = Some(conn.get_user_by_username(target).await.ok()?.id);
+    }
+    search.after_date = params.after_date.as_ref().and_then(|d| d.parse::<NaiveDate>().ok());
+    search.before_date = params.before_date.as_ref().and_then(|d| d.parse::<NaiveDate>().ok());
+    Some(search)
+}
+
+#[get("/mod-log?<params..>")]
+async fn mod_log(conn: MoreInterestingConn, login: Option<LoginSession>, flash: Option<FlashMessage<'_>>, params: Option<ModLogParams>, config: &State<SiteConfig>, customization: Customization) -> template::ModLog {
+    let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
+    let log = match mod_log_search(&conn, &params).await {
+        Some(search) => conn.search_mod_log(search).await.unwrap_or(Vec::new()),
+        None => Vec::new(),
+    };
+    let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
+    let action = params.as_ref().and_then(|params| params.action.clone()).unwrap_or(String::new());
+    let kinds = mod_action::KINDS.iter().map(|&(kind, label)| (kind, label, kind == action)).collect();
+    let param = |f: fn(&ModLogParams) -> &Option<String>| params.as_ref().and_then(|params| f(params).clone()).unwrap_or(String::new());
+    template::ModLog {
+        title: String::from("mod log"),
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        config: config.inner().clone(),
+        noindex: true,
+        moderator_param: param(|params| &params.moderator),
+        target_param: param(|params| &params.target),
+        after_date_param: param(|params| &params.after_date),
+        before_date_param: param(|params| &params.before_date),
+        filter_query: params.as_ref().map(|params| params.filter_query()).unwrap_or(String::new()),
+        customization,
+        user, log, session,
+        notifications, kinds,
+    }
+}
+
+#[get("/mod-log.json?<params..>")]
+async fn mod_log_json(conn: MoreInterestingConn, _login: ModeratorSession, params: Option<ModLogParams>) -> Option<content::RawJson<String>> {
+    let log = match mod_log_search(&conn, &params).await {
+        Some(search) => conn.search_mod_log(ModLogSearch { limit: MOD_LOG_EXPORT_LIMIT, ..search }).await.ok()?,
+        None => Vec::new(),
+    };
+    Some(content::RawJson(serde_json::to_string(&log).ok()?))
+}
+
+#[get("/mod-log.csv?<params..>")]
+async fn mod_log_csv(conn: MoreInterestingConn, _login: ModeratorSession, params: Option<ModLogParams>) -> Option<(ContentType, String)> {
+    let log = match mod_log_search(&conn, &params).await {
+        Some(search) => conn.search_mod_log(ModLogSearch { limit: MOD_LOG_EXPORT_LIMIT, ..search }).await.ok()?,
+        None => Vec::new(),
+    };
+    let mut csv = mod_action::csv_line(&["id", "created_at", "moderator", "action", "target_user", "payload"]);
+    for entry in log {
+        csv.push_str(&mod_action::csv_line(&[
+            entry.id.to_string(),
+            entry.created_at.to_string(),
+            entry.created_by_username,
+            entry.kind,
+            entry.target_username.unwrap_or(String::new()),
+            entry.payload.to_string(),
+        ]));
+    }
+    Some((ContentType::CSV, csv))
+}
+
+#[get("/post")]
+async fn create_post_form(conn: MoreInterestingConn, login: LoginSession, config: &State<SiteConfig>, customization: Customization) -> template::CreatePost {
+    let (user, session) = (login.user, login.session);
+    let notifications = conn.list_notifications(user.id).await.unwrap_or(Vec::new());
+
+    let submitted_by_username_urlencode = utf8_percent_encode(&user.username, NON_ALPHANUMERIC).to_string();
+    let submitted_by_username = user.username.clone();
+
+    template::CreatePost {
+        post: PostInfo {
+            title: String::new(),
+            url: None,
+            title_html: String::new(),
+            excerpt_html: None,
+            headline_html: None,
+            uuid: Base32::from(0i64),
+            id: 0,
+            visible: true,
+            private: false,
+            noindex: false,
+            locked: false,
             hotness: 0.0,
+            stellar_age: 0,
             score: 0,
             comment_count: 0,
             comment_readpoint: None,
@@ -978,6 +1822,7 @@ async fn post_preview(login: Option<LoginSession>, customization: Customization,
         url,
         title_html: title_html,
         excerpt_html: Some(body_html),
+        headline_html: None,
         uuid: Base32::from(0i64),
         id: 0,
         visible: true,
@@ -985,6 +1830,7 @@ async fn post_preview(login: Option<LoginSession>, customization: Customization,
         noindex: false,
         locked: false,
         hotness: 0.0,
+        stellar_age: 0,
         score: 0,
         comment_count: 0,
         comment_readpoint: None,
@@ -1056,6 +1902,7 @@ async fn create_link_form(login: LoginSession, config: &State<SiteConfig>, conn:
             url: None,
             title_html: String::new(),
             excerpt_html: None,
+            headline_html: None,
             uuid: Base32::from(0i64),
             id: 0,
             visible: true,
@@ -1063,6 +1910,7 @@ async fn create_link_form(login: LoginSession, config: &State<SiteConfig>, conn:
             noindex: false,
             locked: false,
             hotness: 0.0,
+            stellar_age: 0,
             score: 0,
             comment_count: 0,
             comment_readpoint: None,
@@ -1143,6 +1991,7 @@ async fn submit_preview(login: Option<LoginSession>, customization: Customizatio
         url,
         title_html: title_html,
         excerpt_html: Some(body_html),
+        headline_html: None,
         uuid: Base32::from(0i64),
         id: 0,
         visible: true,
@@ -1150,6 +1999,7 @@ async fn submit_preview(login: Option<LoginSession>, customization: Customizatio
         noindex: false,
         locked: false,
         hotness: 0.0,
+        stellar_age: 0,
         score: 0,
         comment_count: 0,
         comment_readpoint: None,
@@ -1205,6 +2055,31 @@ async fn submit_preview(login: Option<LoginSession>, customization: Customizatio
     }))
 }
 
+/// The compiled blocked regexes, loading them again if a moderator changed them.
+async fn blocked_regex_rules(conn: &MoreInterestingConn) -> Arc<blocked_regex::RuleSet> {
+    let generation = conn.get_blocked_regex_generation().await;
+    if let Some(rules) = generation.as_ref().ok().and_then(|&generation| blocked_regex::cached(generation)) {
+        return rules;
+    }
+    let blocked_regexes = conn.get_all_blocked_regexes().await.unwrap_or(Vec::new());
+    let rules = Arc::new(blocked_regex::RuleSet::new(blocked_regexes.iter().filter_map(|blocked_regex| blocked_regex.to_rule()).collect()));
+    match generation {
+        Ok(generation) => blocked_regex::store(generation, rules.clone()),
+        Err(e) => warn!("Failed to check whether the blocked regexes changed: {:?}", e),
+    }
+    rules
+}
+
+/// Check each part of a submission, and count a hit for every rule that matched.
+async fn check_blocked_regexes(conn: &MoreInterestingConn, parts: &[(Scope, &str)]) -> Option<Verdict> {
+    let rules = blocked_regex_rules(conn).await;
+    let verdict = parts.iter()
+        .filter_map(|&(scope, text)| rules.check(scope, text))
+        .reduce(Verdict::and)?;
+    conn.record_blocked_regex_hits(verdict.rule_ids.clone()).await.unwrap_or_else(|e| warn!("Failed to count blocked regex hits: {:?}", e));
+    Some(verdict)
+}
+
 #[post("/submit", data = "<post>")]
 async fn create(login: LoginSession, conn: MoreInterestingConn, post: Form<NewPostForm>, config: &State<SiteConfig>) -> Result<Flash<Redirect>, Status> {
     lazy_static!{
@@ -1221,19 +2096,26 @@ async fn create(login: LoginSession, conn: MoreInterestingConn, post: Form<NewPo
 
     let NewPostForm { title, url, excerpt, tags, blog_post, anon, .. } = &*post;
 
-    if let Some(excerpt) = &excerpt {
-        let blocked_regexes = conn.get_all_blocked_regexes().await.unwrap_or(Vec::new());
-        for blocked_regex in blocked_regexes {
-            let regex = if let Ok(regex) = Regex::new(&blocked_regex.regex) {
-                regex
-            } else {
-                continue
-            };
-            if regex.is_match(&excerpt) {
-                conn.change_user_trust_level(user.id, -3).await.expect("if voting works, then so should switching trust level");
-                user.trust_level = -3;
-            }
+    let mut queued = false;
+    match check_blocked_regexes(&conn, &[
+        (Scope::Title, title),
+        (Scope::Excerpt, excerpt.as_ref().map(|x| &x[..]).unwrap_or("")),
+        (Scope::Url, url.as_ref().map(|x| &x[..]).unwrap_or("")),
+    ]).await {
+        Some(Verdict { action: blocked_regex::Action::Reject, message, .. }) => {
+            return Ok(Flash::error(Redirect::to("submit".to_string()), message))
         }
+        Some(Verdict { action: blocked_regex::Action::Queue, .. }) => queued = true,
+        Some(Verdict { action: blocked_regex::Action::Lock, .. }) if user.trust_level > -2 => {
+            conn.change_user_trust_level(user.id, -2).await.expect("if voting works, then so should switching trust level");
+            user.trust_level = -2;
+        }
+        Some(Verdict { action: blocked_regex::Action::Lock, .. }) => {}
+        Some(Verdict { action: blocked_regex::Action::Shadowban, .. }) => {
+            conn.change_user_trust_level(user.id, -3).await.expect("if voting works, then so should switching trust level");
+            user.trust_level = -3;
+        }
+        None => {}
     }
 
     let mut title = title.clone();
@@ -1255,7 +2137,7 @@ async fn create(login: LoginSession, conn: MoreInterestingConn, post: Form<NewPo
         }
     });
     let visible = if url.is_some() {
-        user.trust_level > 0i32
+        user.trust_level > 0i32 && !queued
     } else if user.trust_level <= 0i32 {
         return Err(Status::BadRequest);
     } else {
@@ -1283,6 +2165,9 @@ async fn create(login: LoginSession, conn: MoreInterestingConn, post: Form<NewPo
         Err(CreatePostError::TooManyPostsDomainUser) => {
             Ok(Flash::error(Redirect::to("submit".to_string()), "You have submitted too many posts from this particular domain; try submitting quotes from somewhere else"))
         }
+        Err(CreatePostError::DomainBlocked) => {
+            Ok(Flash::error(Redirect::to("submit".to_string()), "Posts from this domain name aren't allowed; try submitting quotes from somewhere else"))
+        }
         Err(CreatePostError::RequireTag) => {
             Ok(Flash::error(Redirect::to("submit".to_string()), "Please specify at least one tag"))
         }
@@ -1390,42 +2275,430 @@ struct UserForm {
     password: String,
 }
 
+/// Finish logging in, once the user has passed every factor.
+async fn start_session(conn: &MoreInterestingConn, user: &User, cookies: &CookieJar<'_>, user_agent: &str) {
+    let session = conn.create_session(user.id, user_agent).await.expect("failed to allocate a session");
+    let cookie = Cookie::build("U", session.uuid.to_string()).path("/").permanent().same_site(SameSite::None).finish();
+    cookies.add(cookie);
+    let cookie = Cookie::build("N", user.username.to_string()).path("/").permanent().same_site(SameSite::None).finish();
+    cookies.add(cookie);
+}
+
+fn ip_address_string(ip: ClientIp) -> String {
+    ip.ip.map(|ip| ip.to_string()).unwrap_or_else(|| String::from("unknown"))
+}
+
+/// Check whether someone is allowed to try a password right now,
+/// considering recent failures for both the account (if there is one) and their IP address.
+async fn check_login_throttle(conn: &MoreInterestingConn, config: &SiteConfig, username: Option<&str>, ip_address: &str) -> throttle::Verdict {
+    let now = Utc::now().naive_utc();
+    let since = now - Duration::minutes(config.login_lockout_minutes);
+    let failures = match conn.get_login_failures(username.map(ToOwned::to_owned), ip_address.to_owned(), since).await {
+        Ok(failures) => failures,
+        Err(e) => {
+            warn!("Failed to check login throttle: {:?}", e);
+            models::LoginFailures::default()
+        }
+    };
+    let account = throttle::check(&config.account_throttle(), &failures.account, now);
+    let ip = throttle::check(&config.ip_throttle(), &failures.ip_address, now);
+    account.and(ip)
+}
+
+/// Reset requests have their own count, because each one sends mail, but they aren't failed logins.
+async fn check_password_reset_throttle(conn: &MoreInterestingConn, config: &SiteConfig, username: &str, ip_address: &str) -> throttle::Verdict {
+    let now = Utc::now().naive_utc();
+    let since = now - Duration::minutes(config.login_lockout_minutes);
+    let requests = match conn.get_password_reset_requests(username.to_owned(), ip_address.to_owned(), since).await {
+        Ok(requests) => requests,
+        Err(e) => {
+            warn!("Failed to check password reset throttle: {:?}", e);
+            models::LoginFailures::default()
+        }
+    };
+    let account = throttle::check(&config.account_throttle(), &requests.account, now);
+    let ip = throttle::check(&config.ip_throttle(), &requests.ip_address, now);
+    account.and(ip)
+}
+
+async fn record_login_attempt(conn: &MoreInterestingConn, kind: models::LoginAttemptKind, username: &str, user_id: Option<i32>, ip_address: &str, succeeded: bool) {
+    let result = conn.record_login_attempt(models::NewLoginAttempt {
+        username: username.to_owned(),
+        ip_address: ip_address.to_owned(),
+        kind, user_id, succeeded,
+    }).await;
+    if let Err(e) = result {
+        warn!("Failed to record login attempt: {:?}", e);
+    }
+}
+
+fn suspension_message(suspension: &models::Suspension) -> String {
+    format!("You're suspended until {} UTC: {}", suspension.ends_at.format("%Y-%m-%d %H:%M"), suspension.reason)
+}
+
+/// Everything that happens after the user proves who they are with a password or single sign-on.
+async fn finish_login(conn: &MoreInterestingConn, user: &User, cookies: &CookieJar<'_>, user_agent: &str) -> Flash<Redirect> {
+    if user.trust_level <= -3 || user.banned {
+        let cookie = Cookie::build("B", "1").path("/").permanent().same_site(SameSite::None).finish();
+        cookies.add(cookie);
+    }
+    if user.banned {
+        return Flash::error(Redirect::to("/"), "Sorry. Not sorry. You're banned.");
+    }
+    if let Some(suspension) = conn.get_active_suspension(user.id).await {
+        return Flash::error(Redirect::to("/"), suspension_message(&suspension));
+    }
+    if conn.has_totp_enabled(user.id).await {
+        let pending = conn.create_pending_login(user.id).await.expect("failed to allocate a pending login");
+        let cookie = Cookie::build("T", pending.to_string()).path("/").http_only(true).same_site(SameSite::Lax).finish();
+        cookies.add(cookie);
+        return Flash::success(Redirect::to("/login-2fa"), "Enter the code from your authenticator app");
+    }
+    start_session(conn, user, cookies, user_agent).await;
+    Flash::success(Redirect::to("/"), "Congrats, you're in!")
+}
+
 #[post("/login", data = "<post>")]
-async fn login(conn: MoreInterestingConn, post: Form<UserForm>, cookies: &CookieJar<'_>, user_agent: UserAgentString<'_>) -> Flash<Redirect> {
+async fn login(conn: MoreInterestingConn, post: Form<UserForm>, cookies: &CookieJar<'_>, user_agent: UserAgentString<'_>, ip: ClientIp, config: &State<SiteConfig>) -> Flash<Redirect> {
+    let ip_address = ip_address_string(ip);
+    if let Some(message) = check_login_throttle(&conn, config, Some(&post.username), &ip_address).await.message() {
+        return Flash::error(Redirect::to("login"), message);
+    }
     match conn.authenticate_user(&UserAuth {
         username: post.username.clone(),
         password: post.password.clone(),
     }).await {
         Some(user) => {
-            if user.trust_level <= -3 || user.banned {
-                let cookie = Cookie::build("B", "1").path("/").permanent().same_site(SameSite::None).finish();
-                cookies.add(cookie);
+            // With two-factor authentication on, the success gets recorded when the code is right instead.
+            // Recording it now would reset the count for someone who has the password and is guessing codes.
+            if !conn.has_totp_enabled(user.id).await {
+                record_login_attempt(&conn, models::LoginAttemptKind::Login, &post.username, Some(user.id), &ip_address, true).await;
             }
-            if user.banned {
-                return Flash::error(Redirect::to("."), "Sorry. Not sorry. You're banned.");
-            }
-            let session = conn.create_session(user.id, user_agent.user_agent).await.expect("failed to allocate a session");
-            let cookie = Cookie::build("U", session.uuid.to_string()).path("/").permanent().same_site(SameSite::None).finish();
-            cookies.add(cookie);
-            let cookie = Cookie::build("N", user.username.to_string()).path("/").permanent().same_site(SameSite::None).finish();
-            cookies.add(cookie);
-            Flash::success(Redirect::to("."), "Congrats, you're in!")
+            finish_login(&conn, &user, cookies, user_agent.user_agent).await
         },
         None => {
+            let user_id = conn.get_user_by_username(&post.username).await.ok().map(|user| user.id);
+            record_login_attempt(&conn, models::LoginAttemptKind::Login, &post.username, user_id, &ip_address, false).await;
             Flash::error(Redirect::to("login"), "Incorrect username or password")
         },
     }
 }
 
+#[get("/login-2fa")]
+async fn login_totp_form(config: &State<SiteConfig>, flash: Option<FlashMessage<'_>>, customization: Customization) -> template::LoginTotp {
+    template::LoginTotp {
+        title: String::from("log in"),
+        config: config.inner().clone(),
+        customization,
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        user: User::default(),
+        session: UserSession::default(),
+        notifications: Vec::new(),
+        noindex: true,
+    }
+}
+
+#[derive(FromForm)]
+struct TotpForm {
+    code: String,
+}
+
+#[post("/login-2fa", data = "<post>")]
+async fn login_totp(conn: MoreInterestingConn, post: Form<TotpForm>, cookies: &CookieJar<'_>, user_agent: UserAgentString<'_>, ip: ClientIp, config: &State<SiteConfig>) -> Flash<Redirect> {
+    let pending: Option<Base32> = cookies.get("T").and_then(|cookie| cookie.value().parse().ok());
+    let pending = if let Some(pending) = pending {
+        pending
+    } else {
+        return Flash::error(Redirect::to("login"), "Your login attempt expired; please try again");
+    };
+    let user = if let Ok(user) = conn.get_pending_login(pending).await {
+        user
+    } else {
+        cookies.remove(Cookie::named("T"));
+        return Flash::error(Redirect::to("login"), "Your login attempt expired; please try again");
+    };
+    let ip_address = ip_address_string(ip);
+    if let Some(message) = check_login_throttle(&conn, config, Some(&user.username), &ip_address).await.message() {
+        return Flash::error(Redirect::to("login-2fa"), message);
+    }
+    if !conn.verify_totp(user.id, post.code.clone()).await {
+        record_login_attempt(&conn, models::LoginAttemptKind::TwoFactor, &user.username, Some(user.id), &ip_address, false).await;
+        return Flash::error(Redirect::to("login-2fa"), "Incorrect code");
+    }
+    record_login_attempt(&conn, models::LoginAttemptKind::TwoFactor, &user.username, Some(user.id), &ip_address, true).await;
+    let _ = conn.delete_pending_login(pending).await;
+    cookies.remove(Cookie::named("T"));
+    if let Some(suspension) = conn.get_active_suspension(user.id).await {
+        return Flash::error(Redirect::to("/"), suspension_message(&suspension));
+    }
+    start_session(&conn, &user, cookies, user_agent.user_agent).await;
+    Flash::success(Redirect::to("."), "Congrats, you're in!")
+}
+
+#[get("/forgot-password")]
+async fn forgot_password_form(config: &State<SiteConfig>, flash: Option<FlashMessage<'_>>, customization: Customization) -> template::ForgotPassword {
+    template::ForgotPassword {
+        title: String::from("forgot password"),
+        config: config.inner().clone(),
+        customization,
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        user: User::default(),
+        session: UserSession::default(),
+        notifications: Vec::new(),
+        noindex: true,
+    }
+}
+
+#[derive(FromForm)]
+struct ForgotPasswordForm {
+    username: String,
+}
+
+#[post("/forgot-password", data = "<form>")]
+async fn forgot_password(conn: MoreInterestingConn, form: Form<ForgotPasswordForm>, mailer: Mailer, ip: ClientIp, config: &State<SiteConfig>, customization: Customization) -> Flash<Redirect> {
+    let username = form.username.trim();
+    let ip_address = ip_address_string(ip);
+    if let Some(message) = check_password_reset_throttle(&conn, config, username, &ip_address).await.message() {
+        return Flash::error(Redirect::to("/forgot-password"), message);
+    }
+    record_login_attempt(&conn, models::LoginAttemptKind::PasswordReset, username, None, &ip_address, false).await;
+    // Say the same thing whether or not the account exists,
+    // so this form can't be used to find out who has an account or an email address.
+    let done = Flash::success(Redirect::to("/login"), "If that account has a verified email address, a reset link is on its way");
+    let user = if username.contains('@') {
+        conn.get_user_by_email(username.to_owned()).await
+    } else {
+        conn.get_user_by_username(username).await
+    };
+    let (user, email) = match user {
+        Ok(user) if !user.banned && user.email.is_some() => {
+            let email = user.email.clone().unwrap();
+            (user, email)
+        }
+        _ => return done,
+    };
+    let token = match conn.create_password_reset_token(user.id).await {
+        Ok(token) => token,
+        Err(e) => {
+            warn!("Failed to create password reset token: {:?}", e);
+            return Flash::error(Redirect::to("/forgot-password"), "Something went wrong; please try again later");
+        }
+    };
+    let url = config.public_url.join(&format!("reset-password/{}", token)).expect("base32 is a valid relative URL");
+    let message = mail::Message {
+        to: email,
+        subject: format!("Reset your {} password", customization.title),
+        body: format!("Someone, hopefully you, asked to reset the password for {} on {}.\n\nTo choose a new password, go to:\n\n{}\n\nThis link expires in an hour. If you didn't ask for this, you can ignore it.\n", user.username, customization.title, url),
+    };
+    if let Err(e) = mailer.send(message).await {
+        warn!("Failed to send password reset mail: {}", e);
+    }
+    done
+}
+
+#[get("/reset-password/<token>")]
+async fn reset_password_form(conn: MoreInterestingConn, token: String, config: &State<SiteConfig>, flash: Option<FlashMessage<'_>>, customization: Customization) -> Result<template::ResetPassword, Flash<Redirect>> {
+    if conn.check_password_reset_token(token).await.is_err() {
+        return Err(Flash::error(Redirect::to("/forgot-password"), "That reset link has expired or already been used"));
+    }
+    Ok(template::ResetPassword {
+        title: String::from("reset password"),
+        config: config.inner().clone(),
+        customization,
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        user: User::default(),
+        session: UserSession::default(),
+        notifications: Vec::new(),
+        noindex: true,
+    })
+}
+
+#[derive(FromForm)]
+struct ResetPasswordForm {
+    new_password: String,
+}
+
+#[post("/reset-password/<token>", data = "<form>")]
+async fn reset_password(conn: MoreInterestingConn, token: String, form: Form<ResetPasswordForm>) -> Flash<Redirect> {
+    if form.new_password == "" {
+        return Flash::error(Redirect::to(format!("/reset-password/{}", token)), "Password cannot be empty");
+    }
+    match conn.consume_password_reset_token(token, form.new_password.clone()).await {
+        Ok(_) => Flash::success(Redirect::to("/login"), "Your password has been changed, and you've been logged out everywhere"),
+        Err(_) => Flash::error(Redirect::to("/forgot-password"), "That reset link has expired or already been used"),
+    }
+}
+
+fn oidc_redirect_uri(config: &SiteConfig) -> String {
+    config.public_url.join("login/oidc/callback").expect("static relative URL").to_string()
+}
+
+/// Send the browser off to the identity provider, and remember what to check when it comes back.
+async fn begin_oidc_login(conn: &MoreInterestingConn, config: &SiteConfig, cookies: &CookieJar<'_>, invite_token: Option<Base32>, link_user_id: Option<i32>) -> Result<Redirect, Flash<Redirect>> {
+    let fail = || Flash::error(Redirect::to("/login"), "Single sign-on isn't working right now; please try again later");
+    if config.oidc_issuer == "" {
+        return Err(Flash::error(Redirect::to("/login"), "Single sign-on isn't set up on this site"));
+    }
+    let metadata = oidc::discover(&config.oidc_issuer, config.oidc_allow_http).await.map_err(|e| {
+        warn!("Failed to discover OIDC provider: {}", e);
+        fail()
+    })?;
+    let challenge = oidc::Challenge::new();
+    let url = oidc::authorization_url(&metadata, &config.oidc_client_id, &oidc_redirect_uri(config), &config.oidc_scopes, &challenge).map_err(|e| {
+        warn!("Failed to build OIDC authorization URL: {}", e);
+        fail()
+    })?;
+    conn.create_pending_oidc_login(challenge.state.clone(), challenge.nonce, challenge.code_verifier, invite_token, link_user_id).await.map_err(|e| {
+        warn!("Failed to create pending OIDC login: {:?}", e);
+        fail()
+    })?;
+    let cookie = Cookie::build("O", challenge.state).path("/").http_only(true).same_site(SameSite::Lax).finish();
+    cookies.add(cookie);
+    Ok(Redirect::to(url.to_string()))
+}
+
+#[get("/login/oidc?<invite_token>")]
+async fn oidc_login(conn: MoreInterestingConn, config: &State<SiteConfig>, cookies: &CookieJar<'_>, invite_token: Option<Base32>) -> Result<Redirect, Flash<Redirect>> {
+    begin_oidc_login(&conn, config, cookies, invite_token, None).await
+}
+
+#[post("/oidc/link")]
+async fn oidc_link(conn: MoreInterestingConn, login: LoginSession, config: &State<SiteConfig>, cookies: &CookieJar<'_>) -> Result<Redirect, Flash<Redirect>> {
+    begin_oidc_login(&conn, config, cookies, None, Some(login.user.id)).await
+}
+
+#[get("/login/oidc/callback?<code>&<state>&<error>")]
+async fn oidc_callback(conn: MoreInterestingConn, config: &State<SiteConfig>, cookies: &CookieJar<'_>, user_agent: UserAgentString<'_>, ip: ClientIp, code: Option<String>, state: Option<String>, error: Option<String>) -> Flash<Redirect> {
+    let fail = |message: &str| Flash::error(Redirect::to("/login"), message.to_owned());
+    let expired = "Your login attempt expired; please try again";
+    let ip_address = ip_address_string(ip);
+    let cookie_state = cookies.get("O").map(|cookie| cookie.value().to_owned());
+    cookies.remove(Cookie::named("O"));
+    let (code, state) = match oidc::check_callback(code, state, error, cookie_state.as_deref()) {
+        Ok(code_and_state) => code_and_state,
+        Err(oidc::CallbackError::Refused(error)) => return fail(&format!("The identity provider refused the login: {}", error)),
+        Err(oidc::CallbackError::Expired) => return fail(expired),
+    };
+    let pending = match conn.take_pending_oidc_login(state).await {
+        Ok(pending) => pending,
+        Err(_) => return fail(expired),
+    };
+    let metadata = match oidc::discover(&config.oidc_issuer, config.oidc_allow_http).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            warn!("Failed to discover OIDC provider: {}", e);
+            return fail("Single sign-on isn't working right now; please try again later");
+        }
+    };
+    let challenge = oidc::Challenge {
+        state: pending.state.clone(),
+        nonce: pending.nonce.clone(),
+        code_verifier: pending.code_verifier.clone(),
+    };
+    let claims = match oidc::exchange_code(&metadata, &config.oidc_client_id, &config.oidc_client_secret, &oidc_redirect_uri(config), &code, &challenge, Utc::now().timestamp()).await {
+        Ok(claims) => claims,
+        Err(e) => {
+            warn!("Failed to finish OIDC login: {}", e);
+            record_login_attempt(&conn, models::LoginAttemptKind::SingleSignOn, "", pending.link_user_id, &ip_address, false).await;
+            return fail("Single sign-on failed; please try again");
+        }
+    };
+    if let Some(link_user_id) = pending.link_user_id {
+        return match conn.link_external_identity(link_user_id, claims.iss.clone(), claims.sub.clone()).await {
+            Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), format!("You can now log in with {}", config.oidc_provider_name)),
+            Err(e) => {
+                warn!("Failed to link external identity: {:?}", e);
+                Flash::error(Redirect::to(uri!(get_settings)), "That login is already linked to an account")
+            }
+        };
+    }
+    if let Ok(user) = conn.get_user_by_external_identity(claims.iss.clone(), claims.sub.clone()).await {
+        // Just like with a password, two-factor authentication records the success once the code is right.
+        if !conn.has_totp_enabled(user.id).await {
+            record_login_attempt(&conn, models::LoginAttemptKind::SingleSignOn, &user.username, Some(user.id), &ip_address, true).await;
+        }
+        return finish_login(&conn, &user, cookies, user_agent.user_agent).await;
+    }
+    // Nobody has this identity yet, so this makes a new account, which has the same rules as signing up.
+    let invited_by = if let Some(invite_token) = pending.invite_token {
+        match conn.consume_invite_token(Base32::from(invite_token)).await {
+            Ok(invite_token) => Some(invite_token.invited_by),
+            Err(_) => return fail("That invite link has already been used"),
+        }
+    } else if config.enable_public_signup {
+        None
+    } else {
+        return fail("There's no account linked to that login, and you need an invite to sign up");
+    };
+    let username = match conn.find_available_username(oidc::suggest_username(&claims)).await {
+        Ok(username) => username,
+        Err(e) => {
+            warn!("Failed to pick a username: {:?}", e);
+            return fail("Failed to create account");
+        }
+    };
+    let username_verdict = check_blocked_regexes(&conn, &[(Scope::Username, &username)]).await;
+    if let Some(Verdict { action: blocked_regex::Action::Reject, message, .. }) = username_verdict {
+        return fail(&message);
+    }
+    // No password, so they log in with single sign-on, or set one with a reset link if they add an email address.
+    let user = match conn.register_user(NewUser { username, password: String::new(), invited_by }).await {
+        Ok(user) => user,
+        Err(e) => {
+            warn!("Failed to register single sign-on user: {:?}", e);
+            return fail("Failed to create account");
+        }
+    };
+    if let Err(e) = conn.link_external_identity(user.id, claims.iss.clone(), claims.sub.clone()).await {
+        warn!("Failed to link external identity: {:?}", e);
+        return fail("Failed to create account");
+    }
+    set_initial_trust_level(&conn, &user, invited_by, cookies).await;
+    apply_username_verdict(&conn, user.id, username_verdict).await;
+    record_login_attempt(&conn, models::LoginAttemptKind::SingleSignOn, &user.username, Some(user.id), &ip_address, true).await;
+    start_session(&conn, &user, cookies, user_agent.user_agent).await;
+    Flash::success(Redirect::to("/"), format!("Congrats, you're in! Your username is {}", user.username))
+}
+
+#[derive(FromForm)]
+struct UnlinkExternalIdentityForm {
+    id: i32,
+}
+
+#[post("/oidc/unlink", data = "<form>")]
+async fn oidc_unlink(conn: MoreInterestingConn, login: LoginSession, form: Form<UnlinkExternalIdentityForm>) -> Flash<Redirect> {
+    let identity_count = match conn.list_external_identities(login.user.id).await {
+        Ok(identities) => identities.len(),
+        Err(e) => {
+            warn!("Failed to list external identities: {:?}", e);
+            return Flash::error(Redirect::to(uri!(get_settings)), "Failed to remove single sign-on login");
+        }
+    };
+    if !oidc::can_unlink(login.user.has_password(), identity_count) {
+        return Flash::error(Redirect::to(uri!(get_settings)), "This is the only way to log in to your account. Set a password first, with a reset link sent to your email address.");
+    }
+    match conn.unlink_external_identity(login.user.id, form.id).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Single sign-on login removed"),
+        Err(e) => {
+            warn!("Failed to unlink external identity: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to remove single sign-on login")
+        }
+    }
+}
+
 #[post("/logout")]
-async fn logout(cookies: &CookieJar<'_>) -> Redirect {
+async fn logout(conn: MoreInterestingConn, login: Option<LoginSession>, cookies: &CookieJar<'_>) -> Redirect {
+    if let Some(login) = login {
+        let _ = conn.revoke_session(login.user.id, login.session.uuid).await;
+    }
     let cookie = Cookie::build("U", "").path("/").permanent().same_site(SameSite::None).finish();
     cookies.add(cookie);
     Redirect::to(".")
 }
 
-#[get("/<uuid>", rank = 1)]
-async fn get_comments(conn: MoreInterestingConn, login: Option<LoginSession>, uuid: String, config: &State<SiteConfig>, flash: Option<FlashMessage<'_>>, customization: Customization) -> Result<OneOf<template::ProfilePosts, template::Similar, template::Comments, template::Signup>, Status> {
+/// `page` counts from one, and `comment` picks whichever page has that comment on it.
+#[get("/<uuid>?<page>&<sort>&<comment>", rank = 1)]
+async fn get_comments(conn: MoreInterestingConn, login: Option<LoginSession>, uuid: String, page: Option<usize>, sort: Option<String>, comment: Option<i32>, config: &State<SiteConfig>, flash: Option<FlashMessage<'_>>, customization: Customization) -> Result<OneOf<template::ProfilePosts, template::Similar, template::Comments, template::Signup>, Status> {
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
     if uuid.len() > 0 && uuid.as_bytes()[0] == b'@' {
         let username = &uuid[1..];
@@ -1494,26 +2767,43 @@ async fn get_comments(conn: MoreInterestingConn, login: Option<LoginSession>, uu
         return Err(Status::NotFound);
     };
     if let Ok(post_info) = conn.get_post_info_by_uuid(user.id, uuid).await {
-        let comments = conn.get_comments_from_post(post_info.id, user.id).await.unwrap_or_else(|e| {
+        let order = models::CommentOrder::from_param(sort.as_deref().unwrap_or("")).unwrap_or(models::CommentOrder::Oldest);
+        let target = match (comment, page, post_info.comment_readpoint) {
+            (Some(comment_id), _, _) => models::CommentPageTarget::Comment(comment_id),
+            (None, Some(page), _) => models::CommentPageTarget::Page(page.saturating_sub(1)),
+            (None, None, Some(readpoint)) if order == models::CommentOrder::Oldest => models::CommentPageTarget::After(readpoint),
+            (None, None, _) => models::CommentPageTarget::Page(0),
+        };
+        let comment_page = conn.get_comment_page(post_info.id, user.id, post_info.comment_readpoint, order, target).await.unwrap_or_else(|e| {
             warn!("Failed to get comments: {:?}", e);
-            Vec::new()
+            models::CommentPage { comments: Vec::new(), page: 0, page_count: 1, advanced_readpoint: None }
         });
-        let legacy_comments = conn.get_legacy_comment_info_from_post(post_info.id, user.id).await.unwrap_or_else(|e| {
-            warn!("Failed to get comments: {:?}", e);
+        // Old comments come before all the others, so they only go on the first page.
+        let legacy_comments = if comment_page.page == 0 && order == models::CommentOrder::Oldest {
+            conn.get_legacy_comment_info_from_post(post_info.id, user.id).await.unwrap_or_else(|e| {
+                warn!("Failed to get comments: {:?}", e);
+                Vec::new()
+            })
+        } else {
             Vec::new()
-        });
+        };
         let post_id = post_info.id;
         let title = post_info.title.clone();
         if user.id != 0 {
             conn.use_notification(post_id, user.id).await.unwrap_or_else(|e| {
                 warn!("Failed to consume notification: {:?}", e);
             });
-            if let Some(comment) = comments.last() {
-                conn.set_readpoint(post_id, user.id, comment.id).await.unwrap_or_else(|e| {
+            // Reading one page shouldn't mark unread comments on the others as read.
+            if let Some(comment_id) = comment_page.advanced_readpoint {
+                conn.set_readpoint(post_id, user.id, comment_id).await.unwrap_or_else(|e| {
                     warn!("Failed to set readpoint: {:?}", e);
                 });
             }
         }
+        let comments = comment_page.comments;
+        let comment_page_number = comment_page.page + 1;
+        let comment_page_count = comment_page.page_count;
+        let comment_sort = order.param();
         let is_private = post_info.private || !post_info.visible;
         let noindex = is_private || post_info.noindex;
         let locked = post_info.locked;
@@ -1538,6 +2828,8 @@ async fn get_comments(conn: MoreInterestingConn, login: Option<LoginSession>, uu
             config: config.inner().clone(),
             comment_preview_html: String::new(),
             comment_preview_text: String::new(),
+            comment_parent_id: None,
+            comment_page_number, comment_page_count, comment_sort,
             customization,
             noindex, locked,
             comments, user, title, legacy_comments, session,
@@ -1566,25 +2858,32 @@ struct CommentForm {
     text: String,
     post: Base32,
     preview: Option<String>,
+    /// The comment being replied to, if any.
+    parent: Option<i32>,
 }
 
 #[post("/comment", data = "<comment>")]
 async fn post_comment(conn: MoreInterestingConn, login: LoginSession, comment: Form<CommentForm>, config: &State<SiteConfig>) -> Option<Flash<Redirect>> {
     let post_info = conn.get_post_info_by_uuid(login.user.id, comment.post).await.into_option()?;
     let mut user = login.user;
-    let blocked_regexes = conn.get_all_blocked_regexes().await.unwrap_or(Vec::new());
-    for blocked_regex in blocked_regexes {
-        let regex = if let Ok(regex) = Regex::new(&blocked_regex.regex) {
-            regex
-        } else {
-            continue
-        };
-        if regex.is_match(&comment.text) {
+    let mut queued = false;
+    match check_blocked_regexes(&conn, &[(Scope::Comment, &comment.text)]).await {
+        Some(Verdict { action: blocked_regex::Action::Reject, message, .. }) => {
+            return Some(Flash::error(Redirect::to(comment.post.to_string()), message));
+        }
+        Some(Verdict { action: blocked_regex::Action::Queue, .. }) => queued = true,
+        Some(Verdict { action: blocked_regex::Action::Lock, .. }) if user.trust_level > -2 => {
             conn.change_user_trust_level(user.id, -2).await.ok()?;
             user.trust_level = -2;
         }
+        Some(Verdict { action: blocked_regex::Action::Lock, .. }) => {}
+        Some(Verdict { action: blocked_regex::Action::Shadowban, .. }) => {
+            conn.change_user_trust_level(user.id, -3).await.ok()?;
+            user.trust_level = -3;
+        }
+        None => {}
     }
-    let visible = user.trust_level > 0 || post_info.private || user.trust_level == -1;
+    let visible = (user.trust_level > 0 || post_info.private || user.trust_level == -1) && !queued;
     if post_info.locked {
         return Some(Flash::error(
             Redirect::to(comment.post.to_string()),
@@ -1596,9 +2895,10 @@ async fn post_comment(conn: MoreInterestingConn, login: LoginSession, comment: F
         text: comment.text.clone(),
         created_by: user.id,
         visible,
+        parent_id: comment.parent,
     }, config.body_format).await;
-    match comment_result {
-        Ok(_) => (),
+    let new_comment = match comment_result {
+        Ok(new_comment) => new_comment,
         Err(CreateCommentError::TooManyComments) => {
             return Some(Flash::error(
                 Redirect::to(comment.post.to_string()),
@@ -1609,21 +2909,31 @@ async fn post_comment(conn: MoreInterestingConn, login: LoginSession, comment: F
             warn!("Post comment error: {:?}", e);
             return None;
         }
-    }
+    };
     let subscribed_users = conn.list_subscribed_users(post_info.id).await.unwrap_or_else(|e| {
         warn!("Failed to get subscribed users list for post uuid {}: {:?}", post_info.uuid, e);
         Vec::new()
     });
+    // People this comment replies to have already been notified about it, saying so.
+    let replied_to_users = conn.list_users_notified_about_comment(new_comment.id).await.unwrap_or_else(|e| {
+        warn!("Failed to get users notified about comment {}: {:?}", new_comment.id, e);
+        Vec::new()
+    });
     for subscribed_user_id in subscribed_users {
-        if subscribed_user_id == user.id { continue };
+        if subscribed_user_id == user.id || replied_to_users.contains(&subscribed_user_id) { continue };
         conn.create_notification(NewNotification {
             user_id: subscribed_user_id,
             post_id: post_info.id,
             created_by: user.id,
         }).await.unwrap_or_else(|e| warn!("Cannot notify {} on comment: {:?}", subscribed_user_id, e));
     }
+    let redirect = if visible {
+        format!("{}?comment={}#{}", comment.post, new_comment.id, new_comment.id)
+    } else {
+        comment.post.to_string()
+    };
     Some(Flash::success(
-        Redirect::to(comment.post.to_string()),
+        Redirect::to(redirect),
         if visible { "Your comment has been posted" } else { "Your comment will be posted after a mod gets a chance to look at it" }
     ))
 }
@@ -1633,14 +2943,26 @@ async fn preview_comment(conn: MoreInterestingConn, login: LoginSession, comment
     use models::{BodyFormat, PrettifyData};
     let (user, session) = (login.user, login.session);
     let post_info = conn.get_post_info_by_uuid(user.id, comment.post).await.into_option()?;
-    let comments = conn.get_comments_from_post(post_info.id, user.id).await.unwrap_or_else(|e| {
+    // A reply goes back to the page it's replying on, and anything else goes at the end.
+    let target = match comment.parent {
+        Some(parent_id) => models::CommentPageTarget::Comment(parent_id),
+        None => models::CommentPageTarget::Page(usize::MAX),
+    };
+    let comment_page = conn.get_comment_page(post_info.id, user.id, post_info.comment_readpoint, models::CommentOrder::Oldest, target).await.unwrap_or_else(|e| {
         warn!("Failed to get comments: {:?}", e);
-        Vec::new()
+        models::CommentPage { comments: Vec::new(), page: 0, page_count: 1, advanced_readpoint: None }
     });
-    let legacy_comments = conn.get_legacy_comment_info_from_post(post_info.id, user.id).await.unwrap_or_else(|e| {
-        warn!("Failed to get comments: {:?}", e);
+    let legacy_comments = if comment_page.page == 0 {
+        conn.get_legacy_comment_info_from_post(post_info.id, user.id).await.unwrap_or_else(|e| {
+            warn!("Failed to get comments: {:?}", e);
+            Vec::new()
+        })
+    } else {
         Vec::new()
-    });
+    };
+    let comments = comment_page.comments;
+    let comment_page_number = comment_page.page + 1;
+    let comment_page_count = comment_page.page_count;
     let post_id = post_info.id;
     let title = post_info.title.clone();
     let is_private = post_info.private;
@@ -1675,9 +2997,46 @@ async fn preview_comment(conn: MoreInterestingConn, login: LoginSession, comment
         comments, user, title, legacy_comments, session,
         notifications, is_private, is_subscribed,
         comment_preview_text, comment_preview_html,
+        comment_parent_id: comment.parent,
+        comment_page_number, comment_page_count,
+        comment_sort: models::CommentOrder::Oldest.param(),
     })
 }
 
+/// Brand-new accounts inherit a shadowban from whoever last used this browser,
+/// and get a head start if they were invited by someone trusted.
+async fn set_initial_trust_level(conn: &MoreInterestingConn, user: &User, invited_by: Option<i32>, cookies: &CookieJar<'_>) {
+    if cookies.get("B").is_some() {
+        conn.change_user_trust_level(user.id, -3).await.expect("if logging in worked, then so should changing trust level");
+    } else if let Some(other_user) = cookies.get("N") {
+        if let Ok(other_user) = conn.get_user_by_username(other_user.value()).await {
+            if other_user.banned || other_user.trust_level <= -3 {
+                conn.change_user_trust_level(user.id, -3).await.expect("if logging in worked, then so should changing trust level");
+            }
+        }
+    } else if let Some(invited_by) = invited_by {
+        if let Ok(invited_by) = conn.get_user_by_id(invited_by).await {
+            if invited_by.trust_level >= 2 {
+                conn.change_user_trust_level(user.id, 1).await.expect("if logging in worked, then so should changing trust level");
+            }
+        }
+    }
+}
+
+/// A username that matched a blocked regex starts out locked or shadowbanned, whatever else would have happened.
+async fn apply_username_verdict(conn: &MoreInterestingConn, user_id: i32, verdict: Option<Verdict>) {
+    let trust_level = match verdict.map(|verdict| verdict.action) {
+        Some(blocked_regex::Action::Queue) | Some(blocked_regex::Action::Lock) => -2,
+        Some(blocked_regex::Action::Shadowban) => -3,
+        _ => return,
+    };
+    if let Ok(user) = conn.get_user_by_id(user_id).await {
+        if user.trust_level > trust_level {
+            conn.change_user_trust_level(user_id, trust_level).await.expect("if signing up worked, then so should changing trust level");
+        }
+    }
+}
+
 #[derive(FromForm)]
 struct SignupForm {
     username: String,
@@ -1686,14 +3045,25 @@ struct SignupForm {
 }
 
 #[post("/signup", data = "<form>")]
-async fn signup(conn: MoreInterestingConn, user_agent: UserAgentString<'_>, form: Form<SignupForm>, cookies: &CookieJar<'_>, config: &State<SiteConfig>) -> Result<Flash<Redirect>, Status> {
-    if form.username == "" || form.username == "anonymous" {
+async fn signup(conn: MoreInterestingConn, user_agent: UserAgentString<'_>, form: Form<SignupForm>, cookies: &CookieJar<'_>, config: &State<SiteConfig>, ip: ClientIp) -> Result<Flash<Redirect>, Status> {
+    if form.username == "" || form.username == "anonymous" || form.password == "" {
         return Err(Status::BadRequest);
     }
+    // Invite tokens are guessable in the same way passwords are, so this is throttled per IP address.
+    let ip_address = ip_address_string(ip);
+    if !check_login_throttle(&conn, config, None, &ip_address).await.is_allowed() {
+        return Err(Status::TooManyRequests);
+    }
+    let username_verdict = check_blocked_regexes(&conn, &[(Scope::Username, &form.username)]).await;
+    if let Some(Verdict { action: blocked_regex::Action::Reject, message, .. }) = username_verdict {
+        let signup_page = form.invite_token.map(|invite_token| invite_token.to_string()).unwrap_or_else(|| String::from("signup"));
+        return Ok(Flash::error(Redirect::to(signup_page), message));
+    }
     let invited_by = if let Some(invite_token) = form.invite_token {
         if let Ok(invite_token) = conn.consume_invite_token(invite_token).await {
             Some(invite_token.invited_by)
         } else {
+            record_login_attempt(&conn, models::LoginAttemptKind::Signup, &form.username, None, &ip_address, false).await;
             return Err(Status::BadRequest)
         }
     } else {
@@ -1708,106 +3078,386 @@ async fn signup(conn: MoreInterestingConn, user_agent: UserAgentString<'_>, form
         password: form.password.to_owned(),
         invited_by,
     }).await {
-        if cookies.get("B").is_some() {
-            conn.change_user_trust_level(user.id, -3).await.expect("if logging in worked, then so should changing trust level");
-        } else if let Some(other_user) = cookies.get("N") {
-            if let Ok(other_user) = conn.get_user_by_username(other_user.value()).await {
-                if other_user.banned || other_user.trust_level <= -3 {
-                    conn.change_user_trust_level(user.id, -3).await.expect("if logging in worked, then so should changing trust level");
-                }
-            }
-        } else if let Some(invited_by) = invited_by.as_ref() {
-            if let Ok(invited_by) = conn.get_user_by_id(*invited_by).await {
-                if invited_by.trust_level >= 2 {
-                    conn.change_user_trust_level(user.id, 1).await.expect("if logging in worked, then so should changing trust level");
-                }
-            }
+        set_initial_trust_level(&conn, &user, invited_by, cookies).await;
+        apply_username_verdict(&conn, user.id, username_verdict).await;
+        start_session(&conn, &user, cookies, user_agent.user_agent).await;
+        return Ok(Flash::success(Redirect::to("."), "Congrats, you're in!"));
+    }
+    record_login_attempt(&conn, models::LoginAttemptKind::Signup, &form.username, None, &ip_address, false).await;
+    Err(Status::BadRequest)
+}
+
+#[get("/signup")]
+async fn get_public_signup(flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, customization: Customization) -> Result<template::Signup, Status> {
+    if !config.enable_public_signup {
+        return Err(Status::NotFound);
+    }
+    Ok(template::Signup {
+        title: String::from("sign up"),
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        config: config.inner().clone(),
+        invite_token: None,
+        user: User::default(),
+        session: UserSession::default(),
+        notifications: Vec::new(),
+        noindex: false,
+        customization,
+    })
+}
+
+#[get("/settings")]
+async fn get_settings(conn: MoreInterestingConn, login: LoginSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, customization: Customization) -> template::Settings {
+    let user = login.user;
+    let session = login.session;
+    let sessions = conn.list_sessions(user.id).await.unwrap_or(Vec::new()).into_iter().map(|s| models::UserSessionInfo {
+        device: user_agent::describe(&s.user_agent),
+        last_seen_at_relative: relative_date(&s.last_seen_at),
+        is_current: s.uuid == session.uuid,
+        uuid: s.uuid,
+        name: s.name,
+        created_at: s.created_at,
+        last_seen_at: s.last_seen_at,
+    }).collect();
+    let user_totp = conn.get_user_totp(user.id).await.ok();
+    let totp_enabled = user_totp.as_ref().map(|user_totp| user_totp.enabled).unwrap_or(false);
+    let (totp_pending_secret, totp_pending_url) = match user_totp {
+        Some(ref user_totp) if !user_totp.enabled => (
+            Some(totp::encode_secret(&user_totp.secret)),
+            Some(totp::otpauth_url(&customization.title, &user.username, &user_totp.secret)),
+        ),
+        _ => (None, None),
+    };
+    template::Settings {
+        title: String::from("settings"),
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        config: config.inner().clone(),
+        notifications: conn.list_notifications(user.id).await.unwrap_or(Vec::new()),
+        api_tokens: conn.list_api_tokens(user.id).await.unwrap_or(Vec::new()),
+        private_feed_url: conn.get_private_feed_token(user.id).await.ok()
+            .and_then(|token| config.public_url.join(&format!("private-feed/{}", token.uuid)).ok())
+            .map(|url| url.to_string()),
+        external_identities: conn.list_external_identities(user.id).await.unwrap_or(Vec::new()),
+        totp_enabled, totp_pending_secret, totp_pending_url, sessions,
+        totp_recovery_code_count: if totp_enabled { conn.count_recovery_codes(user.id).await.unwrap_or(0) } else { 0 },
+        totp_required: config.require_2fa_for_moderators && user.trust_level >= 3,
+        noindex: true,
+        customization,
+        user, session,
+    }
+}
+
+#[derive(FromForm)]
+struct DarkModeForm {
+    active: bool,
+}
+
+#[post("/set-dark-mode", data="<form>")]
+async fn set_dark_mode<'a>(conn: MoreInterestingConn, login: LoginSession, form: Form<DarkModeForm>) -> Flash<Redirect> {
+    match conn.set_dark_mode(login.user.id, form.active).await {
+        Ok(()) => {
+            Flash::success(Redirect::to(uri!(get_settings)), "")
+        }
+        Err(e) => {
+            dbg!(e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Something went horribly wrong")
+        }
+    }
+}
+
+#[post("/set-big-mode", data="<form>")]
+async fn set_big_mode<'a>(conn: MoreInterestingConn, login: LoginSession, form: Form<DarkModeForm>) -> Flash<Redirect> {
+    match conn.set_big_mode(login.user.id, form.active).await {
+        Ok(()) => {
+            Flash::success(Redirect::to(uri!(get_settings)), "")
+        }
+        Err(e) => {
+            dbg!(e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Something went horribly wrong")
+        }
+    }
+}
+
+#[post("/set-notify-on-mention", data="<form>")]
+async fn set_notify_on_mention<'a>(conn: MoreInterestingConn, login: LoginSession, form: Form<DarkModeForm>) -> Flash<Redirect> {
+    match conn.set_notify_on_mention(login.user.id, form.active).await {
+        Ok(()) => {
+            Flash::success(Redirect::to(uri!(get_settings)), "")
+        }
+        Err(e) => {
+            warn!("Failed to set notify on mention: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Something went horribly wrong")
+        }
+    }
+}
+
+#[post("/create-invite")]
+async fn create_invite<'a>(conn: MoreInterestingConn, login: LoginSession, config: &State<SiteConfig>) -> Flash<Redirect> {
+    match conn.create_invite_token(login.user.id).await {
+        Ok(invite_token) => {
+            let public_url = &config.public_url;
+            let created_invite_url = public_url.join(&invite_token.uuid.to_string()).expect("base128 is a valid relative URL");
+            Flash::success(Redirect::to(uri!(get_settings)), format!("To invite them, send them this link: {}", created_invite_url))
+        }
+        Err(e) => {
+            dbg!(e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to create invite")
+        }
+    }
+}
+
+#[derive(FromForm)]
+struct RenameSessionForm {
+    uuid: Base32,
+    name: String,
+}
+
+#[post("/rename-session", data = "<form>")]
+async fn rename_session(conn: MoreInterestingConn, login: LoginSession, form: Form<RenameSessionForm>) -> Flash<Redirect> {
+    let name = form.name.trim();
+    let name = if name == "" { None } else { Some(name.to_owned()) };
+    match conn.rename_session(login.user.id, form.uuid, name).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Session renamed"),
+        Err(e) => {
+            warn!("Failed to rename session: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to rename session")
+        }
+    }
+}
+
+#[derive(FromForm)]
+struct RevokeSessionForm {
+    uuid: Base32,
+}
+
+#[post("/revoke-session", data = "<form>")]
+async fn revoke_session(conn: MoreInterestingConn, login: LoginSession, form: Form<RevokeSessionForm>) -> Flash<Redirect> {
+    match conn.revoke_session(login.user.id, form.uuid).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Session logged out"),
+        Err(e) => {
+            warn!("Failed to revoke session: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to log out session")
+        }
+    }
+}
+
+#[post("/revoke-other-sessions")]
+async fn revoke_other_sessions(conn: MoreInterestingConn, login: LoginSession) -> Flash<Redirect> {
+    match conn.revoke_other_sessions(login.user.id, login.session.uuid).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Logged out everywhere else"),
+        Err(e) => {
+            warn!("Failed to revoke sessions: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to log out other sessions")
+        }
+    }
+}
+
+#[derive(FromForm)]
+struct SetEmailForm {
+    email: String,
+    /// Accounts made with single sign-on don't have a password to give.
+    password: Option<String>,
+}
+
+/// How long after logging in with single sign-on a password-less account can set its email address.
+const FRESH_LOGIN_MINUTES: i64 = 10;
+
+#[post("/set-email", data = "<form>")]
+async fn set_email(conn: MoreInterestingConn, login: LoginSession, form: Form<SetEmailForm>, mailer: Mailer, ip: ClientIp, config: &State<SiteConfig>, customization: Customization) -> Flash<Redirect> {
+    let user = login.user;
+    let email = models::normalize_email(&form.email);
+    if !email.contains('@') || email.contains(char::is_whitespace) {
+        return Flash::error(Redirect::to(uri!(get_settings)), "That doesn't look like an email address");
+    }
+    let ip_address = ip_address_string(ip);
+    if let Some(message) = check_login_throttle(&conn, config, Some(&user.username), &ip_address).await.message() {
+        return Flash::error(Redirect::to(uri!(get_settings)), message);
+    }
+    if !user.has_password() {
+        // The email address is how they'd set a password, so instead of asking for one,
+        // this has to come right after they proved who they are to the identity provider.
+        if login.api_token.is_some() || Utc::now().naive_utc() - login.session.created_at > Duration::minutes(FRESH_LOGIN_MINUTES) {
+            return Flash::error(Redirect::to(uri!(get_settings)), format!("Log out, log back in with single sign-on, and then add the address within {} minutes", FRESH_LOGIN_MINUTES));
+        }
+    } else if conn.authenticate_user(&UserAuth {
+        username: user.username.clone(),
+        password: form.password.clone().unwrap_or_default(),
+    }).await.is_none() {
+        record_login_attempt(&conn, models::LoginAttemptKind::ConfirmPassword, &user.username, Some(user.id), &ip_address, false).await;
+        return Flash::error(Redirect::to(uri!(get_settings)), "Incorrect password");
+    }
+    let token = match conn.create_email_verification_token(user.id, email.clone()).await {
+        Ok(token) => token,
+        Err(e) => {
+            warn!("Failed to create email verification token: {:?}", e);
+            return Flash::error(Redirect::to(uri!(get_settings)), "Failed to set email address");
+        }
+    };
+    let url = config.public_url.join(&format!("verify-email/{}", token)).expect("base32 is a valid relative URL");
+    let message = mail::Message {
+        to: email,
+        subject: format!("Verify your email address on {}", customization.title),
+        body: format!("To use this address for the account {} on {}, go to:\n\n{}\n\nThis link expires in a day. If you didn't ask for this, you can ignore it.\n", user.username, customization.title, url),
+    };
+    match mailer.send(message).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Check your email for a link to verify the address"),
+        Err(e) => {
+            warn!("Failed to send verification mail: {}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to send verification mail")
+        }
+    }
+}
+
+#[get("/verify-email/<token>")]
+async fn verify_email(conn: MoreInterestingConn, token: String) -> Flash<Redirect> {
+    match conn.consume_email_verification_token(token).await {
+        Ok(_) => Flash::success(Redirect::to(uri!(get_settings)), "Your email address has been verified"),
+        Err(e) => {
+            warn!("Failed to verify email: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "That verification link has expired, already been used, or is for an address that's already taken")
         }
-        let session = conn.create_session(user.id, user_agent.user_agent).await.expect("failed to allocate a session");
-        let cookie = Cookie::build("U", session.uuid.to_string()).path("/").permanent().same_site(SameSite::None).finish();
-        cookies.add(cookie);
-        let cookie = Cookie::build("N", user.username.to_string()).path("/").permanent().same_site(SameSite::None).finish();
-        cookies.add(cookie);
-        return Ok(Flash::success(Redirect::to("."), "Congrats, you're in!"));
     }
-    Err(Status::BadRequest)
 }
 
-#[get("/signup")]
-async fn get_public_signup(flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, customization: Customization) -> Result<template::Signup, Status> {
-    if !config.enable_public_signup {
-        return Err(Status::NotFound);
+#[post("/remove-email")]
+async fn remove_email(conn: MoreInterestingConn, login: LoginSession) -> Flash<Redirect> {
+    match conn.remove_email(login.user.id).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Email address removed"),
+        Err(e) => {
+            warn!("Failed to remove email: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to remove email address")
+        }
     }
-    Ok(template::Signup {
-        title: String::from("sign up"),
-        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
-        config: config.inner().clone(),
-        invite_token: None,
-        user: User::default(),
-        session: UserSession::default(),
-        notifications: Vec::new(),
-        noindex: false,
-        customization,
-    })
 }
 
-#[get("/settings")]
-async fn get_settings(conn: MoreInterestingConn, login: LoginSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, customization: Customization) -> template::Settings {
-    let user = login.user;
-    let session = login.session;
-    template::Settings {
-        title: String::from("settings"),
-        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
-        config: config.inner().clone(),
-        notifications: conn.list_notifications(user.id).await.unwrap_or(Vec::new()),
-        noindex: true,
-        customization,
-        user, session,
+#[post("/totp/begin")]
+async fn begin_totp(conn: MoreInterestingConn, login: LoginSession) -> Flash<Redirect> {
+    match conn.begin_totp_enrollment(login.user.id).await {
+        Ok(_) => Flash::success(Redirect::to(uri!(get_settings)), "Add the secret to your authenticator app, then enter the code it shows"),
+        Err(e) => {
+            warn!("Failed to begin TOTP enrollment: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to set up two-factor authentication")
+        }
     }
 }
 
-#[derive(FromForm)]
-struct DarkModeForm {
-    active: bool,
+#[post("/totp/confirm", data = "<form>")]
+async fn confirm_totp(conn: MoreInterestingConn, login: LoginSession, form: Form<TotpForm>) -> Flash<Redirect> {
+    match conn.confirm_totp_enrollment(login.user.id, form.code.clone()).await {
+        Some(recovery_codes) => Flash::success(
+            Redirect::to(uri!(get_settings)),
+            format!("Two-factor authentication is on. Write down these recovery codes; they will not be shown again: {}", recovery_codes.join(" ")),
+        ),
+        None => Flash::error(Redirect::to(uri!(get_settings)), "Incorrect code"),
+    }
 }
 
-#[post("/set-dark-mode", data="<form>")]
-async fn set_dark_mode<'a>(conn: MoreInterestingConn, login: LoginSession, form: Form<DarkModeForm>) -> Flash<Redirect> {
-    match conn.set_dark_mode(login.user.id, form.active).await {
-        Ok(()) => {
-            Flash::success(Redirect::to(uri!(get_settings)), "")
+#[post("/totp/recovery-codes", data = "<form>")]
+async fn regenerate_recovery_codes(conn: MoreInterestingConn, login: LoginSession, form: Form<TotpForm>, ip: ClientIp, config: &State<SiteConfig>) -> Flash<Redirect> {
+    let ip_address = ip_address_string(ip);
+    if let Some(message) = check_login_throttle(&conn, config, Some(&login.user.username), &ip_address).await.message() {
+        return Flash::error(Redirect::to(uri!(get_settings)), message);
+    }
+    if !conn.verify_totp(login.user.id, form.code.clone()).await {
+        record_login_attempt(&conn, models::LoginAttemptKind::TwoFactor, &login.user.username, Some(login.user.id), &ip_address, false).await;
+        return Flash::error(Redirect::to(uri!(get_settings)), "Incorrect code");
+    }
+    match conn.regenerate_recovery_codes(login.user.id).await {
+        Ok(recovery_codes) => Flash::success(
+            Redirect::to(uri!(get_settings)),
+            format!("Your old recovery codes no longer work. Write down these new ones; they will not be shown again: {}", recovery_codes.join(" ")),
+        ),
+        Err(e) => {
+            warn!("Failed to regenerate recovery codes: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to create recovery codes")
         }
+    }
+}
+
+#[derive(FromForm)]
+struct DisableTotpForm {
+    password: String,
+    code: String,
+}
+
+#[post("/totp/disable", data = "<form>")]
+async fn disable_totp(conn: MoreInterestingConn, login: LoginSession, form: Form<DisableTotpForm>, ip: ClientIp, config: &State<SiteConfig>) -> Flash<Redirect> {
+    let user = login.user;
+    let ip_address = ip_address_string(ip);
+    if let Some(message) = check_login_throttle(&conn, config, Some(&user.username), &ip_address).await.message() {
+        return Flash::error(Redirect::to(uri!(get_settings)), message);
+    }
+    if conn.authenticate_user(&UserAuth {
+        username: user.username.clone(),
+        password: form.password.clone(),
+    }).await.is_none() {
+        record_login_attempt(&conn, models::LoginAttemptKind::ConfirmPassword, &user.username, Some(user.id), &ip_address, false).await;
+        return Flash::error(Redirect::to(uri!(get_settings)), "Incorrect password");
+    }
+    if !conn.verify_totp(user.id, form.code.clone()).await {
+        record_login_attempt(&conn, models::LoginAttemptKind::TwoFactor, &user.username, Some(user.id), &ip_address, false).await;
+        return Flash::error(Redirect::to(uri!(get_settings)), "Incorrect code");
+    }
+    match conn.disable_totp(user.id).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "Two-factor authentication is off"),
         Err(e) => {
-            dbg!(e);
-            Flash::error(Redirect::to(uri!(get_settings)), "Something went horribly wrong")
+            warn!("Failed to disable TOTP: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to turn off two-factor authentication")
         }
     }
 }
 
-#[post("/set-big-mode", data="<form>")]
-async fn set_big_mode<'a>(conn: MoreInterestingConn, login: LoginSession, form: Form<DarkModeForm>) -> Flash<Redirect> {
-    match conn.set_big_mode(login.user.id, form.active).await {
-        Ok(()) => {
-            Flash::success(Redirect::to(uri!(get_settings)), "")
+#[derive(FromForm)]
+struct CreateApiTokenForm {
+    name: String,
+    scope_read: bool,
+    scope_vote: bool,
+    scope_post: bool,
+    scope_moderate: bool,
+}
+
+#[post("/create-api-token", data = "<form>")]
+async fn create_api_token(conn: MoreInterestingConn, login: LoginSession, form: Form<CreateApiTokenForm>, config: &State<SiteConfig>) -> Flash<Redirect> {
+    let user = login.user;
+    if form.name.trim() == "" {
+        return Flash::error(Redirect::to(uri!(get_settings)), "API tokens need a name");
+    }
+    if form.scope_moderate && user.trust_level < 3 {
+        return Flash::error(Redirect::to(uri!(get_settings)), "Only moderators can create moderation tokens");
+    }
+    if form.scope_moderate && config.require_2fa_for_moderators && !conn.has_totp_enabled(user.id).await {
+        return Flash::error(Redirect::to(uri!(get_settings)), "Turn on two-factor authentication before creating moderation tokens");
+    }
+    let new_token = models::NewApiToken {
+        user_id: user.id,
+        name: form.name.trim().to_owned(),
+        scope_read: form.scope_read,
+        scope_vote: form.scope_vote,
+        scope_post: form.scope_post,
+        scope_moderate: form.scope_moderate,
+    };
+    match conn.create_api_token(new_token).await {
+        Ok((_, token_string)) => {
+            Flash::success(Redirect::to(uri!(get_settings)), format!("Your new API token is {}. It will not be shown again.", token_string))
         }
         Err(e) => {
-            dbg!(e);
-            Flash::error(Redirect::to(uri!(get_settings)), "Something went horribly wrong")
+            warn!("Failed to create API token: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to create API token")
         }
     }
 }
 
-#[post("/create-invite")]
-async fn create_invite<'a>(conn: MoreInterestingConn, login: LoginSession, config: &State<SiteConfig>) -> Flash<Redirect> {
-    match conn.create_invite_token(login.user.id).await {
-        Ok(invite_token) => {
-            let public_url = &config.public_url;
-            let created_invite_url = public_url.join(&invite_token.uuid.to_string()).expect("base128 is a valid relative URL");
-            Flash::success(Redirect::to(uri!(get_settings)), format!("To invite them, send them this link: {}", created_invite_url))
-        }
+#[derive(FromForm)]
+struct RevokeApiTokenForm {
+    uuid: Base32,
+}
+
+#[post("/revoke-api-token", data = "<form>")]
+async fn revoke_api_token(conn: MoreInterestingConn, login: LoginSession, form: Form<RevokeApiTokenForm>) -> Flash<Redirect> {
+    match conn.revoke_api_token(login.user.id, form.uuid).await {
+        Ok(()) => Flash::success(Redirect::to(uri!(get_settings)), "API token revoked"),
         Err(e) => {
-            dbg!(e);
-            Flash::error(Redirect::to(uri!(get_settings)), "Failed to create invite")
+            warn!("Failed to revoke API token: {:?}", e);
+            Flash::error(Redirect::to(uri!(get_settings)), "Failed to revoke API token")
         }
     }
 }
@@ -1832,6 +3482,129 @@ async fn get_domains_json(conn: MoreInterestingConn, search: String) -> Option<c
     Some(content::RawJson(json))
 }
 
+async fn api_search_posts(conn: &MoreInterestingConn, user: &User, search: PostSearch) -> Option<content::RawJson<String>> {
+    let posts: Vec<PostInfo> = conn.search_posts(&search).await.into_option()?
+        .into_iter()
+        .map(|post| api::scrub_post(user, post))
+        .collect();
+    let (next_after, next_page) = api::next_posts(&search, posts.last().map(|post| post.uuid));
+    let json = serde_json::to_string(&api::Posts { posts, next_after, next_page }).ok()?;
+    Some(content::RawJson(json))
+}
+
+#[get("/api/v1/posts?<params..>")]
+async fn api_index(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let search = PostSearch {
+        blog_post: Some(false),
+        .. search
+    };
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/blog?<params..>")]
+async fn api_blog_index(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let search = PostSearch {
+        blog_post: Some(true),
+        order_by: PostSearchOrderBy::Newest,
+        .. search
+    };
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/new?<params..>")]
+async fn api_new(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let search = PostSearch {
+        order_by: PostSearchOrderBy::Newest,
+        blog_post: Some(false),
+        .. search
+    };
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/latest?<params..>")]
+async fn api_latest(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let search = PostSearch {
+        order_by: PostSearchOrderBy::Latest,
+        blog_post: Some(false),
+        .. search
+    };
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/top?<timespan>&<params..>")]
+async fn api_top(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>, timespan: Option<Timespan>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let mut search = PostSearch {
+        order_by: PostSearchOrderBy::Top,
+        blog_post: Some(false),
+        .. search
+    };
+    apply_timespan(&mut search, timespan);
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/random?<params..>")]
+async fn api_random(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let search = PostSearch {
+        order_by: PostSearchOrderBy::Random,
+        blog_post: Some(false),
+        .. search
+    };
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/search?<params..>")]
+async fn api_advanced_search(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/comments?<params..>")]
+async fn api_search_comments(conn: MoreInterestingConn, login: Option<LoginSession>, params: Option<SearchCommentsParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let search = parse_search_comments_params(&conn, &user, &params).await?;
+    let comments = conn.search_comments(&search).await.into_option()?;
+    let next_after = api::next_comments(&search, comments.last().map(|comment| comment.id));
+    let json = serde_json::to_string(&api::Comments { comments, next_after }).ok()?;
+    Some(content::RawJson(json))
+}
+
+#[get("/api/v1/users/<username>?<params..>")]
+async fn api_user_posts(conn: MoreInterestingConn, login: Option<LoginSession>, username: String, params: Option<IndexParams>) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let user_info = conn.get_user_by_username(&username[..]).await.into_option()?;
+    let (search, _tags) = parse_index_params(&conn, &user, params).await?;
+    let search = PostSearch {
+        for_user_id: user_info.id,
+        order_by: PostSearchOrderBy::Newest,
+        blog_post: None,
+        .. search
+    };
+    api_search_posts(&conn, &user, search).await
+}
+
+#[get("/api/v1/posts/<uuid>")]
+async fn api_get_comments(conn: MoreInterestingConn, login: Option<LoginSession>, uuid: Base32) -> Option<content::RawJson<String>> {
+    let user = login.map(|l| l.user).unwrap_or_default();
+    let post = conn.get_post_info_by_uuid(user.id, uuid).await.ok()?;
+    let comments = conn.get_comments_from_post(post.id, user.id).await.into_option()?;
+    let post = api::scrub_post(&user, post);
+    let json = serde_json::to_string(&api::PostWithComments { post, comments }).ok()?;
+    Some(content::RawJson(json))
+}
+
 #[get("/tags")]
 async fn get_tags(conn: MoreInterestingConn, login: Option<LoginSession>, config: &State<SiteConfig>, customization: Customization) -> template::Tags {
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
@@ -1914,7 +3687,7 @@ async fn get_admin_tags(conn: MoreInterestingConn, customization: Customization,
 
 #[get("/admin/flags")]
 async fn get_admin_flags(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>) -> template::AdminFlags {
-    let post_flags = conn.get_recent_post_flags().await;
+    let post_flags = flag_reason::group_by_reason(conn.get_recent_post_flags().await, |flag| &flag.reason);
     template::AdminFlags {
         title: String::from("recent flags"),
         user: login.user,
@@ -1928,7 +3701,7 @@ async fn get_admin_flags(conn: MoreInterestingConn, customization: Customization
 
 #[get("/admin/comment-flags")]
 async fn get_admin_comment_flags(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>) -> template::AdminCommentFlags {
-    let comment_flags = conn.get_recent_comment_flags().await;
+    let comment_flags = flag_reason::group_by_reason(conn.get_recent_comment_flags().await, |flag| &flag.reason);
     template::AdminCommentFlags {
         title: String::from("recent flags"),
         user: login.user,
@@ -1943,6 +3716,7 @@ async fn get_admin_comment_flags(conn: MoreInterestingConn, customization: Custo
 #[get("/admin/users")]
 async fn get_admin_users(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>) -> template::AdminUsers {
     let users_list = conn.get_recent_users(String::new()).await.unwrap_or(Vec::new());
+    let suspensions = conn.get_active_suspensions(users_list.iter().map(|user| user.id).collect()).await.unwrap_or(Vec::new());
     template::AdminUsers {
         title: String::from("recently logged in users"),
         user: login.user,
@@ -1951,13 +3725,14 @@ async fn get_admin_users(conn: MoreInterestingConn, customization: Customization
         config: config.inner().clone(),
         page: AdminPageId::Users,
         username: String::new(),
-        users_list, customization,
+        users_list, suspensions, customization,
     }
 }
 
 #[get("/admin/users?<username>")]
 async fn get_admin_users_search(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, username: &str) -> template::AdminUsers {
     let users_list = conn.get_recent_users(username.to_owned()).await.unwrap_or(Vec::new());
+    let suspensions = conn.get_active_suspensions(users_list.iter().map(|user| user.id).collect()).await.unwrap_or(Vec::new());
     template::AdminUsers {
         title: String::from("recently logged in users"),
         user: login.user,
@@ -1966,7 +3741,147 @@ async fn get_admin_users_search(conn: MoreInterestingConn, customization: Custom
         config: config.inner().clone(),
         page: AdminPageId::Users,
         username: username.to_owned(),
-        users_list, customization,
+        users_list, suspensions, customization,
+    }
+}
+
+#[derive(FromForm)]
+struct AdminUserTrustForm {
+    username: String,
+    trust_level: i32,
+}
+
+#[post("/admin/users/trust", data = "<form>")]
+async fn admin_user_trust(conn: MoreInterestingConn, login: ModeratorSession, form: Form<AdminUserTrustForm>) -> Flash<Redirect> {
+    let redirect = Redirect::to(format!("/admin/users?username={}", utf8_percent_encode(&form.username, NON_ALPHANUMERIC)));
+    let target = match conn.get_user_by_username(&form.username).await {
+        Ok(target) => target,
+        Err(_) => return Flash::error(redirect, "User not found"),
+    };
+    // Moderators can't promote anyone to their own level, or touch anyone already there.
+    if target.trust_level >= login.user.trust_level || form.trust_level >= login.user.trust_level || form.trust_level < -3 {
+        return Flash::error(redirect, "You can't set that trust level");
+    }
+    if target.trust_level == form.trust_level {
+        return Flash::success(redirect, "Trust level unchanged");
+    }
+    conn.change_user_trust_level(target.id, form.trust_level).await.expect("if the user exists, then so should changing trust level");
+    conn.mod_log(login.user.id, ModAction::TrustLevel {
+        user_id: target.id,
+        username: target.username.clone(),
+        old_trust_level: target.trust_level,
+        new_trust_level: form.trust_level,
+    }).await.expect("if updating the user worked, then so should logging");
+    Flash::success(redirect, format!("Set {}'s trust level to {}", target.username, form.trust_level))
+}
+
+#[derive(FromForm)]
+struct AdminUserBanForm {
+    username: String,
+    banned: bool,
+}
+
+#[post("/admin/users/ban", data = "<form>")]
+async fn admin_user_ban(conn: MoreInterestingConn, login: ModeratorSession, form: Form<AdminUserBanForm>) -> Flash<Redirect> {
+    let redirect = Redirect::to(format!("/admin/users?username={}", utf8_percent_encode(&form.username, NON_ALPHANUMERIC)));
+    let target = match conn.get_user_by_username(&form.username).await {
+        Ok(target) => target,
+        Err(_) => return Flash::error(redirect, "User not found"),
+    };
+    if target.trust_level >= login.user.trust_level {
+        return Flash::error(redirect, "You can't ban that user");
+    }
+    if target.banned == form.banned {
+        return Flash::success(redirect, "Ban unchanged");
+    }
+    conn.change_user_banned(target.id, form.banned).await.expect("if the user exists, then so should banning them");
+    conn.mod_log(login.user.id, ModAction::Ban {
+        user_id: target.id,
+        username: target.username.clone(),
+        banned: form.banned,
+    }).await.expect("if updating the user worked, then so should logging");
+    Flash::success(redirect, if form.banned { format!("Banned {}", target.username) } else { format!("Unbanned {}", target.username) })
+}
+
+/// Anything longer than this should be a ban.
+const MAX_SUSPENSION_DAYS: i64 = 365;
+
+#[derive(FromForm)]
+struct AdminUserSuspendForm {
+    username: String,
+    days: i64,
+    reason: String,
+}
+
+#[post("/admin/users/suspend", data = "<form>")]
+async fn admin_user_suspend(conn: MoreInterestingConn, login: ModeratorSession, form: Form<AdminUserSuspendForm>) -> Flash<Redirect> {
+    let redirect = Redirect::to(format!("/admin/users?username={}", utf8_percent_encode(&form.username, NON_ALPHANUMERIC)));
+    let target = match conn.get_user_by_username(&form.username).await {
+        Ok(target) => target,
+        Err(_) => return Flash::error(redirect, "User not found"),
+    };
+    if target.trust_level >= login.user.trust_level {
+        return Flash::error(redirect, "You can't suspend that user");
+    }
+    let reason = form.reason.trim();
+    if reason == "" {
+        return Flash::error(redirect, "Say why they're being suspended; they'll see it");
+    }
+    if form.days < 1 || form.days > MAX_SUSPENSION_DAYS {
+        return Flash::error(redirect, format!("Suspensions last from 1 to {} days", MAX_SUSPENSION_DAYS));
+    }
+    let suspension = conn.suspend_user(models::NewSuspension {
+        user_id: target.id,
+        ends_at: Utc::now().naive_utc() + Duration::days(form.days),
+        reason: reason.to_owned(),
+        created_by: login.user.id,
+    }).await.expect("if the user exists, then so should suspending them");
+    conn.mod_log(login.user.id, ModAction::Suspend {
+        user_id: target.id,
+        username: target.username.clone(),
+        ends_at: suspension.ends_at,
+        reason: suspension.reason,
+    }).await.expect("if suspending the user worked, then so should logging");
+    Flash::success(redirect, format!("Suspended {} until {} UTC", target.username, suspension.ends_at.format("%Y-%m-%d %H:%M")))
+}
+
+#[derive(FromForm)]
+struct AdminUserLiftSuspensionForm {
+    username: String,
+}
+
+#[post("/admin/users/lift-suspension", data = "<form>")]
+async fn admin_user_lift_suspension(conn: MoreInterestingConn, login: ModeratorSession, form: Form<AdminUserLiftSuspensionForm>) -> Flash<Redirect> {
+    let redirect = Redirect::to(format!("/admin/users?username={}", utf8_percent_encode(&form.username, NON_ALPHANUMERIC)));
+    let target = match conn.get_user_by_username(&form.username).await {
+        Ok(target) => target,
+        Err(_) => return Flash::error(redirect, "User not found"),
+    };
+    if target.trust_level >= login.user.trust_level {
+        return Flash::error(redirect, "You can't lift that user's suspension");
+    }
+    if !conn.lift_suspension(target.id, login.user.id).await.expect("if the user exists, then so should lifting their suspension") {
+        return Flash::success(redirect, "They weren't suspended");
+    }
+    conn.mod_log(login.user.id, ModAction::LiftSuspension {
+        user_id: target.id,
+        username: target.username.clone(),
+    }).await.expect("if lifting the suspension worked, then so should logging");
+    Flash::success(redirect, format!("Lifted {}'s suspension", target.username))
+}
+
+#[get("/admin/login-attempts?<search>")]
+async fn get_admin_login_attempts(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, search: Option<&str>) -> template::AdminLoginAttempts {
+    let search = search.unwrap_or("").trim().to_owned();
+    let login_attempts = conn.list_failed_login_attempts(search.clone()).await.unwrap_or(Vec::new());
+    template::AdminLoginAttempts {
+        title: String::from("failed logins"),
+        user: login.user,
+        session: login.session,
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        config: config.inner().clone(),
+        page: AdminPageId::LoginAttempts,
+        search, login_attempts, customization,
     }
 }
 
@@ -1993,39 +3908,70 @@ async fn admin_tags(conn: MoreInterestingConn, _login: ModeratorSession, form: F
     }
 }
 
-#[get("/admin/blocked-regexes")]
-async fn get_admin_blocked_regexes(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>) -> template::AdminBlockedRegexes {
+/// How much recent content a blocked regex gets tried on, and how many of the matches get shown.
+const BLOCKED_REGEX_TEST_SAMPLES: i64 = 1000;
+const BLOCKED_REGEX_TEST_MATCHES: usize = 100;
+
+#[get("/admin/blocked-regexes?<test_regex>&<test_scope>")]
+async fn get_admin_blocked_regexes(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, test_regex: Option<&str>, test_scope: Option<&str>) -> template::AdminBlockedRegexes {
     let blocked_regexes = conn.get_all_blocked_regexes().await.unwrap_or(Vec::new());
+    let mut alert = flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new);
+    let test_regex = test_regex.unwrap_or("").to_owned();
+    let scope = test_scope.and_then(Scope::parse).unwrap_or(Scope::Excerpt);
+    // This is a dry run, so nothing gets hidden or counted as a hit.
+    let mut test_matches = Vec::new();
+    let mut test_sample_count = 0;
+    if !test_regex.is_empty() {
+        match Regex::new(&test_regex) {
+            Ok(regex) => {
+                let samples = conn.get_content_samples(scope, BLOCKED_REGEX_TEST_SAMPLES).await.unwrap_or(Vec::new());
+                test_sample_count = samples.len();
+                test_matches = samples.into_iter().filter(|sample| regex.is_match(&sample.text)).take(BLOCKED_REGEX_TEST_MATCHES).collect();
+            }
+            Err(e) => alert = e.to_string(),
+        }
+    }
     template::AdminBlockedRegexes {
         title: String::from("add or edit blocked regexes"),
         user: login.user,
         session: login.session,
-        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
         config: config.inner().clone(),
         page: AdminPageId::BlockedRegexes,
-        blocked_regexes, customization,
+        scopes: Scope::ALL.iter().map(|&s| (s.as_str(), s.label(), s == scope)).collect(),
+        actions: blocked_regex::Action::ALL.iter().map(|&a| (a.as_str(), a.label())).collect(),
+        alert, blocked_regexes, test_regex, test_matches, test_sample_count, customization,
     }
 }
 
 #[derive(FromForm)]
 struct AddBlockedRegexForm {
     regex: String,
+    scope: String,
+    action: String,
+    message: String,
 }
 
 #[post("/admin/blocked-regexes", data = "<form>")]
 async fn add_admin_blocked_regex(conn: MoreInterestingConn, _login: ModeratorSession, form: Form<AddBlockedRegexForm>) -> Option<Flash<Redirect>> {
     if let Err(e) = Regex::new(&form.regex) {
-        return Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes)), e.to_string()));
+        return Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes(_, _))), e.to_string()));
+    }
+    if Scope::parse(&form.scope).is_none() || blocked_regex::Action::parse(&form.action).is_none() {
+        return Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes(_, _))), "Unknown scope or action"));
     }
-    match conn.add_blocked_regex(models::NewBlockedRegex {
+    let result = conn.add_blocked_regex(models::NewBlockedRegex {
         regex: form.regex.clone(),
-    }).await {
+        scope: form.scope.clone(),
+        action: form.action.clone(),
+        message: form.message.trim().to_owned(),
+    }).await;
+    match result {
         Ok(_) => {
-            Some(Flash::success(Redirect::to(uri!(get_admin_blocked_regexes)), "Added new blocked regex"))
+            Some(Flash::success(Redirect::to(uri!(get_admin_blocked_regexes(_, _))), "Added new blocked regex"))
         }
         Err(e) => {
             warn!("Unable to add blocked regex: {:?}", e);
-            Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes)), "Unable to add blocked regex"))
+            Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes(_, _))), "Unable to add blocked regex"))
         }
     }
 }
@@ -2037,13 +3983,14 @@ struct DeleteBlockedRegexForm {
 
 #[post("/admin/delete-blocked-regex", data = "<form>")]
 async fn delete_admin_blocked_regex(conn: MoreInterestingConn, _login: ModeratorSession, form: Form<DeleteBlockedRegexForm>) -> Option<Flash<Redirect>> {
-    match conn.delete_blocked_regex(form.id).await {
+    let result = conn.delete_blocked_regex(form.id).await;
+    match result {
         Ok(_) => {
-            Some(Flash::success(Redirect::to(uri!(get_admin_blocked_regexes)), "Deleted blocked regex"))
+            Some(Flash::success(Redirect::to(uri!(get_admin_blocked_regexes(_, _))), "Deleted blocked regex"))
         }
         Err(e) => {
             warn!("Unable to delete blocked regex: {:?}", e);
-            Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes)), "Unable to delete blocked regex"))
+            Some(Flash::error(Redirect::to(uri!(get_admin_blocked_regexes(_, _))), "Unable to delete blocked regex"))
         }
     }
 }
@@ -2093,6 +4040,76 @@ async fn admin_domains(conn: MoreInterestingConn, _login: ModeratorSession, form
     }
 }
 
+#[get("/admin/domain-restrictions?<hostname>")]
+async fn get_admin_domain_restrictions(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>, hostname: Option<&str>) -> template::AdminDomainRestrictions {
+    let hostname = hostname.unwrap_or("").trim().to_owned();
+    let domain_stats = conn.get_domain_stats(hostname.clone()).await.unwrap_or(Vec::new());
+    template::AdminDomainRestrictions {
+        title: String::from("domain restrictions"),
+        user: login.user,
+        session: login.session,
+        alert: flash.map(|f| f.message().to_owned()).unwrap_or_else(String::new),
+        config: config.inner().clone(),
+        page: AdminPageId::DomainRestrictions,
+        hostname, domain_stats, customization,
+    }
+}
+
+#[derive(FromForm)]
+struct AdminDomainRestrictionForm {
+    hostname: String,
+    restriction_level: i32,
+}
+
+#[post("/admin/domain-restrictions/level", data = "<form>")]
+async fn admin_domain_restriction(conn: MoreInterestingConn, login: ModeratorSession, form: Form<AdminDomainRestrictionForm>) -> Flash<Redirect> {
+    let redirect = Redirect::to(format!("/admin/domain-restrictions?hostname={}", utf8_percent_encode(&form.hostname, NON_ALPHANUMERIC)));
+    let domain = match conn.get_domain_by_hostname(&form.hostname).await {
+        Ok(domain) => domain,
+        Err(_) => return Flash::error(redirect, "Domain not found"),
+    };
+    if form.restriction_level != 0 && form.restriction_level != DomainRestriction::QUEUE && form.restriction_level != DomainRestriction::BLOCK {
+        return Flash::error(redirect, "Unknown restriction level");
+    }
+    let old_restriction_level = conn.get_domain_restriction_by_id(domain.id).await.map(|restriction| restriction.restriction_level).unwrap_or(0);
+    if old_restriction_level == form.restriction_level {
+        return Flash::success(redirect, "Restriction unchanged");
+    }
+    conn.set_domain_restriction(domain.id, form.restriction_level).await.expect("if the domain exists, then so should restricting it");
+    conn.mod_log(login.user.id, ModAction::DomainRestriction {
+        domain_id: domain.id,
+        hostname: domain.hostname.clone(),
+        old_restriction_level,
+        new_restriction_level: form.restriction_level,
+    }).await.expect("if updating the domain worked, then so should logging");
+    Flash::success(redirect, format!("Updated the restriction on {}", domain.hostname))
+}
+
+#[derive(FromForm)]
+struct AdminDomainBanForm {
+    hostname: String,
+    banned: bool,
+}
+
+#[post("/admin/domain-restrictions/ban", data = "<form>")]
+async fn admin_domain_ban(conn: MoreInterestingConn, login: ModeratorSession, form: Form<AdminDomainBanForm>) -> Flash<Redirect> {
+    let redirect = Redirect::to(format!("/admin/domain-restrictions?hostname={}", utf8_percent_encode(&form.hostname, NON_ALPHANUMERIC)));
+    let domain = match conn.get_domain_by_hostname(&form.hostname).await {
+        Ok(domain) => domain,
+        Err(_) => return Flash::error(redirect, "Domain not found"),
+    };
+    if domain.banned == form.banned {
+        return Flash::success(redirect, "Ban unchanged");
+    }
+    conn.change_domain_banned(domain.id, form.banned).await.expect("if the domain exists, then so should banning it");
+    conn.mod_log(login.user.id, ModAction::DomainBan {
+        domain_id: domain.id,
+        hostname: domain.hostname.clone(),
+        banned: form.banned,
+    }).await.expect("if updating the domain worked, then so should logging");
+    Flash::success(redirect, if form.banned { format!("Banned {}", domain.hostname) } else { format!("Unbanned {}", domain.hostname) })
+}
+
 #[get("/admin/customization")]
 async fn get_admin_customization(conn: MoreInterestingConn, customization: Customization, login: ModeratorSession, flash: Option<FlashMessage<'_>>, config: &State<SiteConfig>) -> template::AdminCustomization {
     let site_customization = conn.get_customizations().await.unwrap_or(Vec::new());
@@ -2188,13 +4205,12 @@ async fn edit_post(conn: MoreInterestingConn, login: ModeratorSession, form: For
         match conn.delete_post(post_id).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_delete_post(
-                        login.user.id,
-                        post_info.uuid,
-                        post_info.title,
-                        post_info.url.unwrap_or(String::new()),
-                        post.excerpt.unwrap_or(String::new()),
-                    ).await.expect("if updating the post worked, then so should logging");
+                    conn.mod_log(login.user.id, ModAction::DeletePost {
+                        post_uuid: post_info.uuid,
+                        old_title: post_info.title,
+                        old_url: post_info.url.unwrap_or(String::new()),
+                        old_excerpt: post.excerpt.unwrap_or(String::new()),
+                    }).await.expect("if updating the post worked, then so should logging");
                 }
                 return Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Deleted post"))
             },
@@ -2218,16 +4234,15 @@ async fn edit_post(conn: MoreInterestingConn, login: ModeratorSession, form: For
         }, config.body_format).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_edit_post(
-                        login.user.id,
-                        post_info.uuid,
-                        post_info.title,
-                        form.title.clone(),
-                        post_info.url.unwrap_or(String::new()),
-                        url.unwrap_or(String::new()),
-                        post.excerpt.unwrap_or(String::new()),
-                        form.excerpt.clone().unwrap_or(String::new()),
-                    ).await.expect("if updating the post worked, then so should logging");
+                    conn.mod_log(login.user.id, ModAction::EditPost {
+                        post_uuid: post_info.uuid,
+                        old_title: post_info.title,
+                        new_title: form.title.clone(),
+                        old_url: post_info.url.unwrap_or(String::new()),
+                        new_url: url.unwrap_or(String::new()),
+                        old_excerpt: post.excerpt.unwrap_or(String::new()),
+                        new_excerpt: form.excerpt.clone().unwrap_or(String::new()),
+                    }).await.expect("if updating the post worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(form.post.to_string()), "Updated post"))
             },
@@ -2287,12 +4302,11 @@ async fn edit_comment(conn: MoreInterestingConn, login: LoginSession, form: Form
         match conn.delete_comment(comment.id).await {
             Ok(_) => {
                 if !post.private {
-                    conn.mod_log_delete_comment(
-                        user.id,
-                        comment.id,
-                        post.uuid,
-                        comment.text,
-                    ).await.expect("if updating the comment worked, then so should logging");
+                    conn.mod_log(user.id, ModAction::DeleteComment {
+                        comment_id: comment.id,
+                        post_uuid: post.uuid,
+                        old_text: comment.text,
+                    }).await.expect("if updating the comment worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Deleted comment"))
             },
@@ -2305,13 +4319,12 @@ async fn edit_comment(conn: MoreInterestingConn, login: LoginSession, form: Form
         match conn.update_comment(post.id, form.comment, form.text.clone(), config.body_format).await {
             Ok(_) => {
                 if !post.private {
-                    conn.mod_log_edit_comment(
-                        user.id,
-                        comment.id,
-                        post.uuid,
-                        comment.text,
-                        form.text.clone(),
-                    ).await.expect("if updating the comment worked, then so should logging");
+                    conn.mod_log(user.id, ModAction::EditComment {
+                        comment_id: comment.id,
+                        post_uuid: post.uuid,
+                        old_text: comment.text,
+                        new_text: form.text.clone(),
+                    }).await.expect("if updating the comment worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(post.uuid.to_string()), "Updated comment"))
             },
@@ -2355,19 +4368,30 @@ struct ChangePasswordForm {
 }
 
 #[post("/change-password", data = "<form>")]
-async fn change_password(conn: MoreInterestingConn, login: LoginSession, form: Form<ChangePasswordForm>) -> Result<Flash<Redirect>, Status> {
+async fn change_password(conn: MoreInterestingConn, login: LoginSession, form: Form<ChangePasswordForm>, ip: ClientIp, config: &State<SiteConfig>) -> Result<Flash<Redirect>, Status> {
     let user = login.user;
     if form.new_password == "" {
         return Err(Status::BadRequest);
     }
+    // Someone with a stolen session shouldn't get unlimited guesses at the password, either.
+    let ip_address = ip_address_string(ip);
+    if !check_login_throttle(&conn, config, Some(&user.username), &ip_address).await.is_allowed() {
+        return Err(Status::TooManyRequests);
+    }
     if conn.authenticate_user(&UserAuth {
         username: user.username.clone(),
         password: form.old_password.clone(),
     }).await.is_none() {
+        record_login_attempt(&conn, models::LoginAttemptKind::ChangePassword, &user.username, Some(user.id), &ip_address, false).await;
         return Err(Status::BadRequest);
     }
     match conn.change_user_password(user.id, &form.new_password).await {
-        Ok(()) => Ok(Flash::success(Redirect::to(uri!(get_settings)), "Done!")),
+        Ok(()) => {
+            if let Err(e) = conn.revoke_other_sessions(user.id, login.session.uuid).await {
+                warn!("Failed to log out other sessions after password change: {:?}", e);
+            }
+            Ok(Flash::success(Redirect::to(uri!(get_settings)), "Done! You've been logged out everywhere else."))
+        }
         Err(_) => Err(Status::BadRequest),
     }
 }
@@ -2419,7 +4443,7 @@ struct ModeratePostForm {
 }
 
 #[post("/moderate-post", data = "<form>")]
-async fn moderate_post(conn: MoreInterestingConn, login: ModeratorSession, form: Form<ModeratePostForm>) -> Result<Flash<Redirect>, Status> {
+async fn moderate_post(conn: MoreInterestingConn, login: ModeratorSession, form: Form<ModeratePostForm>, config: &State<SiteConfig>) -> Result<Flash<Redirect>, Status> {
     let user = login.user;
     let post_info = if let Ok(post_info) = conn.get_post_info_by_uuid(user.id, form.post).await {
         post_info
@@ -2433,16 +4457,15 @@ async fn moderate_post(conn: MoreInterestingConn, login: ModeratorSession, form:
         return Err(Status::NotFound);
     };
     if form.action == "approve" {
-        match conn.approve_post(post_id).await {
+        match conn.approve_post(post_id, config.body_format).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_approve_post(
-                        user.id,
-                        post_info.uuid,
-                        post_info.title,
-                        post_info.url.unwrap_or(String::new()),
-                        post.excerpt.unwrap_or(String::new()),
-                    ).await.expect("if updating the post worked, then so should logging");
+                    conn.mod_log(user.id, ModAction::ApprovePost {
+                        post_uuid: post_info.uuid,
+                        new_title: post_info.title,
+                        new_url: post_info.url.unwrap_or(String::new()),
+                        new_excerpt: post.excerpt.unwrap_or(String::new()),
+                    }).await.expect("if updating the post worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Approved post"))
             },
@@ -2455,13 +4478,12 @@ async fn moderate_post(conn: MoreInterestingConn, login: ModeratorSession, form:
         match conn.delete_post(post_id).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_delete_post(
-                        user.id,
-                        post_info.uuid,
-                        post_info.title,
-                        post_info.url.unwrap_or(String::new()),
-                        post.excerpt.unwrap_or(String::new()),
-                    ).await.expect("if updating the post worked, then so should logging");
+                    conn.mod_log(user.id, ModAction::DeletePost {
+                        post_uuid: post_info.uuid,
+                        old_title: post_info.title,
+                        old_url: post_info.url.unwrap_or(String::new()),
+                        old_excerpt: post.excerpt.unwrap_or(String::new()),
+                    }).await.expect("if updating the post worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Deleted post"))
             },
@@ -2498,12 +4520,11 @@ async fn create_poll(conn: MoreInterestingConn, login: ModeratorSession, form: F
     match conn.create_poll(post_id, title, choices, login.user.id).await {
         Ok(poll) => {
             if !post_info.private {
-                conn.mod_log_poll_post(
-                    login.user.id,
-                    post_info.uuid,
-                    poll.title,
-                    poll.id,
-                ).await.expect("if updating the post worked, then so should logging");
+                conn.mod_log(login.user.id, ModAction::PollPost {
+                    post_uuid: post_info.uuid,
+                    poll_title: poll.title,
+                    poll: poll.id,
+                }).await.expect("if updating the post worked, then so should logging");
             }
             Ok(Flash::success(Redirect::to(form.post.to_string()), "Added poll to post"))
         },
@@ -2529,11 +4550,10 @@ async fn close_poll(conn: MoreInterestingConn, login: ModeratorSession, form: Fo
     match conn.close_poll(form.poll).await {
         Ok(poll) => {
             if !post_info.private {
-                conn.mod_log_close_poll(
-                    login.user.id,
-                    form.post,
-                    poll.id,
-                ).await.expect("if updating the post worked, then so should logging");
+                conn.mod_log(login.user.id, ModAction::ClosePoll {
+                    post_uuid: form.post,
+                    poll: poll.id,
+                }).await.expect("if updating the post worked, then so should logging");
             }
             Ok(Flash::success(Redirect::to(form.post.to_string()), "Added poll to post"))
         },
@@ -2584,12 +4604,11 @@ async fn banner_post(conn: MoreInterestingConn, login: ModeratorSession, form: F
     match conn.banner_post(post_id, banner_title.clone(), banner_desc.clone()).await {
         Ok(_) => {
             if !post_info.private {
-                conn.mod_log_banner_post(
-                    login.user.id,
-                    post_info.uuid,
-                    banner_title.unwrap_or(String::new()),
-                    banner_desc.unwrap_or(String::new()),
-                ).await.expect("if updating the post worked, then so should logging");
+                conn.mod_log(login.user.id, ModAction::BannerPost {
+                    post_uuid: post_info.uuid,
+                    banner_title: banner_title.unwrap_or(String::new()),
+                    banner_desc: banner_desc.unwrap_or(String::new()),
+                }).await.expect("if updating the post worked, then so should logging");
             }
             Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Added banner to post"))
         },
@@ -2619,11 +4638,10 @@ async fn advanced_post(conn: MoreInterestingConn, login: ModeratorSession, form:
         match conn.lock_post(post_id, form.locked).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_lock(
-                        login.user.id,
-                        post_info.uuid,
-                        form.locked,
-                    ).await.expect("if updating the post worked, then so should logging");
+                    conn.mod_log(login.user.id, ModAction::Lock {
+                        post_uuid: post_info.uuid,
+                        locked: form.locked,
+                    }).await.expect("if updating the post worked, then so should logging");
                 }
             },
             Err(e) => {
@@ -2636,11 +4654,10 @@ async fn advanced_post(conn: MoreInterestingConn, login: ModeratorSession, form:
         match conn.noindex_post(post_id, form.noindex).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_noindex(
-                        login.user.id,
-                        post_info.uuid,
-                        form.noindex,
-                    ).await.expect("if updating the post worked, then so should logging");
+                    conn.mod_log(login.user.id, ModAction::Noindex {
+                        post_uuid: post_info.uuid,
+                        noindex: form.noindex,
+                    }).await.expect("if updating the post worked, then so should logging");
                 }
             },
             Err(e) => {
@@ -2659,7 +4676,7 @@ struct ModerateCommentForm {
 }
 
 #[post("/moderate-comment", data = "<form>")]
-async fn moderate_comment(conn: MoreInterestingConn, login: ModeratorSession, form: Form<ModerateCommentForm>) -> Result<Flash<Redirect>, Status> {
+async fn moderate_comment(conn: MoreInterestingConn, login: ModeratorSession, form: Form<ModerateCommentForm>, config: &State<SiteConfig>) -> Result<Flash<Redirect>, Status> {
     let comment_info = if let Ok(comment) = conn.get_comment_by_id(form.comment).await {
         comment
     } else {
@@ -2667,15 +4684,14 @@ async fn moderate_comment(conn: MoreInterestingConn, login: ModeratorSession, fo
     };
     let post_info = conn.get_post_info_from_comment(comment_info.id).await.unwrap();
     if form.action == "approve" {
-        match conn.approve_comment(comment_info.id).await {
+        match conn.approve_comment(comment_info.id, config.body_format).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_approve_comment(
-                        login.user.id,
-                        comment_info.id,
-                        post_info.uuid,
-                        comment_info.text,
-                    ).await.expect("if updating the comment worked, then so should logging");
+                    conn.mod_log(login.user.id, ModAction::ApproveComment {
+                        comment_id: comment_info.id,
+                        post_uuid: post_info.uuid,
+                        new_text: comment_info.text,
+                    }).await.expect("if updating the comment worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Approved comment"))
             },
@@ -2688,12 +4704,11 @@ async fn moderate_comment(conn: MoreInterestingConn, login: ModeratorSession, fo
         match conn.delete_comment(comment_info.id).await {
             Ok(_) => {
                 if !post_info.private {
-                    conn.mod_log_delete_comment(
-                        login.user.id,
-                        comment_info.id,
-                        post_info.uuid,
-                        comment_info.text,
-                    ).await.expect("if updating the comment worked, then so should logging");
+                    conn.mod_log(login.user.id, ModAction::DeleteComment {
+                        comment_id: comment_info.id,
+                        post_uuid: post_info.uuid,
+                        old_text: comment_info.text,
+                    }).await.expect("if updating the comment worked, then so should logging");
                 }
                 Ok(Flash::success(Redirect::to(uri!(get_mod_queue)), "Deleted comment"))
             },
@@ -2710,6 +4725,7 @@ async fn random(conn: MoreInterestingConn, login: Option<LoginSession>, params:
     let (user, session) = login.map(|l| (l.user, l.session)).unwrap_or((User::default(), UserSession::default()));
     let tag_param = params.as_ref().and_then(|params| Some(params.tag.as_ref()?.to_string())).unwrap_or_else(String::new);
     let domain = params.as_ref().and_then(|params| Some(params.domain.as_ref()?.to_string())).unwrap_or_else(String::new);
+    let query_param = params.as_ref().and_then(|params| params.q.clone());
     let (search, tags) = parse_index_params(&conn, &user, params).await?;
     let before_date_param = search.before_date;
     let after_date_param = search.after_date;
@@ -2718,7 +4734,7 @@ async fn random(conn: MoreInterestingConn, login: Option<LoginSession>, params:
         blog_post: Some(false),
         .. search
     };
-    let keywords_param = search.keywords.clone();
+    let keywords_param = query_param.unwrap_or_else(|| search.keywords.clone());
     let title_param = search.title.clone();
     let is_home = tag_param == "" && domain == "" && keywords_param == "";
     let posts = conn.search_posts(&search).await.ok()?;
@@ -2857,6 +4873,61 @@ fn launch() -> rocket::Rocket<rocket::Build> {
     rocket::build()
         .attach(MoreInterestingConn::fairing())
         .attach(fairing::AdHoc::config::<SiteConfig>())
+        .attach(fairing::AdHoc::try_on_ignite("password hashing", |rocket| {
+            Box::pin(async move {
+                let config = rocket.state::<SiteConfig>().cloned().unwrap_or_default();
+                match password::configure(password::Argon2Params {
+                    memory_kib: config.argon2_memory_kib,
+                    iterations: config.argon2_iterations,
+                    parallelism: config.argon2_parallelism,
+                }) {
+                    Ok(()) => Ok(rocket),
+                    Err(e) => {
+                        error!("{}", e);
+                        Err(rocket)
+                    }
+                }
+            })
+        }))
+        .attach(fairing::AdHoc::try_on_ignite("trusted proxies", |rocket| {
+            Box::pin(async move {
+                let config = rocket.state::<SiteConfig>().cloned().unwrap_or_default();
+                match client_ip::parse_trusted_proxies(&config.trusted_proxy) {
+                    Ok(_) => Ok(rocket),
+                    Err(e) => {
+                        error!("{}", e);
+                        Err(rocket)
+                    }
+                }
+            })
+        }))
+        .attach(fairing::AdHoc::try_on_ignite("single sign-on", |rocket| {
+            Box::pin(async move {
+                let config = rocket.state::<SiteConfig>().cloned().unwrap_or_default();
+                if config.oidc_issuer == "" {
+                    return Ok(rocket);
+                }
+                match oidc::check_url(&config.oidc_issuer, config.oidc_allow_http) {
+                    Ok(()) => Ok(rocket),
+                    Err(e) => {
+                        error!("{}", e);
+                        Err(rocket)
+                    }
+                }
+            })
+        }))
+        .attach(fairing::AdHoc::try_on_ignite("ranking", |rocket| {
+            Box::pin(async move {
+                let config = rocket.state::<SiteConfig>().cloned().unwrap_or_default();
+                match ranking::configure(&config.ranking, config.ranking_params()) {
+                    Ok(()) => Ok(rocket),
+                    Err(e) => {
+                        error!("{}", e);
+                        Err(rocket)
+                    }
+                }
+            })
+        }))
         .attach(fairing::AdHoc::on_liftoff("setup", |rocket| {
             Box::pin(async move {
                 let conn = MoreInterestingConn::get_one(&rocket).await.unwrap();
@@ -2878,7 +4949,7 @@ fn launch() -> rocket::Rocket<rocket::Build> {
                 }
             })
         }))
-        .mount("/", routes![index, blog_index, advanced_search, login_form, login, logout, create_link_form, create_post_form, create, post_preview, submit_preview, get_comments, vote, signup, get_settings, create_invite, invite_tree, change_password, post_comment, vote_comment, get_admin_tags, admin_tags, get_tags, edit_post, get_edit_post, edit_comment, get_edit_comment, set_dark_mode, set_big_mode, mod_log, get_mod_queue, moderate_post, moderate_comment, get_public_signup, random, redirect_legacy_id, latest, rss, blog_rss, top, banner_post, advanced_post, robots_txt, search_comments, new, get_admin_domains, admin_domains, create_message_form, create_message, subscriptions, post_subscriptions, get_reply_comment, preview_comment, get_admin_customization, admin_customization, conv_legacy_id, get_tags_json, get_domains_json, get_admin_flags, get_admin_comment_flags, get_admin_users, get_admin_users_search, faq, identicon, create_poll, close_poll, vote_poll, get_admin_blocked_regexes, add_admin_blocked_regex, delete_admin_blocked_regex])
+        .mount("/", routes![index, blog_index, advanced_search, login_form, login, logout, create_link_form, create_post_form, create, post_preview, submit_preview, get_comments, vote, signup, get_settings, create_invite, invite_tree, change_password, post_comment, vote_comment, get_admin_tags, admin_tags, get_tags, edit_post, get_edit_post, edit_comment, get_edit_comment, set_dark_mode, set_big_mode, set_notify_on_mention, mod_log, mod_log_json, mod_log_csv, get_mod_queue, moderate_post, moderate_comment, get_public_signup, random, redirect_legacy_id, latest, rss, blog_rss, top, banner_post, advanced_post, robots_txt, search_comments, new, get_admin_domains, admin_domains, get_admin_domain_restrictions, admin_domain_restriction, admin_domain_ban, create_message_form, create_message, subscriptions, post_subscriptions, get_reply_comment, preview_comment, get_admin_customization, admin_customization, conv_legacy_id, get_tags_json, get_domains_json, get_admin_flags, get_admin_comment_flags, get_admin_users, get_admin_users_search, admin_user_trust, admin_user_ban, admin_user_suspend, admin_user_lift_suspension, faq, identicon, create_poll, close_poll, vote_poll, get_admin_blocked_regexes, add_admin_blocked_regex, delete_admin_blocked_regex, api_index, api_blog_index, api_new, api_latest, api_top, api_random, api_advanced_search, api_search_comments, api_user_posts, api_get_comments, create_api_token, revoke_api_token, atom, json_feed, blog_atom, blog_json_feed, comments_rss, comments_atom, comments_json_feed, live_comments, private_feed, reset_private_feed, revoke_private_feed, login_totp_form, login_totp, begin_totp, confirm_totp, regenerate_recovery_codes, disable_totp, rename_session, revoke_session, revoke_other_sessions, forgot_password_form, forgot_password, reset_password_form, reset_password, set_email, verify_email, remove_email, get_admin_login_attempts, oidc_login, oidc_link, oidc_callback, oidc_unlink, save_search, delete_saved_search])
         .mount("/assets", FileServer::from("assets"))
         .register("/submit", catchers![unauthorized_to_login])
         .attach(PidFileFairing)
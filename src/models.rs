@@ -4,25 +4,43 @@ use diesel::prelude::*;
 use diesel::sql_types;
 use diesel::result::Error as DieselError;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc, Duration};
-use crate::schema::{site_customization, users, user_sessions, posts, stars, invite_tokens, comments, comment_stars, tags, post_tagging, moderation, flags, comment_flags, domains, legacy_comments, domain_synonyms, notifications, subscriptions, post_hides, comment_hides, post_word_freq, comment_readpoints, domain_restrictions, polls, poll_votes, poll_choices, blocked_regexes};
-use crate::password::{password_hash, password_verify, PasswordResult};
+use crate::schema::{api_tokens, site_customization, users, user_sessions, posts, stars, invite_tokens, comments, comment_stars, tags, post_tagging, moderation, flags, comment_flags, domains, legacy_comments, domain_synonyms, notifications, subscriptions, post_hides, comment_hides, post_word_freq, comment_readpoints, domain_restrictions, polls, poll_votes, poll_choices, blocked_regexes, suspensions, private_feed_tokens, user_totp, totp_recovery_codes, pending_logins, saved_searches, comment_search_index, login_attempts, pending_oidc_logins, external_identities, email_verification_tokens, password_reset_tokens};
+use crate::password::{password_hash, password_verify, password_needs_rehash, PasswordResult};
 use serde::{Deserialize, Serialize};
 use more_interesting_base32::Base32;
-use std::cmp::max;
 use std::cmp::Ordering;
 use std::mem;
 use ordered_float::OrderedFloat;
 use std::collections::{HashMap, HashSet};
 use crate::prettify::{self, prettify_title};
-use serde_json::{self as json, json};
+use serde_json as json;
 use url::Url;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::convert::TryInto;
+use std::sync::Arc;
+use crate::ranking::{self, Ranking, RankInput};
+use crate::mod_action::{ModAction, Target};
+use crate::flag_reason::{FlagReason, FlagThresholds};
+use crate::blocked_regex;
+use crate::api_token;
 
 sql_function!(fn coalesce(x: sql_types::Nullable<sql_types::VarChar>, y: sql_types::VarChar) -> sql_types::VarChar);
 no_arg_sql_function!(random, sql_types::BigInt, "Random number");
+// Unlike plainto_tsquery, this understands "quoted phrases", OR, and -exclusions.
+sql_function!(fn websearch_to_tsquery(x: sql_types::Text) -> diesel_full_text_search::TsQuery);
 
 const FLAG_INVISIBLE_THRESHOLD: i64 = 3;
+const PENDING_LOGIN_MINUTES: i64 = 5;
+const DOMAIN_STATS_RECENT_DAYS: i64 = 30;
+const DOMAIN_STATS_LIMIT: usize = 50;
+const PASSWORD_RESET_MINUTES: i64 = 60;
+const EMAIL_VERIFICATION_HOURS: i64 = 24;
+const LOGIN_ATTEMPT_RETENTION_DAYS: i64 = 30;
+const PENDING_OIDC_LOGIN_MINUTES: i64 = 10;
+pub const MAX_SAVED_SEARCHES: i64 = 20;
+/// A post or comment that @mentions more people than this doesn't notify any of them.
+pub const MAX_MENTIONS: usize = 10;
+pub const COMMENTS_PER_PAGE: usize = 100;
 
 #[derive(Debug)]
 pub enum CreateCommentError {
@@ -43,6 +61,7 @@ pub enum CreatePostError {
     TooManyPosts,
     TooManyPostsDomain,
     TooManyPostsDomainUser,
+    DomainBlocked,
     TooLong
 }
 
@@ -72,15 +91,56 @@ pub struct Moderation {
     pub payload: json::Value,
     pub created_at: NaiveDateTime,
     pub created_by: i32,
+    pub action: String,
+    pub target_user_id: Option<i32>,
 }
 
 #[derive(Serialize)]
 pub struct ModerationInfo {
     pub id: i32,
+    /// `None` if the payload isn't any `ModAction` this version knows about.
+    pub action: Option<ModAction>,
+    /// The `type` from the payload, even if the rest of it didn't parse.
+    pub kind: String,
     pub payload: json::Value,
     pub created_at: NaiveDateTime,
     pub created_by: i32,
     pub created_by_username: String,
+    pub target_user_id: Option<i32>,
+    pub target_username: Option<String>,
+}
+
+impl ModerationInfo {
+    pub fn kind_label(&self) -> &str {
+        crate::mod_action::kind_label(&self.kind)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ModLogSearch {
+    /// Only filter by `action` this if it's not empty.
+    pub kind: String,
+    pub created_by: Option<i32>,
+    pub target_user_id: Option<i32>,
+    pub before_date: Option<NaiveDate>,
+    pub after_date: Option<NaiveDate>,
+    /// Cursor for paging backwards: only entries older than this one.
+    pub before_id: Option<i32>,
+    pub limit: i64,
+}
+
+impl Default for ModLogSearch {
+    fn default() -> Self {
+        ModLogSearch {
+            kind: String::new(),
+            created_by: None,
+            target_user_id: None,
+            before_date: None,
+            after_date: None,
+            before_id: None,
+            limit: 50,
+        }
+    }
 }
 
 #[derive(Queryable, QueryableByName, Serialize)]
@@ -166,6 +226,209 @@ pub struct UserSession {
     pub user_agent: String,
     pub user_id: i32,
     pub last_seen_at: NaiveDateTime,
+    pub name: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct UserSessionInfo {
+    pub uuid: Base32,
+    pub name: Option<String>,
+    pub device: String,
+    pub created_at: NaiveDateTime,
+    pub last_seen_at: NaiveDateTime,
+    pub last_seen_at_relative: String,
+    pub is_current: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ApiScope {
+    Read,
+    Vote,
+    Post,
+    Moderate,
+}
+
+#[derive(Clone, Queryable, Serialize)]
+pub struct ApiToken {
+    pub uuid: Base32,
+    pub user_id: i32,
+    pub name: String,
+    #[serde(skip)]
+    pub token_hash: Vec<u8>,
+    pub scope_read: bool,
+    pub scope_vote: bool,
+    pub scope_post: bool,
+    pub scope_moderate: bool,
+    pub created_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+}
+
+impl ApiToken {
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        match scope {
+            ApiScope::Read => self.scope_read,
+            ApiScope::Vote => self.scope_vote,
+            ApiScope::Post => self.scope_post,
+            ApiScope::Moderate => self.scope_moderate,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NewApiToken {
+    pub user_id: i32,
+    pub name: String,
+    pub scope_read: bool,
+    pub scope_vote: bool,
+    pub scope_post: bool,
+    pub scope_moderate: bool,
+}
+
+#[derive(Clone, Queryable, Serialize)]
+pub struct PrivateFeedToken {
+    pub uuid: Base32,
+    pub user_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoginAttemptKind {
+    Login,
+    TwoFactor,
+    Signup,
+    ChangePassword,
+    /// Settings that ask for the password again, like setting an email address.
+    ConfirmPassword,
+    PasswordReset,
+    SingleSignOn,
+}
+
+impl LoginAttemptKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LoginAttemptKind::Login => "login",
+            LoginAttemptKind::TwoFactor => "2fa",
+            LoginAttemptKind::Signup => "signup",
+            LoginAttemptKind::ChangePassword => "change_password",
+            LoginAttemptKind::ConfirmPassword => "confirm_password",
+            LoginAttemptKind::PasswordReset => "password_reset",
+            LoginAttemptKind::SingleSignOn => "sso",
+        }
+    }
+}
+
+#[derive(Clone, Queryable, Serialize)]
+pub struct LoginAttempt {
+    pub id: i32,
+    pub kind: String,
+    pub username: String,
+    pub user_id: Option<i32>,
+    pub ip_address: String,
+    pub succeeded: bool,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone)]
+pub struct NewLoginAttempt {
+    pub kind: LoginAttemptKind,
+    /// What was typed in, even if there's no such user.
+    pub username: String,
+    pub user_id: Option<i32>,
+    pub ip_address: String,
+    pub succeeded: bool,
+}
+
+/// A moderator keeping someone out for a while. It ends on its own at `ends_at`,
+/// or early if a moderator lifts it.
+#[derive(Clone, Queryable, Serialize)]
+pub struct Suspension {
+    pub id: i32,
+    pub user_id: i32,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub reason: String,
+    pub created_by: i32,
+    pub lifted_at: Option<NaiveDateTime>,
+    pub lifted_by: Option<i32>,
+}
+
+#[derive(Clone, Insertable)]
+#[table_name="suspensions"]
+pub struct NewSuspension {
+    pub user_id: i32,
+    pub ends_at: NaiveDateTime,
+    pub reason: String,
+    pub created_by: i32,
+}
+
+/// Recent failures, oldest first, for feeding to `throttle::check`.
+#[derive(Clone, Default)]
+pub struct LoginFailures {
+    /// Failures for this username since it last logged in successfully.
+    pub account: Vec<NaiveDateTime>,
+    /// Every failure from this IP address, whatever account it was for.
+    pub ip_address: Vec<NaiveDateTime>,
+}
+
+#[derive(Clone, Queryable, Serialize)]
+pub struct ExternalIdentity {
+    pub id: i32,
+    pub user_id: i32,
+    pub issuer: String,
+    pub subject: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Queryable, Serialize)]
+pub struct SavedSearch {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    /// The query string that produced this search, so it can be run again from the search page.
+    pub search_params: String,
+    pub keywords: String,
+    pub title: String,
+    pub and_tags: Vec<i32>,
+    pub or_tags: Vec<i32>,
+    pub hide_tags: Vec<i32>,
+    pub or_domains: Vec<i32>,
+    pub for_user_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[table_name="saved_searches"]
+pub struct NewSavedSearch {
+    pub user_id: i32,
+    pub name: String,
+    pub search_params: String,
+    pub keywords: String,
+    pub title: String,
+    pub and_tags: Vec<i32>,
+    pub or_tags: Vec<i32>,
+    pub hide_tags: Vec<i32>,
+    pub or_domains: Vec<i32>,
+    pub for_user_id: i32,
+}
+
+#[derive(Clone, Queryable)]
+pub struct PendingOidcLogin {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub invite_token: Option<i64>,
+    /// If set, this is someone who's already logged in, adding another way to log in.
+    pub link_user_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Queryable)]
+pub struct UserTotp {
+    pub user_id: i32,
+    pub secret: Vec<u8>,
+    pub enabled: bool,
+    pub last_used_step: Option<i64>,
+    pub created_at: NaiveDateTime,
 }
 
 #[derive(Clone, Queryable, Serialize)]
@@ -192,6 +455,11 @@ pub struct User {
     pub dark_mode: bool,
     pub big_mode: bool,
     pub identicon: i32,
+    /// Only ever set to an address that has been verified.
+    #[serde(skip)]
+    pub email: Option<String>,
+    /// Whether to get a notification when someone @mentions this user.
+    pub notify_on_mention: bool,
 }
 
 #[derive(Queryable, Serialize)]
@@ -230,10 +498,19 @@ impl Default for User {
             dark_mode: false,
             big_mode: false,
             identicon: 0,
+            email: None,
+            notify_on_mention: true,
         }
     }
 }
 
+impl User {
+    /// Accounts made through single sign-on start out without one.
+    pub fn has_password(&self) -> bool {
+        !self.password_hash.is_empty()
+    }
+}
+
 impl Default for UserSession {
     fn default() -> Self {
         UserSession {
@@ -242,6 +519,7 @@ impl Default for UserSession {
             user_agent: String::new(),
             created_at: NaiveDateTime::from_timestamp(0, 0),
             last_seen_at: NaiveDateTime::from_timestamp(0, 0),
+            name: None,
         }
     }
 }
@@ -274,6 +552,9 @@ pub struct PostInfo {
     pub visible: bool,
     pub private: bool,
     pub hotness: f64,
+    /// Stars given out site-wide since this post was submitted, for ranking.
+    #[serde(skip)]
+    pub stellar_age: i32,
     pub score: i32,
     pub comment_count: i32,
     pub blog_post: bool,
@@ -287,6 +568,8 @@ pub struct PostInfo {
     pub hidden_by_me: bool,
     pub comment_readpoint: Option<i32>,
     pub excerpt_html: Option<String>,
+    /// For keyword searches, the part of the excerpt that matched, highlighted.
+    pub headline_html: Option<String>,
     pub banner_title: Option<String>,
     pub banner_desc: Option<String>,
     pub noindex: bool,
@@ -300,6 +583,10 @@ pub struct NotificationInfo {
     pub post_title: String,
     pub comment_count: i32,
     pub from_username: String,
+    /// Set when this is about a new post that matched one of the user's saved searches.
+    pub saved_search_name: Option<String>,
+    /// Set when this is about a comment that replied to, or quoted, one of the user's comments.
+    pub comment_id: Option<i32>,
 }
 
 #[derive(Queryable, Serialize)]
@@ -315,12 +602,46 @@ pub struct Domain {
 pub struct BlockedRegex {
     pub id: i32,
     pub regex: String,
+    pub scope: String,
+    pub action: String,
+    pub message: String,
+    pub hits: i32,
+    pub last_hit_at: Option<NaiveDateTime>,
+}
+
+impl BlockedRegex {
+    /// `None` if it was saved with a scope or action this version doesn't know about.
+    pub fn to_rule(&self) -> Option<blocked_regex::Rule> {
+        Some(blocked_regex::Rule {
+            id: self.id,
+            regex: self.regex.clone(),
+            scope: blocked_regex::Scope::parse(&self.scope)?,
+            action: blocked_regex::Action::parse(&self.action)?,
+            message: self.message.clone(),
+        })
+    }
+    pub fn scope_label(&self) -> &str {
+        blocked_regex::Scope::parse(&self.scope).map(|scope| scope.label()).unwrap_or(&self.scope)
+    }
+    pub fn action_label(&self) -> &str {
+        blocked_regex::Action::parse(&self.action).map(|action| action.label()).unwrap_or(&self.action)
+    }
 }
 
 #[derive(Insertable)]
 #[table_name="blocked_regexes"]
 pub struct NewBlockedRegex {
     pub regex: String,
+    pub scope: String,
+    pub action: String,
+    pub message: String,
+}
+
+/// Something people submitted recently, for trying out a blocked regex on.
+#[derive(Serialize)]
+pub struct ContentSample {
+    pub href: String,
+    pub text: String,
 }
 
 #[derive(Insertable)]
@@ -345,6 +666,28 @@ pub struct DomainRestriction {
     pub restriction_level: i32,
 }
 
+impl DomainRestriction {
+    /// Posts from the domain go to the mod queue.
+    pub const QUEUE: i32 = 1;
+    /// Posts from the domain are turned away.
+    pub const BLOCK: i32 = 3;
+}
+
+#[derive(Serialize)]
+pub struct DomainStats {
+    pub id: i32,
+    pub hostname: String,
+    pub banned: bool,
+    /// Zero if the domain has no restriction.
+    pub restriction_level: i32,
+    pub post_count: i64,
+    /// Posts in the last `DOMAIN_STATS_RECENT_DAYS` days.
+    pub recent_post_count: i64,
+    /// Posts that are in the mod queue or were deleted.
+    pub hidden_post_count: i64,
+    pub last_post_at: Option<NaiveDateTime>,
+}
+
 #[derive(Queryable, Serialize)]
 pub struct DomainSynonymInfo {
     pub from_hostname: String,
@@ -362,6 +705,7 @@ pub struct Comment {
     pub created_by: i32,
     pub updated_at: NaiveDateTime,
     pub rejected: bool,
+    pub parent_id: Option<i32>,
 }
 
 #[derive(Clone)]
@@ -370,6 +714,8 @@ pub struct NewComment {
     pub text: String,
     pub created_by: i32,
     pub visible: bool,
+    /// The comment this is a reply to. It has to be on the same post.
+    pub parent_id: Option<i32>,
 }
 
 #[derive(Serialize)]
@@ -389,6 +735,59 @@ pub struct CommentInfo {
     pub flagged_by_me: bool,
     pub hidden_by_me: bool,
     pub starred_by: Vec<String>,
+    pub parent_id: Option<i32>,
+    /// Who wrote the parent comment, if it's shown on the same page.
+    pub parent_username: Option<String>,
+    /// Where this comment goes in the reply tree; see `comment_thread::Position`.
+    pub depth: usize,
+    pub opens_replies: bool,
+    pub reply_count: usize,
+    pub closes_replies: usize,
+}
+
+/// How to sort the top-level comments on a post. Replies are always oldest first.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommentOrder {
+    Oldest,
+    Newest,
+    MostStarred,
+}
+
+impl CommentOrder {
+    pub fn from_param(param: &str) -> Option<CommentOrder> {
+        match param {
+            "" | "oldest" => Some(CommentOrder::Oldest),
+            "newest" => Some(CommentOrder::Newest),
+            "stars" => Some(CommentOrder::MostStarred),
+            _ => None,
+        }
+    }
+    pub fn param(self) -> &'static str {
+        match self {
+            CommentOrder::Oldest => "oldest",
+            CommentOrder::Newest => "newest",
+            CommentOrder::MostStarred => "stars",
+        }
+    }
+}
+
+/// Which page of a post's comments to show.
+#[derive(Clone, Copy, Debug)]
+pub enum CommentPageTarget {
+    /// Counting from zero.
+    Page(usize),
+    /// Whichever page has this comment on it.
+    Comment(i32),
+    /// Whichever page has the first comment newer than this readpoint, or the last page if there isn't one.
+    After(i32),
+}
+
+pub struct CommentPage {
+    pub comments: Vec<CommentInfo>,
+    pub page: usize,
+    pub page_count: usize,
+    /// Where the reader's readpoint should move to, now that they've seen this page.
+    pub advanced_readpoint: Option<i32>,
 }
 
 #[derive(Serialize)]
@@ -498,6 +897,8 @@ struct CreatePostTagging {
 struct CreateModeration {
     pub payload: json::Value,
     pub created_by: i32,
+    pub action: &'static str,
+    pub target_user_id: Option<i32>,
 }
 
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -570,6 +971,9 @@ pub struct PostSearch {
     pub blog_post: Option<bool>,
     pub before_date: Option<NaiveDate>,
     pub after_date: Option<NaiveDate>,
+    pub locked: Option<bool>,
+    /// How to order the hottest posts. `None` uses the one from the site config.
+    pub ranking: Option<Arc<dyn Ranking>>,
     pub limit: i32,
 }
 
@@ -578,6 +982,8 @@ pub struct PostFlagInfo {
     pub uuid: Base32,
     pub title: String,
     pub created_by_username: String,
+    pub reason: String,
+    pub note: Option<String>,
 }
 
 #[derive(Queryable, Serialize)]
@@ -586,6 +992,50 @@ pub struct CommentFlagInfo {
     pub id: i32,
     pub title: String,
     pub created_by_username: String,
+    pub reason: String,
+    pub note: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommentSearchOrderBy {
+    Newest,
+    Relevance,
+}
+
+#[derive(Clone, Debug)]
+pub struct CommentSearch {
+    pub my_user_id: i32,
+    pub for_user_id: Option<i32>,
+    pub for_post_id: Option<i32>,
+    pub keywords: String,
+    /// Only comments on posts with at least one of these tags.
+    pub or_tags: Vec<i32>,
+    pub before_date: Option<NaiveDate>,
+    pub after_date: Option<NaiveDate>,
+    /// Cursor for paging through newest-first results.
+    pub after_id: Option<i32>,
+    /// Offset for paging through results ranked by relevance, which can't use the cursor.
+    pub search_page: i32,
+    pub order_by: CommentSearchOrderBy,
+    pub limit: i64,
+}
+
+impl CommentSearch {
+    pub fn with_my_user_id(my_user_id: i32) -> CommentSearch {
+        CommentSearch {
+            my_user_id,
+            for_user_id: None,
+            for_post_id: None,
+            keywords: String::new(),
+            or_tags: Vec::new(),
+            before_date: None,
+            after_date: None,
+            after_id: None,
+            search_page: 0,
+            order_by: CommentSearchOrderBy::Newest,
+            limit: 50,
+        }
+    }
 }
 
 impl PostSearch {
@@ -606,11 +1056,69 @@ impl PostSearch {
             blog_post: None,
             before_date: None,
             after_date: None,
+            locked: None,
+            ranking: None,
             limit: 50,
         }
     }
 }
 
+#[derive(Insertable)]
+#[table_name="user_totp"]
+struct CreateUserTotp {
+    user_id: i32,
+    secret: Vec<u8>,
+}
+
+#[derive(Insertable)]
+#[table_name="totp_recovery_codes"]
+struct CreateRecoveryCode {
+    user_id: i32,
+    code_hash: Vec<u8>,
+}
+
+#[derive(Insertable)]
+#[table_name="pending_logins"]
+struct CreatePendingLogin {
+    uuid: i64,
+    user_id: i32,
+}
+
+#[derive(Insertable)]
+#[table_name="login_attempts"]
+struct CreateLoginAttempt<'a> {
+    kind: &'a str,
+    username: &'a str,
+    user_id: Option<i32>,
+    ip_address: &'a str,
+    succeeded: bool,
+}
+
+#[derive(Insertable)]
+#[table_name="pending_oidc_logins"]
+struct CreatePendingOidcLogin<'a> {
+    state: &'a str,
+    nonce: &'a str,
+    code_verifier: &'a str,
+    invite_token: Option<i64>,
+    link_user_id: Option<i32>,
+}
+
+#[derive(Insertable)]
+#[table_name="email_verification_tokens"]
+struct CreateEmailVerificationToken<'a> {
+    token_hash: Vec<u8>,
+    user_id: i32,
+    email: &'a str,
+}
+
+#[derive(Insertable)]
+#[table_name="password_reset_tokens"]
+struct CreatePasswordResetToken {
+    token_hash: Vec<u8>,
+    user_id: i32,
+}
+
 #[database("more_interesting")]
 pub struct MoreInterestingConn(PgConnection);
 
@@ -648,6 +1156,8 @@ impl MoreInterestingConn {
                 p::uuid,
                 p::title,
                 u::username,
+                f::reason,
+                f::note,
             ))
             .filter(p::visible.eq(true))
             .order_by(f::created_at.desc())
@@ -675,6 +1185,8 @@ impl MoreInterestingConn {
                 c::id,
                 p::title,
                 u::username,
+                f::reason,
+                f::note,
             ))
             .filter(c::visible.eq(true))
             .order_by(f::created_at.desc())
@@ -764,6 +1276,30 @@ impl MoreInterestingConn {
         }
         Ok(())
     }
+    /// Move the readpoint forward over comments that showed up while the post was open.
+    ///
+    /// This follows the same rule as reading a page, so it never moves backwards,
+    /// and it stops at the first comment the reader hasn't seen.
+    pub async fn advance_readpoint(&self, post_id_value: i32, user_id_value: i32, seen_ids: Vec<i32>) -> Result<(), DieselError> {
+        self.run(move |conn| Self::advance_readpoint_(conn, post_id_value, user_id_value, &seen_ids)).await
+    }
+    fn advance_readpoint_(conn: &PgConnection, post_id_value: i32, user_id_value: i32, seen_ids: &[i32]) -> Result<(), DieselError> {
+        let readpoint = comment_readpoints::table
+            .filter(comment_readpoints::user_id.eq(user_id_value))
+            .filter(comment_readpoints::post_id.eq(post_id_value))
+            .select(comment_readpoints::comment_readpoint)
+            .get_result::<i32>(conn)
+            .optional()?;
+        let all_ids: Vec<i32> = comments::table
+            .filter(comments::visible.eq(true))
+            .filter(comments::post_id.eq(post_id_value))
+            .select(comments::id)
+            .get_results(conn)?;
+        if let Some(comment_id_value) = crate::comment_thread::advance_readpoint(readpoint, &all_ids, seen_ids) {
+            Self::set_readpoint_(conn, post_id_value, user_id_value, comment_id_value)?;
+        }
+        Ok(())
+    }
     pub async fn list_notifications(&self, user_id_value: i32) -> Result<Vec<NotificationInfo>, DieselError> {
         if user_id_value == 0 {
             return Ok(Vec::new());
@@ -780,70 +1316,383 @@ impl MoreInterestingConn {
         let all: Vec<NotificationInfo> = notifications
             .inner_join(users.on(self::users::dsl::id.eq(self::notifications::dsl::created_by)))
             .inner_join(posts)
+            .left_outer_join(saved_searches::table)
             .select((
                 self::posts::dsl::uuid,
                 self::posts::dsl::title,
                 self::posts::dsl::comment_count,
                 self::users::dsl::username,
+                saved_searches::name.nullable(),
+                self::notifications::dsl::comment_id,
+                self::notifications::dsl::created_by,
+                self::posts::dsl::submitted_by,
+                self::posts::dsl::anon,
             ))
             .filter(visible.eq(true))
             .filter(self::notifications::dsl::user_id.eq(user_id_value))
             .order_by(self::notifications::dsl::created_at.asc())
             .limit(50)
-            .get_results::<(Base32, String, i32, String)>(conn)?
+            .get_results::<(Base32, String, i32, String, Option<String>, Option<i32>, i32, i32, bool)>(conn)?
             .into_iter()
             .map(|t| tuple_to_notification_info(t))
             .collect();
         Ok(all)
     }
-    pub async fn is_subscribed(&self, post_id_value: i32, user_id_value: i32) -> Result<bool, DieselError> {
-        if user_id_value == 0 {
-            return Ok(false);
-        }
-        self.run(move |conn| Self::is_subscribed_(conn, post_id_value, user_id_value)).await
+    pub async fn create_saved_search(&self, new: NewSavedSearch) -> Result<bool, DieselError> {
+        self.run(move |conn| Self::create_saved_search_(conn, new)).await
     }
-    fn is_subscribed_(conn: &PgConnection, post_id_value: i32, user_id_value: i32) -> Result<bool, DieselError> {
-        use self::subscriptions::dsl::*;
-        use diesel::{select, dsl::exists};
-        if user_id_value == 0 {
+    /// Returns false, without saving anything, if the user already has too many.
+    fn create_saved_search_(conn: &PgConnection, new: NewSavedSearch) -> Result<bool, DieselError> {
+        use self::saved_searches::dsl::*;
+        let count: i64 = saved_searches
+            .filter(user_id.eq(new.user_id))
+            .count()
+            .get_result(conn)?;
+        if count >= MAX_SAVED_SEARCHES {
             return Ok(false);
         }
-        Ok(select(exists(subscriptions
-            .filter(post_id.eq(post_id_value))
-            .filter(user_id.eq(user_id_value))))
-            .get_result::<bool>(conn)?)
-    }
-    pub async fn create_subscription(&self, new: NewSubscription) -> Result<(), DieselError> {
-        self.run(move |conn| Self::create_subscription_(conn, new)).await
-    }
-    fn create_subscription_(conn: &PgConnection, new: NewSubscription) -> Result<(), DieselError> {
-        diesel::insert_into(subscriptions::table)
+        diesel::insert_into(saved_searches)
             .values(new)
             .execute(conn)?;
-        Ok(())
+        Ok(true)
     }
-    pub async fn drop_subscription(&self, new: NewSubscription) -> Result<(), DieselError> {
-        self.run(move |conn| Self::drop_subscription_(conn, new)).await
+    pub async fn list_saved_searches(&self, user_id_value: i32) -> Result<Vec<SavedSearch>, DieselError> {
+        self.run(move |conn| Self::list_saved_searches_(conn, user_id_value)).await
     }
-    fn drop_subscription_(conn: &PgConnection, new: NewSubscription) -> Result<(), DieselError> {
-        use self::subscriptions::dsl::*;
-        diesel::delete(subscriptions.filter(user_id.eq(new.user_id)).filter(post_id.eq(new.post_id)))
+    fn list_saved_searches_(conn: &PgConnection, user_id_value: i32) -> Result<Vec<SavedSearch>, DieselError> {
+        use self::saved_searches::dsl::*;
+        saved_searches
+            .filter(user_id.eq(user_id_value))
+            .order_by(created_at.asc())
+            .get_results::<SavedSearch>(conn)
+    }
+    pub async fn delete_saved_search(&self, user_id_value: i32, saved_search_id_value: i32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::delete_saved_search_(conn, user_id_value, saved_search_id_value)).await
+    }
+    fn delete_saved_search_(conn: &PgConnection, user_id_value: i32, saved_search_id_value: i32) -> Result<(), DieselError> {
+        use self::saved_searches::dsl::*;
+        let affected_rows = diesel::delete(saved_searches.find(saved_search_id_value).filter(user_id.eq(user_id_value)))
             .execute(conn)?;
+        if affected_rows == 0 {
+            return Err(DieselError::NotFound);
+        }
         Ok(())
     }
-    pub async fn list_subscribed_users(&self, post_id_value: i32) -> Result<Vec<i32>, DieselError> {
-        self.run(move |conn| Self::list_subscribed_users_(conn, post_id_value)).await
-    }
-    fn list_subscribed_users_(conn: &PgConnection, post_id_value: i32) -> Result<Vec<i32>, DieselError> {
-        use self::subscriptions::dsl::*;
-        Ok(subscriptions
-            .select(user_id)
-            .filter(post_id.eq(post_id_value))
-            .get_results::<i32>(conn)?)
+    /// Notify everyone with a saved search that matches a post that just became visible.
+    ///
+    /// Everything but the keywords gets checked in memory by `saved_search`; the saved searches
+    /// with keywords that are left get checked against the post's search index in one query.
+    fn notify_saved_searches_(conn: &PgConnection, post: &Post) -> Result<(), DieselError> {
+        #[derive(Insertable)]
+        #[table_name="notifications"]
+        struct CreateNotification {
+            user_id: i32,
+            post_id: i32,
+            created_by: i32,
+            saved_search_id: Option<i32>,
+        }
+        #[derive(QueryableByName)]
+        struct KeywordMatch {
+            #[sql_type="sql_types::Integer"]
+            id: i32,
+        }
+        let post_tags: Vec<i32> = post_tagging::table
+            .filter(post_tagging::post_id.eq(post.id))
+            .select(post_tagging::tag_id)
+            .get_results(conn)?;
+        let candidates: Vec<SavedSearch> = saved_searches::table
+            .inner_join(users::table)
+            .filter(saved_searches::user_id.ne(post.submitted_by))
+            .filter(users::banned.eq(false))
+            .select(saved_searches::all_columns)
+            .order_by(saved_searches::id.asc())
+            .get_results::<SavedSearch>(conn)?
+            .into_iter()
+            .filter(|saved_search| crate::saved_search::matches_except_keywords(saved_search, post, &post_tags))
+            .collect();
+        let (ids, keywords): (Vec<i32>, Vec<String>) = candidates.iter()
+            .filter(|saved_search| saved_search.keywords != "")
+            .map(|saved_search| (saved_search.id, saved_search.keywords.clone()))
+            .unzip();
+        let keyword_matches: HashSet<i32> = if ids.is_empty() {
+            HashSet::new()
+        } else {
+            diesel::sql_query("SELECT t.id AS id FROM unnest($1::int[], $2::text[]) AS t(id, keywords) INNER JOIN post_search_index i ON i.post_id = $3 WHERE i.search_index @@ websearch_to_tsquery(t.keywords)")
+                .bind::<sql_types::Array<sql_types::Integer>, _>(ids)
+                .bind::<sql_types::Array<sql_types::Text>, _>(keywords)
+                .bind::<sql_types::Integer, _>(post.id)
+                .get_results::<KeywordMatch>(conn)?
+                .into_iter()
+                .map(|KeywordMatch { id }| id)
+                .collect()
+        };
+        let mut notified: HashSet<i32> = notifications::table
+            .filter(notifications::post_id.eq(post.id))
+            .select(notifications::user_id)
+            .get_results::<i32>(conn)?
+            .into_iter()
+            .collect();
+        for saved_search in candidates {
+            if notified.contains(&saved_search.user_id) {
+                continue;
+            }
+            if saved_search.keywords != "" && !keyword_matches.contains(&saved_search.id) {
+                continue;
+            }
+            notified.insert(saved_search.user_id);
+            diesel::insert_into(notifications::table)
+                .values(CreateNotification {
+                    user_id: saved_search.user_id,
+                    post_id: post.id,
+                    created_by: post.submitted_by,
+                    saved_search_id: Some(saved_search.id),
+                })
+                .execute(conn)?;
+        }
+        Ok(())
     }
-    pub async fn search_posts(&self, search: &PostSearch) -> Result<Vec<PostInfo>, DieselError> {
-        let search = search.clone();
-        self.run(move |conn| Self::search_posts_(conn, &search)).await
+    /// Notify the people a comment that just became visible replies to, either because they
+    /// wrote its parent or because it links to one of their comments with `#id`.
+    ///
+    /// Unlike the notifications for new comments, these don't need a subscription to the post.
+    fn notify_replies_(conn: &PgConnection, comment: &Comment, comment_refs: &[i32]) -> Result<(), DieselError> {
+        #[derive(Insertable)]
+        #[table_name="notifications"]
+        struct CreateNotification {
+            user_id: i32,
+            post_id: i32,
+            created_by: i32,
+            comment_id: Option<i32>,
+        }
+        let author = Self::get_user_by_id_(conn, comment.created_by)?;
+        if author.banned || author.trust_level <= -3 {
+            return Ok(());
+        }
+        let replied_to: HashSet<i32> = comment.parent_id.iter().chain(comment_refs.iter()).cloned().collect();
+        if replied_to.is_empty() {
+            return Ok(());
+        }
+        let replied_to_users: HashSet<i32> = comments::table
+            .inner_join(users::table)
+            .filter(comments::id.eq_any(replied_to.into_iter().collect::<Vec<i32>>()))
+            .filter(comments::post_id.eq(comment.post_id))
+            .filter(comments::created_by.ne(comment.created_by))
+            .filter(users::banned.eq(false))
+            .select(comments::created_by)
+            .get_results::<i32>(conn)?
+            .into_iter()
+            .collect();
+        for user_id_value in replied_to_users {
+            diesel::insert_into(notifications::table)
+                .values(CreateNotification {
+                    user_id: user_id_value,
+                    post_id: comment.post_id,
+                    created_by: comment.created_by,
+                    comment_id: Some(comment.id),
+                })
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+    pub async fn list_users_notified_about_comment(&self, comment_id_value: i32) -> Result<Vec<i32>, DieselError> {
+        self.run(move |conn| Self::list_users_notified_about_comment_(conn, comment_id_value)).await
+    }
+    fn list_users_notified_about_comment_(conn: &PgConnection, comment_id_value: i32) -> Result<Vec<i32>, DieselError> {
+        use self::notifications::dsl::*;
+        notifications
+            .filter(comment_id.eq(comment_id_value))
+            .select(user_id)
+            .get_results(conn)
+    }
+    /// Notify the users @mentioned in a post or comment that just became visible.
+    ///
+    /// Mentions in private posts don't count, since those already have a list of who to notify.
+    /// Neither do mentions from banned or untrusted users, or from a post or comment that names
+    /// more than `MAX_MENTIONS` people. Subscribers get notified about new comments anyway, and
+    /// nobody gets a second notification for a post they haven't looked at yet.
+    fn notify_mentions_(conn: &PgConnection, post_id_value: i32, created_by_value: i32, usernames: &[String]) -> Result<(), DieselError> {
+        let usernames: HashSet<&str> = usernames.iter().map(|s| &s[..]).collect();
+        if usernames.is_empty() || usernames.len() > MAX_MENTIONS {
+            return Ok(());
+        }
+        let post = posts::table.find(post_id_value).get_result::<Post>(conn)?;
+        if post.private || !post.visible {
+            return Ok(());
+        }
+        let author = Self::get_user_by_id_(conn, created_by_value)?;
+        if author.banned || author.trust_level < 0 {
+            return Ok(());
+        }
+        let mentioned: Vec<User> = users::table
+            .filter(users::username.eq_any(usernames.into_iter().collect::<Vec<&str>>()))
+            .filter(users::id.ne(created_by_value))
+            .filter(users::banned.eq(false))
+            .filter(users::notify_on_mention.eq(true))
+            .get_results(conn)?;
+        for user in mentioned {
+            if Self::is_subscribed_(conn, post_id_value, user.id)? {
+                continue;
+            }
+            let already_notified: i64 = notifications::table
+                .filter(notifications::user_id.eq(user.id))
+                .filter(notifications::post_id.eq(post_id_value))
+                .count()
+                .get_result(conn)?;
+            if already_notified != 0 {
+                continue;
+            }
+            diesel::insert_into(notifications::table)
+                .values(NewNotification {
+                    user_id: user.id,
+                    post_id: post_id_value,
+                    created_by: created_by_value,
+                })
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+    /// List comments on posts the user is subscribed to that they haven't seen yet,
+    /// according to their readpoint, newest first.
+    pub async fn list_unread_subscribed_comments(&self, user_id_value: i32) -> Result<Vec<CommentSearchResult>, DieselError> {
+        self.run(move |conn| Self::list_unread_subscribed_comments_(conn, user_id_value)).await
+    }
+    fn list_unread_subscribed_comments_(conn: &PgConnection, user_id_value: i32) -> Result<Vec<CommentSearchResult>, DieselError> {
+        use self::comments::dsl::*;
+        use self::comment_stars::dsl::*;
+        use self::comment_flags::dsl::*;
+        use self::comment_readpoints::dsl::*;
+        use self::subscriptions::dsl::*;
+        use self::users::dsl::*;
+        use self::posts::dsl::*;
+        if user_id_value == 0 {
+            return Ok(Vec::new());
+        }
+        let all: Vec<CommentSearchResult> = comments
+            .inner_join(subscriptions.on(self::subscriptions::dsl::post_id.eq(self::comments::dsl::post_id).and(self::subscriptions::dsl::user_id.eq(user_id_value))))
+            .left_outer_join(comment_readpoints.on(self::comment_readpoints::dsl::post_id.eq(self::comments::dsl::post_id).and(self::comment_readpoints::dsl::user_id.eq(user_id_value))))
+            .left_outer_join(comment_stars.on(self::comment_stars::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_stars::dsl::user_id.eq(user_id_value))))
+            .left_outer_join(comment_flags.on(self::comment_flags::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_flags::dsl::user_id.eq(user_id_value))))
+            .inner_join(users.on(self::users::dsl::id.eq(self::comments::dsl::created_by)))
+            .inner_join(posts.on(self::posts::dsl::id.eq(self::comments::dsl::post_id)))
+            .select((
+                self::comments::dsl::id,
+                self::comments::dsl::html,
+                self::posts::dsl::id,
+                self::posts::dsl::uuid,
+                self::posts::dsl::title,
+                self::comments::dsl::created_at,
+                self::comments::dsl::created_by,
+                self::users::dsl::username,
+                self::comment_stars::dsl::comment_id.nullable(),
+                self::comment_flags::dsl::comment_id.nullable(),
+                self::users::dsl::identicon,
+                self::posts::dsl::locked,
+            ))
+            .filter(self::comments::dsl::visible.eq(true))
+            .filter(self::posts::dsl::visible.eq(true))
+            .filter(self::comments::dsl::created_by.ne(user_id_value))
+            .filter(comment_readpoint.nullable().is_null().or(self::comments::dsl::id.nullable().gt(comment_readpoint.nullable())))
+            .order_by(self::comments::dsl::id.desc())
+            .limit(50)
+            .get_results::<(i32, String, i32, Base32, String, NaiveDateTime, i32, String, Option<i32>, Option<i32>, i32, bool)>(conn)?
+            .into_iter()
+            .map(|t| tuple_to_comment_search_results(conn, t))
+            .collect();
+        Ok(all)
+    }
+    pub async fn get_private_feed_token(&self, user_id_value: i32) -> Result<PrivateFeedToken, DieselError> {
+        self.run(move |conn| Self::get_private_feed_token_(conn, user_id_value)).await
+    }
+    fn get_private_feed_token_(conn: &PgConnection, user_id_value: i32) -> Result<PrivateFeedToken, DieselError> {
+        use self::private_feed_tokens::dsl::*;
+        private_feed_tokens.filter(user_id.eq(user_id_value)).get_result(conn)
+    }
+    /// Replace the user's private feed token with a new one, so that any old URLs stop working.
+    pub async fn reset_private_feed_token(&self, user_id_value: i32) -> Result<PrivateFeedToken, DieselError> {
+        self.run(move |conn| Self::reset_private_feed_token_(conn, user_id_value)).await
+    }
+    fn reset_private_feed_token_(conn: &PgConnection, user_id_value: i32) -> Result<PrivateFeedToken, DieselError> {
+        #[derive(Insertable)]
+        #[table_name="private_feed_tokens"]
+        struct CreatePrivateFeedToken {
+            uuid: i64,
+            user_id: i32,
+        }
+        conn.transaction(|| {
+            Self::revoke_private_feed_token_(conn, user_id_value)?;
+            diesel::insert_into(private_feed_tokens::table)
+                .values(CreatePrivateFeedToken {
+                    uuid: ::rand::random(),
+                    user_id: user_id_value,
+                })
+                .get_result(conn)
+        })
+    }
+    pub async fn revoke_private_feed_token(&self, user_id_value: i32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::revoke_private_feed_token_(conn, user_id_value)).await
+    }
+    fn revoke_private_feed_token_(conn: &PgConnection, user_id_value: i32) -> Result<(), DieselError> {
+        use self::private_feed_tokens::dsl::*;
+        diesel::delete(private_feed_tokens.filter(user_id.eq(user_id_value)))
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn get_user_by_private_feed_token(&self, uuid_value: Base32) -> Result<User, DieselError> {
+        self.run(move |conn| Self::get_user_by_private_feed_token_(conn, uuid_value)).await
+    }
+    fn get_user_by_private_feed_token_(conn: &PgConnection, uuid_value: Base32) -> Result<User, DieselError> {
+        use self::private_feed_tokens::dsl::*;
+        let token: PrivateFeedToken = private_feed_tokens.find(uuid_value.into_i64()).get_result(conn)?;
+        Self::get_user_by_id_(conn, token.user_id)
+    }
+    pub async fn is_subscribed(&self, post_id_value: i32, user_id_value: i32) -> Result<bool, DieselError> {
+        if user_id_value == 0 {
+            return Ok(false);
+        }
+        self.run(move |conn| Self::is_subscribed_(conn, post_id_value, user_id_value)).await
+    }
+    fn is_subscribed_(conn: &PgConnection, post_id_value: i32, user_id_value: i32) -> Result<bool, DieselError> {
+        use self::subscriptions::dsl::*;
+        use diesel::{select, dsl::exists};
+        if user_id_value == 0 {
+            return Ok(false);
+        }
+        Ok(select(exists(subscriptions
+            .filter(post_id.eq(post_id_value))
+            .filter(user_id.eq(user_id_value))))
+            .get_result::<bool>(conn)?)
+    }
+    pub async fn create_subscription(&self, new: NewSubscription) -> Result<(), DieselError> {
+        self.run(move |conn| Self::create_subscription_(conn, new)).await
+    }
+    fn create_subscription_(conn: &PgConnection, new: NewSubscription) -> Result<(), DieselError> {
+        diesel::insert_into(subscriptions::table)
+            .values(new)
+            .execute(conn)?;
+        Ok(())
+    }
+    pub async fn drop_subscription(&self, new: NewSubscription) -> Result<(), DieselError> {
+        self.run(move |conn| Self::drop_subscription_(conn, new)).await
+    }
+    fn drop_subscription_(conn: &PgConnection, new: NewSubscription) -> Result<(), DieselError> {
+        use self::subscriptions::dsl::*;
+        diesel::delete(subscriptions.filter(user_id.eq(new.user_id)).filter(post_id.eq(new.post_id)))
+            .execute(conn)?;
+        Ok(())
+    }
+    pub async fn list_subscribed_users(&self, post_id_value: i32) -> Result<Vec<i32>, DieselError> {
+        self.run(move |conn| Self::list_subscribed_users_(conn, post_id_value)).await
+    }
+    fn list_subscribed_users_(conn: &PgConnection, post_id_value: i32) -> Result<Vec<i32>, DieselError> {
+        use self::subscriptions::dsl::*;
+        Ok(subscriptions
+            .select(user_id)
+            .filter(post_id.eq(post_id_value))
+            .get_results::<i32>(conn)?)
+    }
+    pub async fn search_posts(&self, search: &PostSearch) -> Result<Vec<PostInfo>, DieselError> {
+        let search = search.clone();
+        self.run(move |conn| Self::search_posts_(conn, &search)).await
     }
     fn search_posts_(conn: &PgConnection, search: &PostSearch) -> Result<Vec<PostInfo>, DieselError> {
         use self::posts::dsl::{self as p, *};
@@ -855,7 +1704,7 @@ impl MoreInterestingConn {
         use self::post_tagging::dsl::{self as pt, *};
         use self::comment_readpoints::dsl::{self as cr, *};
         use crate::schema::post_search_index::dsl::*;
-        use diesel_full_text_search::{plainto_tsquery, TsVectorExtensions, ts_rank_cd};
+        use diesel_full_text_search::{TsVectorExtensions, ts_rank_cd};
         let query = posts.filter(visible.eq(true));
         let mut query = match search.order_by {
             PostSearchOrderBy::Hottest if search.keywords == "" => query.order_by((initial_stellar_time.desc(), p::created_at.desc())).into_boxed(),
@@ -899,6 +1748,9 @@ impl MoreInterestingConn {
         if let Some(blog_post_value) = search.blog_post {
             query = query.filter(p::blog_post.eq(blog_post_value));
         }
+        if let Some(locked_value) = search.locked {
+            query = query.filter(p::locked.eq(locked_value));
+        }
         let mut before_date = search.before_date;
         let mut after_date = search.after_date;
         if before_date < after_date && before_date.is_some() && after_date.is_some() {
@@ -962,8 +1814,8 @@ impl MoreInterestingConn {
                         p::locked,
                         p::anon,
                     ))
-                    .filter(search_index.matches(plainto_tsquery(&search.keywords)))
-                    .order_by(ts_rank_cd(search_index, plainto_tsquery(&search.keywords)).desc())
+                    .filter(search_index.matches(websearch_to_tsquery(&search.keywords)))
+                    .order_by(ts_rank_cd(search_index, websearch_to_tsquery(&search.keywords)).desc())
                     .offset(search.search_page as i64 * limit)
                     .limit(limit)
                     .get_results::<(i32, Base32, String, Option<String>, Option<String>, bool, bool, i32, i32, i32, Option<i32>, bool, NaiveDateTime, i32, Option<String>, Option<String>, Option<i32>, Option<i32>, Option<i32>, String, Option<String>, Option<String>, bool, bool, bool)>(conn)?
@@ -997,8 +1849,8 @@ impl MoreInterestingConn {
                         p::locked,
                         p::anon,
                     ))
-                    .filter(search_index.matches(plainto_tsquery(&search.keywords)))
-                    .order_by(ts_rank_cd(search_index, plainto_tsquery(&search.keywords)).desc())
+                    .filter(search_index.matches(websearch_to_tsquery(&search.keywords)))
+                    .order_by(ts_rank_cd(search_index, websearch_to_tsquery(&search.keywords)).desc())
                     .offset(search.search_page as i64 * limit)
                     .limit(limit)
                     .get_results::<(i32, Base32, String, Option<String>, Option<String>, bool, bool, i32, i32, i32, bool, NaiveDateTime, i32, Option<String>, Option<String>, String, Option<String>, Option<String>, bool, bool, bool)>(conn)?
@@ -1009,7 +1861,7 @@ impl MoreInterestingConn {
         } else if search.my_user_id == 0 {
             if search.keywords != "" {
                 let ids = post_search_index
-                    .filter(search_index.matches(plainto_tsquery(&search.keywords)))
+                    .filter(search_index.matches(websearch_to_tsquery(&search.keywords)))
                     .select(crate::schema::post_search_index::dsl::post_id);
                 query = query.filter(p::id.eq_any(ids));
             } else if search.after_post_id != 0 {
@@ -1054,7 +1906,7 @@ impl MoreInterestingConn {
         } else {
             if search.keywords != "" {
                 let ids = post_search_index
-                    .filter(search_index.matches(plainto_tsquery(&search.keywords)))
+                    .filter(search_index.matches(websearch_to_tsquery(&search.keywords)))
                     .select(crate::schema::post_search_index::dsl::post_id);
                 query = query.filter(p::id.eq_any(ids));
             } else if search.after_post_id != 0 {
@@ -1105,7 +1957,32 @@ impl MoreInterestingConn {
                 .map(|t| tuple_to_post_info(&mut data, t, current_stellar_time))
                 .collect()
         };
+        if search.keywords != "" && !all.is_empty() {
+            let excerpts: Vec<(i32, &str)> = all.iter()
+                .filter_map(|post| Some((post.id, post.excerpt_html.as_deref()?)))
+                .collect();
+            let mut headlines = Self::get_search_headlines_(conn, &excerpts, &search.keywords)?;
+            for post in &mut all {
+                post.headline_html = headlines.remove(&post.id);
+            }
+        }
         if let (PostSearchOrderBy::Hottest, "") = (search.order_by, &search.keywords[..]) {
+            let ranking = search.ranking.clone().unwrap_or_else(ranking::current);
+            // The newest posts aren't always the hottest when age is counted in hours,
+            // so rank the top posts from the last few days too.
+            if let Some(days) = ranking.top_posts_window_days() {
+                let window_start = (Utc::now().naive_utc() - Duration::days(days)).date();
+                if search.before_date.map_or(true, |before_date| before_date >= window_start) {
+                    let top = Self::search_posts_(conn, &PostSearch {
+                        order_by: PostSearchOrderBy::Top,
+                        after_date: Some(search.after_date.map_or(window_start, |after_date| after_date.max(window_start))),
+                        .. search.clone()
+                    })?;
+                    let newest: HashSet<i32> = all.iter().map(|post| post.id).collect();
+                    all.extend(top.into_iter().filter(|post| !newest.contains(&post.id)));
+                }
+            }
+            Self::rank_posts_(conn, &mut all, &*ranking)?;
             all.sort_by_key(|info| OrderedFloat(-info.hotness));
             if let Ok(limit) = search.limit.try_into() {
                 all.truncate(if limit > 20usize { limit - 10 } else { limit });
@@ -1118,6 +1995,52 @@ impl MoreInterestingConn {
         }
         Ok(all)
     }
+    /// Recompute each post's hotness, now with its flags counted, which the post queries leave out.
+    /// The flags are only counted if the ranking looks at them.
+    fn rank_posts_(conn: &PgConnection, all: &mut [PostInfo], ranking: &dyn Ranking) -> Result<(), DieselError> {
+        use self::flags::dsl::*;
+        let mut flag_counts: HashMap<i32, i64> = HashMap::new();
+        if ranking.uses_flags() {
+            let ids: Vec<i32> = all.iter().map(|post| post.id).collect();
+            for flagged_post_id in flags.filter(post_id.eq_any(&ids)).select(post_id).get_results::<i32>(conn)? {
+                *flag_counts.entry(flagged_post_id).or_insert(0) += 1;
+            }
+        }
+        for post in all {
+            let flag_count = flag_counts.get(&post.id).cloned().unwrap_or(0) as i32;
+            post.hotness = ranking.hotness(&rank_input(post, flag_count));
+        }
+        Ok(())
+    }
+    /// Get highlighted snippets showing where each post's excerpt matched the keywords.
+    ///
+    /// Posts that only matched on their title are left out.
+    /// This works from the rendered excerpt, so it shows what the reader sees instead of the markup they typed.
+    fn get_search_headlines_(conn: &PgConnection, excerpts: &[(i32, &str)], keywords: &str) -> Result<HashMap<i32, String>, DieselError> {
+        #[derive(QueryableByName)]
+        struct Headline {
+            #[sql_type="sql_types::Integer"]
+            id: i32,
+            #[sql_type="sql_types::Text"]
+            headline: String,
+        }
+        let options = format!(
+            "StartSel={}, StopSel={}, MaxWords=35, MinWords=15, MaxFragments=2, FragmentDelimiter=\" … \"",
+            crate::prettify::HEADLINE_START,
+            crate::prettify::HEADLINE_STOP,
+        );
+        let ids: Vec<i32> = excerpts.iter().map(|&(id, _)| id).collect();
+        let texts: Vec<String> = excerpts.iter().map(|&(_, html)| crate::prettify::html_to_text(html)).collect();
+        let headlines = diesel::sql_query("SELECT t.id AS id, ts_headline(t.body, websearch_to_tsquery($1), $2) AS headline FROM unnest($3::int[], $4::text[]) AS t(id, body)")
+            .bind::<sql_types::Text, _>(keywords)
+            .bind::<sql_types::Text, _>(options)
+            .bind::<sql_types::Array<sql_types::Integer>, _>(ids)
+            .bind::<sql_types::Array<sql_types::Text>, _>(texts)
+            .get_results::<Headline>(conn)?;
+        Ok(headlines.into_iter()
+            .filter_map(|Headline { id, headline }| Some((id, crate::prettify::prettify_headline(&headline)?)))
+            .collect())
+    }
     pub async fn get_post_info_similar(&self, user_id_param: i32, post_info: Post) -> Result<Vec<PostInfo>, DieselError> {
         self.run(move |conn| Self::get_post_info_similar_(conn, user_id_param, post_info)).await
     }
@@ -1591,9 +2514,12 @@ impl MoreInterestingConn {
             if Self::get_userdomain_posts_count_today_(conn, new_post.submitted_by, domain.id) >= 2 && enforce_rate_limit {
                 return Err(CreatePostError::TooManyPostsDomainUser);
             }
+            if domain.banned {
+                return Err(CreatePostError::DomainBlocked);
+            }
             if let Ok(restriction) = Self::get_domain_restriction_by_id_(conn, domain.id) {
-                if restriction.restriction_level > 2 {
-                    return Err(CreatePostError::TooManyPostsDomain);
+                if restriction.restriction_level >= DomainRestriction::BLOCK {
+                    return Err(CreatePostError::DomainBlocked);
                 } else if restriction.restriction_level > 0 {
                     visible = false;
                 }
@@ -1627,6 +2553,15 @@ impl MoreInterestingConn {
                         .execute(conn)?;
                 }
             }
+            if post.visible {
+                if let Err(e) = Self::notify_saved_searches_(conn, post) {
+                    warn!("Failed to check saved searches for new post {}: {:?}", post.id, e);
+                }
+                let usernames: Vec<String> = title_html_and_stuff.usernames.iter().chain(excerpt_html_and_stuff.iter().flat_map(|e| e.usernames.iter())).cloned().collect();
+                if let Err(e) = Self::notify_mentions_(conn, post.id, post.submitted_by, &usernames) {
+                    warn!("Failed to notify mentions in new post {}: {:?}", post.id, e);
+                }
+            }
         }
         result.map_err(Into::into)
     }
@@ -1795,31 +2730,52 @@ impl MoreInterestingConn {
             .unwrap_or(0);
         affected_rows == 1
     }
-    fn maybe_invisible_post_(conn: &PgConnection, post_id_param: i32) {
+    fn maybe_invisible_post_(conn: &PgConnection, post_id_param: i32, flagger_id: i32, reason_param: FlagReason, thresholds: &FlagThresholds) {
         use self::flags::dsl::*;
+        use self::users::dsl::{users, trust_level, id as u_id};
         let flag_count: i64 = flags.filter(post_id.eq(post_id_param)).count().get_result(conn).expect("if flagging worked, then so should counting");
-        if flag_count == FLAG_INVISIBLE_THRESHOLD {
+        let trusted_count: i64 = flags
+            .inner_join(users.on(u_id.eq(user_id)))
+            .filter(post_id.eq(post_id_param))
+            .filter(reason.eq(reason_param.as_str()))
+            .filter(trust_level.ge(thresholds.trusted_level))
+            .count()
+            .get_result(conn)
+            .expect("if flagging worked, then so should counting");
+        let flagger_trust_level = Self::get_user_by_id_(conn, flagger_id).map(|user| user.trust_level).unwrap_or(0);
+        if flag_count == FLAG_INVISIBLE_THRESHOLD || thresholds.is_reached(reason_param, flagger_trust_level, trusted_count) {
             Self::invisible_post_(conn, post_id_param).expect("if flagging worked, then so should hiding the post");
         }
     }
-    fn maybe_invisible_comment_(conn: &PgConnection, comment_id_param: i32) {
+    fn maybe_invisible_comment_(conn: &PgConnection, comment_id_param: i32, flagger_id: i32, reason_param: FlagReason, thresholds: &FlagThresholds) {
         use self::comment_flags::dsl::*;
+        use self::users::dsl::{users, trust_level, id as u_id};
         let flag_count: i64 = comment_flags.filter(comment_id.eq(comment_id_param)).count().get_result(conn).expect("if flagging worked, then so should counting");
-        if flag_count == FLAG_INVISIBLE_THRESHOLD {
+        let trusted_count: i64 = comment_flags
+            .inner_join(users.on(u_id.eq(user_id)))
+            .filter(comment_id.eq(comment_id_param))
+            .filter(reason.eq(reason_param.as_str()))
+            .filter(trust_level.ge(thresholds.trusted_level))
+            .count()
+            .get_result(conn)
+            .expect("if flagging worked, then so should counting");
+        let flagger_trust_level = Self::get_user_by_id_(conn, flagger_id).map(|user| user.trust_level).unwrap_or(0);
+        if flag_count == FLAG_INVISIBLE_THRESHOLD || thresholds.is_reached(reason_param, flagger_trust_level, trusted_count) {
             Self::invisible_comment_(conn, comment_id_param).expect("if flagging worked, then so should hiding the post");
         }
     }
-    pub async fn add_flag(&self, new_flag: &NewFlag) -> bool {
+    pub async fn add_flag(&self, new_flag: &NewFlag, reason: FlagReason, note: Option<String>, thresholds: FlagThresholds) -> bool {
         let new_flag = new_flag.clone();
-        self.run(move |conn| Self::add_flag_(conn, &new_flag)).await
+        self.run(move |conn| Self::add_flag_(conn, &new_flag, reason, note, &thresholds)).await
     }
-    fn add_flag_(conn: &PgConnection, new_flag: &NewFlag) -> bool {
+    fn add_flag_(conn: &PgConnection, new_flag: &NewFlag, reason_param: FlagReason, note_param: Option<String>, thresholds: &FlagThresholds) -> bool {
+        use self::flags::dsl::{reason, note};
         let affected_rows = diesel::insert_into(flags::table)
-            .values(new_flag)
+            .values((new_flag, reason.eq(reason_param.as_str()), note.eq(note_param)))
             .execute(conn)
             .unwrap_or(0);
         if affected_rows == 1 {
-            Self::maybe_invisible_post_(conn, new_flag.post_id);
+            Self::maybe_invisible_post_(conn, new_flag.post_id, new_flag.user_id, reason_param, thresholds);
         }
         affected_rows == 1
     }
@@ -1838,17 +2794,18 @@ impl MoreInterestingConn {
             .unwrap_or(0);
         affected_rows == 1
     }
-    pub async fn add_flag_comment(&self, new_flag: &NewFlagComment) -> bool {
+    pub async fn add_flag_comment(&self, new_flag: &NewFlagComment, reason: FlagReason, note: Option<String>, thresholds: FlagThresholds) -> bool {
         let new_flag = new_flag.clone();
-        self.run(move |conn| Self::add_flag_comment_(conn, &new_flag)).await
+        self.run(move |conn| Self::add_flag_comment_(conn, &new_flag, reason, note, &thresholds)).await
     }
-    fn add_flag_comment_(conn: &PgConnection, new_flag: &NewFlagComment) -> bool {
+    fn add_flag_comment_(conn: &PgConnection, new_flag: &NewFlagComment, reason_param: FlagReason, note_param: Option<String>, thresholds: &FlagThresholds) -> bool {
+        use self::comment_flags::dsl::{reason, note};
         let affected_rows = diesel::insert_into(comment_flags::table)
-            .values(new_flag)
+            .values((new_flag, reason.eq(reason_param.as_str()), note.eq(note_param)))
             .execute(conn)
             .unwrap_or(0);
         if affected_rows == 1 {
-            Self::maybe_invisible_comment_(conn, new_flag.comment_id);
+            Self::maybe_invisible_comment_(conn, new_flag.comment_id, new_flag.user_id, reason_param, thresholds);
         }
         affected_rows == 1
     }
@@ -1914,7 +2871,8 @@ impl MoreInterestingConn {
             invited_by: Option<i32>,
             identicon: i32,
         }
-        let password_hash = password_hash(&new_user.password);
+        // Accounts made through single sign-on don't have a password, and there's nothing to hash.
+        let password_hash = if new_user.password == "" { Vec::new() } else { password_hash(&new_user.password) };
         let identicon = ::rand::random();
         diesel::insert_into(users::table)
             .values(CreateUser {
@@ -1930,10 +2888,24 @@ impl MoreInterestingConn {
     }
     fn authenticate_user_(conn: &PgConnection, new_user: &UserAuth) -> Option<User> {
         let mut user = Self::get_user_by_username_(conn, &new_user.username).ok()?;
-        if password_verify(&new_user.password, &mut user.password_hash[..]) == PasswordResult::Passed {
-            Some(user)
-        } else {
-            None
+        if !user.has_password() {
+            return None;
+        }
+        match password_verify(&new_user.password, &mut user.password_hash[..]) {
+            Ok(PasswordResult::Passed) => {
+                // This is the only time the plaintext is around, so it's the only chance to upgrade the hash.
+                if password_needs_rehash(&user.password_hash) {
+                    if let Err(e) = Self::change_user_password_(conn, user.id, &new_user.password) {
+                        warn!("Failed to rehash password for user {}: {:?}", user.id, e);
+                    }
+                }
+                Some(user)
+            }
+            Ok(PasswordResult::Failed) => None,
+            Err(e) => {
+                warn!("Failed to check password for user {}: {}", user.id, e);
+                None
+            }
         }
     }
     pub async fn change_user_password(&self, user_id_value: i32, password: &str) -> Result<(), DieselError> {
@@ -1965,6 +2937,62 @@ impl MoreInterestingConn {
             .execute(conn)
             .map(|k| { assert_eq!(k, 1); })
     }
+    /// Suspend someone, and log them out everywhere so they find out why the next time they log in.
+    pub async fn suspend_user(&self, new_suspension: NewSuspension) -> Result<Suspension, DieselError> {
+        self.run(move |conn| Self::suspend_user_(conn, new_suspension)).await
+    }
+    fn suspend_user_(conn: &PgConnection, new_suspension: NewSuspension) -> Result<Suspension, DieselError> {
+        conn.transaction(|| {
+            // A new suspension replaces whatever was there before, even if it ends sooner.
+            Self::lift_suspension_(conn, new_suspension.user_id, new_suspension.created_by)?;
+            let suspension = diesel::insert_into(suspensions::table)
+                .values(&new_suspension)
+                .get_result(conn)?;
+            Self::revoke_other_sessions_(conn, new_suspension.user_id, Base32::zero())?;
+            Ok(suspension)
+        })
+    }
+    /// The suspension keeping this user out right now, if there is one.
+    pub async fn get_active_suspension(&self, user_id_value: i32) -> Option<Suspension> {
+        self.run(move |conn| Self::get_active_suspension_(conn, user_id_value)).await
+    }
+    fn get_active_suspension_(conn: &PgConnection, user_id_value: i32) -> Option<Suspension> {
+        use self::suspensions::dsl::*;
+        let now = Utc::now().naive_utc();
+        suspensions
+            .filter(user_id.eq(user_id_value))
+            .filter(starts_at.le(now))
+            .filter(ends_at.gt(now))
+            .filter(lifted_at.is_null())
+            .order_by(ends_at.desc())
+            .first(conn)
+            .ok()
+    }
+    pub async fn get_active_suspensions(&self, user_ids: Vec<i32>) -> Result<Vec<Suspension>, DieselError> {
+        self.run(move |conn| Self::get_active_suspensions_(conn, &user_ids)).await
+    }
+    fn get_active_suspensions_(conn: &PgConnection, user_ids: &[i32]) -> Result<Vec<Suspension>, DieselError> {
+        use self::suspensions::dsl::*;
+        let now = Utc::now().naive_utc();
+        suspensions
+            .filter(user_id.eq_any(user_ids))
+            .filter(starts_at.le(now))
+            .filter(ends_at.gt(now))
+            .filter(lifted_at.is_null())
+            .get_results(conn)
+    }
+    /// End this user's suspension early. Returns whether there was one to lift.
+    pub async fn lift_suspension(&self, user_id_value: i32, lifted_by_value: i32) -> Result<bool, DieselError> {
+        self.run(move |conn| Self::lift_suspension_(conn, user_id_value, lifted_by_value)).await
+    }
+    fn lift_suspension_(conn: &PgConnection, user_id_value: i32, lifted_by_value: i32) -> Result<bool, DieselError> {
+        use self::suspensions::dsl::*;
+        let now = Utc::now().naive_utc();
+        diesel::update(suspensions.filter(user_id.eq(user_id_value)).filter(ends_at.gt(now)).filter(lifted_at.is_null()))
+            .set((lifted_at.eq(now), lifted_by.eq(lifted_by_value)))
+            .execute(conn)
+            .map(|k| k != 0)
+    }
     pub async fn create_invite_token(&self, invited_by: i32) -> Result<InviteToken, DieselError> {
         self.run(move |conn| Self::create_invite_token_(conn, invited_by)).await
     }
@@ -2008,7 +3036,7 @@ impl MoreInterestingConn {
         let username_like = Self::escape_like_query(&username_param);
         users
             .inner_join(user_sessions)
-            .select((u::id, banned, trust_level, username, password_hash, u::created_at, invited_by, dark_mode, big_mode, identicon))
+            .select((u::id, banned, trust_level, username, password_hash, u::created_at, invited_by, dark_mode, big_mode, identicon, email, notify_on_mention))
             .filter(username.like(format!("%{}%", username_like)))
             .order_by(last_seen_at.desc())
             .limit(200)
@@ -2059,10 +3087,11 @@ impl MoreInterestingConn {
                 self::comment_hides::dsl::comment_id.nullable(),
                 self::users::dsl::username,
                 self::users::dsl::identicon,
+                self::comments::dsl::parent_id,
             ))
             .filter(visible.eq(true))
             .filter(self::comments::dsl::id.eq(comment_id_value))
-            .get_result::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32)>(conn)
+            .get_result::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32, Option<i32>)>(conn)
             .map(|t| tuple_to_comment_info(conn, t))
     }
     pub async fn create_domain(&self, new_domain: NewDomain) -> Result<Domain, DieselError> {
@@ -2104,8 +3133,115 @@ impl MoreInterestingConn {
         use self::domain_restrictions::dsl::*;
         domain_restrictions.find(domain_id_value).get_result::<DomainRestriction>(conn)
     }
+    pub async fn set_domain_restriction(&self, domain_id_value: i32, restriction_level_value: i32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::set_domain_restriction_(conn, domain_id_value, restriction_level_value)).await
+    }
+    /// Setting it to zero removes the restriction.
+    fn set_domain_restriction_(conn: &PgConnection, domain_id_value: i32, restriction_level_value: i32) -> Result<(), DieselError> {
+        use self::domain_restrictions::dsl::*;
+        conn.transaction(|| {
+            diesel::delete(domain_restrictions.find(domain_id_value)).execute(conn)?;
+            if restriction_level_value != 0 {
+                diesel::insert_into(domain_restrictions)
+                    .values(DomainRestriction {
+                        domain_id: domain_id_value,
+                        restriction_level: restriction_level_value,
+                    })
+                    .execute(conn)?;
+            }
+            Ok(())
+        })
+    }
+    pub async fn change_domain_banned(&self, domain_id_value: i32, banned_value: bool) -> Result<(), DieselError> {
+        self.run(move |conn| Self::change_domain_banned_(conn, domain_id_value, banned_value)).await
+    }
+    fn change_domain_banned_(conn: &PgConnection, domain_id_value: i32, banned_value: bool) -> Result<(), DieselError> {
+        use self::domains::dsl::*;
+        diesel::update(domains.find(domain_id_value)).set(banned.eq(banned_value))
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn get_domain_stats(&self, hostname_param: String) -> Result<Vec<DomainStats>, DieselError> {
+        self.run(move |conn| Self::get_domain_stats_(conn, hostname_param)).await
+    }
+    /// With a search, the domains that match it. Without one, every banned or restricted domain,
+    /// plus the ones that got the most posts lately.
+    fn get_domain_stats_(conn: &PgConnection, hostname_param: String) -> Result<Vec<DomainStats>, DieselError> {
+        #[derive(QueryableByName)]
+        struct BusyDomain {
+            #[sql_type="sql_types::Integer"]
+            domain_id: i32,
+        }
+        #[derive(QueryableByName)]
+        struct PostCounts {
+            #[sql_type="sql_types::Integer"]
+            domain_id: i32,
+            #[sql_type="sql_types::BigInt"]
+            post_count: i64,
+            #[sql_type="sql_types::BigInt"]
+            recent_post_count: i64,
+            #[sql_type="sql_types::BigInt"]
+            hidden_post_count: i64,
+            #[sql_type="sql_types::Timestamp"]
+            last_post_at: NaiveDateTime,
+        }
+        let recent = Utc::now().naive_utc() - Duration::days(DOMAIN_STATS_RECENT_DAYS);
+        let restrictions: HashMap<i32, i32> = domain_restrictions::table
+            .select((domain_restrictions::domain_id, domain_restrictions::restriction_level))
+            .get_results::<(i32, i32)>(conn)?
+            .into_iter()
+            .collect();
+        let domain_list: Vec<Domain> = if hostname_param.is_empty() {
+            let mut ids: HashSet<i32> = diesel::sql_query("SELECT domain_id FROM posts WHERE created_at > $1 AND domain_id IS NOT NULL GROUP BY domain_id ORDER BY count(*) DESC, domain_id LIMIT $2")
+                .bind::<sql_types::Timestamp, _>(recent)
+                .bind::<sql_types::BigInt, _>(DOMAIN_STATS_LIMIT as i64)
+                .get_results::<BusyDomain>(conn)?
+                .into_iter()
+                .map(|BusyDomain { domain_id }| domain_id)
+                .collect();
+            ids.extend(restrictions.keys().cloned());
+            domains::table
+                .filter(domains::id.eq_any(ids.into_iter().collect::<Vec<i32>>()).or(domains::banned.eq(true)))
+                .get_results::<Domain>(conn)?
+        } else {
+            let hostname_like = Self::escape_like_query(&hostname_param);
+            domains::table
+                .filter(domains::hostname.like(format!("%{}%", hostname_like)))
+                .order_by(domains::hostname)
+                .limit(200)
+                .get_results::<Domain>(conn)?
+        };
+        let mut stats: Vec<DomainStats> = domain_list.into_iter().map(|domain| DomainStats {
+            restriction_level: restrictions.get(&domain.id).cloned().unwrap_or(0),
+            id: domain.id,
+            hostname: domain.hostname,
+            banned: domain.banned,
+            post_count: 0,
+            recent_post_count: 0,
+            hidden_post_count: 0,
+            last_post_at: None,
+        }).collect();
+        let index: HashMap<i32, usize> = stats.iter().enumerate().map(|(i, domain)| (domain.id, i)).collect();
+        let counts = diesel::sql_query("SELECT domain_id, count(*) AS post_count, count(*) FILTER (WHERE created_at > $2) AS recent_post_count, count(*) FILTER (WHERE NOT visible) AS hidden_post_count, max(created_at) AS last_post_at FROM posts WHERE domain_id = ANY($1) GROUP BY domain_id")
+            .bind::<sql_types::Array<sql_types::Integer>, _>(index.keys().cloned().collect::<Vec<i32>>())
+            .bind::<sql_types::Timestamp, _>(recent)
+            .get_results::<PostCounts>(conn)?;
+        for counts in counts {
+            let domain = &mut stats[index[&counts.domain_id]];
+            domain.post_count = counts.post_count;
+            domain.recent_post_count = counts.recent_post_count;
+            domain.hidden_post_count = counts.hidden_post_count;
+            domain.last_post_at = Some(counts.last_post_at);
+        }
+        stats.sort_by(|a, b| b.recent_post_count.cmp(&a.recent_post_count).then(b.post_count.cmp(&a.post_count)).then(a.hostname.cmp(&b.hostname)));
+        Ok(stats)
+    }
     pub async fn comment_on_post(&self, new_post: NewComment, body_format: BodyFormat) -> Result<Comment, CreateCommentError> {
-        self.run(move |conn| Self::comment_on_post_(conn, new_post, body_format)).await
+        let comment = self.run(move |conn| Self::comment_on_post_(conn, new_post, body_format)).await?;
+        if comment.visible {
+            crate::live::send(comment.post_id, comment.id);
+        }
+        Ok(comment)
     }
     fn comment_on_post_(conn: &PgConnection, new_post: NewComment, body_format: BodyFormat) -> Result<Comment, CreateCommentError> {
         #[derive(Insertable)]
@@ -2116,6 +3252,7 @@ impl MoreInterestingConn {
             post_id: i32,
             created_by: i32,
             visible: bool,
+            parent_id: Option<i32>,
         }
         // TODO: make this configurable
         if Self::get_user_comments_count_today_(conn, new_post.created_by) > 100_000 {
@@ -2139,20 +3276,37 @@ impl MoreInterestingConn {
                 }
             }
         }
+        // A reply to a comment on some other post is just a top-level comment.
+        let parent_id = match new_post.parent_id {
+            Some(parent_id) => Self::get_comment_by_id_(conn, parent_id).ok()
+                .filter(|parent| parent.post_id == new_post.post_id)
+                .map(|parent| parent.id),
+            None => None,
+        };
         let html_and_stuff = match body_format {
             BodyFormat::Plain => crate::prettify::prettify_body(&new_post.text, &mut PrettifyData::new(conn, new_post.post_id)),
             BodyFormat::BBCode => crate::prettify::prettify_body_bbcode(&new_post.text, &mut PrettifyData::new(conn, new_post.post_id)),
         };
         Self::update_comment_count_on_post_(conn, new_post.post_id, 1)?;
-        Ok(diesel::insert_into(comments::table)
+        let comment: Comment = diesel::insert_into(comments::table)
             .values(CreateComment{
                 text: &new_post.text,
                 html: &html_and_stuff.string,
                 post_id: new_post.post_id,
                 created_by: new_post.created_by,
                 visible: new_post.visible,
+                parent_id,
             })
-            .get_result(conn)?)
+            .get_result(conn)?;
+        if comment.visible {
+            if let Err(e) = Self::notify_replies_(conn, &comment, &html_and_stuff.comment_refs) {
+                warn!("Failed to notify replies to new comment {}: {:?}", comment.id, e);
+            }
+            if let Err(e) = Self::notify_mentions_(conn, comment.post_id, comment.created_by, &html_and_stuff.usernames) {
+                warn!("Failed to notify mentions in new comment {}: {:?}", comment.id, e);
+            }
+        }
+        Ok(comment)
     }
     pub async fn update_comment(&self, post_id_value: i32, comment_id_value: i32, text_value: String, body_format: BodyFormat) -> Result<(), DieselError> {
         self.run(move |conn| Self::update_comment_(conn, post_id_value, comment_id_value, text_value, body_format)).await
@@ -2198,11 +3352,97 @@ impl MoreInterestingConn {
                 self::comment_hides::dsl::comment_id.nullable(),
                 self::users::dsl::username,
                 self::users::dsl::identicon,
+                self::comments::dsl::parent_id,
             ))
             .filter(visible.eq(true))
             .filter(self::comments::dsl::post_id.eq(post_id_param))
             .order_by(self::comments::dsl::created_at)
-            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32)>(conn)?
+            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32, Option<i32>)>(conn)?
+            .into_iter()
+            .map(|t| tuple_to_comment_info(conn, t))
+            .collect();
+        Ok(all)
+    }
+    pub async fn get_comment_page(&self, post_id_param: i32, user_id_param: i32, readpoint: Option<i32>, order: CommentOrder, target: CommentPageTarget) -> Result<CommentPage, DieselError> {
+        self.run(move |conn| Self::get_comment_page_(conn, post_id_param, user_id_param, readpoint, order, target)).await
+    }
+    /// Like `get_comments_from_post`, but arranged into threads and split into pages.
+    ///
+    /// Only the page's own comments get loaded in full.
+    fn get_comment_page_(conn: &PgConnection, post_id_param: i32, user_id_param: i32, readpoint: Option<i32>, order: CommentOrder, target: CommentPageTarget) -> Result<CommentPage, DieselError> {
+        let outline: Vec<(i32, Option<i32>)> = comments::table
+            .filter(comments::visible.eq(true))
+            .filter(comments::post_id.eq(post_id_param))
+            .order_by(comments::created_at)
+            .select((comments::id, comments::parent_id))
+            .get_results(conn)?;
+        let star_counts = if order == CommentOrder::MostStarred {
+            let ids: Vec<i32> = outline.iter().map(|&(id, _)| id).collect();
+            Self::get_comment_star_counts_(conn, &ids)?
+        } else {
+            HashMap::new()
+        };
+        let positions = arrange_comments(&outline, order, &star_counts);
+        let pages = crate::comment_thread::paginate(&positions, COMMENTS_PER_PAGE);
+        let last_page = pages.len() - 1;
+        let page_with = |matches: &dyn Fn(i32) -> bool| {
+            let i = positions.iter().position(|p| matches(outline[p.index].0))?;
+            pages.iter().position(|page| page.contains(&i))
+        };
+        let page = match target {
+            CommentPageTarget::Page(page) => page.min(last_page),
+            CommentPageTarget::Comment(comment_id) => page_with(&|id| id == comment_id).unwrap_or(0),
+            CommentPageTarget::After(readpoint) => page_with(&|id| id > readpoint).unwrap_or(last_page),
+        };
+        let ids: Vec<i32> = positions[pages[page].clone()].iter().map(|p| outline[p.index].0).collect();
+        let comments = Self::get_comments_by_id_(conn, &ids, user_id_param)?;
+        let all_ids: Vec<i32> = outline.iter().map(|&(id, _)| id).collect();
+        Ok(CommentPage {
+            comments: thread_comments(comments, order, &star_counts),
+            page,
+            page_count: pages.len(),
+            advanced_readpoint: crate::comment_thread::advance_readpoint(readpoint, &all_ids, &ids),
+        })
+    }
+    fn get_comment_star_counts_(conn: &PgConnection, comment_ids: &[i32]) -> Result<HashMap<i32, i64>, DieselError> {
+        use self::comment_stars::dsl::*;
+        let mut star_counts: HashMap<i32, i64> = HashMap::new();
+        for starred_comment_id in comment_stars.filter(comment_id.eq_any(comment_ids)).select(comment_id).get_results::<i32>(conn)? {
+            *star_counts.entry(starred_comment_id).or_insert(0) += 1;
+        }
+        Ok(star_counts)
+    }
+    /// Get visible comments by ID, oldest first.
+    fn get_comments_by_id_(conn: &PgConnection, comment_ids: &[i32], user_id_param: i32) -> Result<Vec<CommentInfo>, DieselError> {
+        use self::comments::dsl::*;
+        use self::comment_stars::dsl::*;
+        use self::comment_flags::dsl::*;
+        use self::comment_hides::dsl::*;
+        use self::users::dsl::*;
+        let all: Vec<CommentInfo> = comments
+            .left_outer_join(comment_stars.on(self::comment_stars::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_stars::dsl::user_id.eq(user_id_param))))
+            .left_outer_join(comment_flags.on(self::comment_flags::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_flags::dsl::user_id.eq(user_id_param))))
+            .left_outer_join(comment_hides.on(self::comment_hides::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_hides::dsl::user_id.eq(user_id_param))))
+            .inner_join(users)
+            .select((
+                self::comments::dsl::id,
+                self::comments::dsl::text,
+                self::comments::dsl::html,
+                self::comments::dsl::visible,
+                self::comments::dsl::post_id,
+                self::comments::dsl::created_at,
+                self::comments::dsl::created_by,
+                self::comment_stars::dsl::comment_id.nullable(),
+                self::comment_flags::dsl::comment_id.nullable(),
+                self::comment_hides::dsl::comment_id.nullable(),
+                self::users::dsl::username,
+                self::users::dsl::identicon,
+                self::comments::dsl::parent_id,
+            ))
+            .filter(visible.eq(true))
+            .filter(self::comments::dsl::id.eq_any(comment_ids))
+            .order_by(self::comments::dsl::created_at)
+            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32, Option<i32>)>(conn)?
             .into_iter()
             .map(|t| tuple_to_comment_info(conn, t))
             .collect();
@@ -2232,27 +3472,33 @@ impl MoreInterestingConn {
                 self::comment_hides::dsl::comment_id.nullable(),
                 self::users::dsl::username,
                 self::users::dsl::identicon,
+                self::comments::dsl::parent_id,
             ))
             .filter(self::comments::dsl::post_id.eq(post_id_param))
             .order_by(self::comments::dsl::created_at)
-            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32)>(conn)?
+            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32, Option<i32>)>(conn)?
             .into_iter()
             .map(|t| tuple_to_comment_info(conn, t))
             .collect();
         Ok(all)
     }
-    pub async fn search_comments(&self, user_id_param: Option<i32>, after_id_param: Option<i32>, self_user_id: i32) -> Result<Vec<CommentSearchResult>, DieselError> {
-        self.run(move |conn| Self::search_comments_(conn, user_id_param, after_id_param, self_user_id)).await
+    pub async fn search_comments(&self, search: &CommentSearch) -> Result<Vec<CommentSearchResult>, DieselError> {
+        let search = search.clone();
+        self.run(move |conn| Self::search_comments_(conn, &search)).await
     }
-    fn search_comments_(conn: &PgConnection, user_id_param: Option<i32>, after_id_param: Option<i32>, self_user_id: i32) -> Result<Vec<CommentSearchResult>, DieselError> {
+    fn search_comments_(conn: &PgConnection, search: &CommentSearch) -> Result<Vec<CommentSearchResult>, DieselError> {
         use self::comments::dsl::*;
         use self::comment_stars::dsl::*;
         use self::comment_flags::dsl::*;
+        use self::comment_search_index::dsl::*;
         use self::users::dsl::*;
         use self::posts::dsl::*;
-        let query = comments
+        use diesel_full_text_search::{ts_rank_cd, TsVectorExtensions};
+        let self_user_id = search.my_user_id;
+        let mut query = comments
             .left_outer_join(comment_stars.on(self::comment_stars::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_stars::dsl::user_id.eq(self_user_id))))
             .left_outer_join(comment_flags.on(self::comment_flags::dsl::comment_id.eq(self::comments::dsl::id).and(self::comment_flags::dsl::user_id.eq(self_user_id))))
+            .inner_join(comment_search_index)
             .inner_join(users)
             .inner_join(posts)
             .select((
@@ -2272,31 +3518,50 @@ impl MoreInterestingConn {
             .filter(self::comments::dsl::visible.eq(true))
             .filter(self::posts::dsl::private.eq(false))
             .filter(self::posts::dsl::visible.eq(true))
-            .order_by(self::comments::dsl::id.desc())
-            .limit(50);
-        let query = match (user_id_param, after_id_param) {
-            (Some(user_id_param), Some(after_id_param)) => {
-                query
-                    .filter(self::comments::dsl::created_by.eq(user_id_param))
-                    .filter(self::comments::dsl::id.lt(after_id_param))
-                    .into_boxed()
-            }
-            (Some(user_id_param), None) => {
-                query
-                    .filter(self::comments::dsl::created_by.eq(user_id_param))
-                    .into_boxed()
-            }
-            (None, Some(after_id_param)) => {
-                query
-                    .filter(self::comments::dsl::id.lt(after_id_param))
-                    .into_boxed()
-            }
-            (None, None) => query.into_boxed()
-        };
-        let all: Vec<CommentSearchResult> = query
-            .get_results::<(i32, String, i32, Base32, String, NaiveDateTime, i32, String, Option<i32>, Option<i32>, i32, bool)>(conn)?
-            .into_iter()
-            .map(|t| tuple_to_comment_search_results(conn, t))
+            .limit(search.limit)
+            .into_boxed();
+        if let Some(user_id_param) = search.for_user_id {
+            query = query.filter(self::comments::dsl::created_by.eq(user_id_param));
+        }
+        if let Some(post_id_param) = search.for_post_id {
+            query = query.filter(self::comments::dsl::post_id.eq(post_id_param));
+        }
+        if !search.or_tags.is_empty() {
+            let tagged = post_tagging::table
+                .filter(post_tagging::tag_id.eq_any(&search.or_tags))
+                .select(post_tagging::post_id);
+            query = query.filter(self::comments::dsl::post_id.eq_any(tagged));
+        }
+        let mut before_date = search.before_date;
+        let mut after_date = search.after_date;
+        if before_date < after_date && before_date.is_some() && after_date.is_some() {
+            mem::swap(&mut after_date, &mut before_date);
+        }
+        if let Some(before_date) = before_date {
+            let midnight = NaiveTime::from_hms(23, 59, 59);
+            query = query.filter(self::comments::dsl::created_at.lt(before_date.and_time(midnight)));
+        }
+        if let Some(after_date) = after_date {
+            let midnight = NaiveTime::from_hms(0, 0, 0);
+            query = query.filter(self::comments::dsl::created_at.gt(after_date.and_time(midnight)));
+        }
+        if search.keywords != "" {
+            query = query.filter(search_index.matches(websearch_to_tsquery(&search.keywords)));
+        }
+        if search.keywords != "" && search.order_by == CommentSearchOrderBy::Relevance {
+            query = query
+                .order_by((ts_rank_cd(search_index, websearch_to_tsquery(&search.keywords)).desc(), self::comments::dsl::id.desc()))
+                .offset((search.search_page as i64) * search.limit);
+        } else {
+            query = query.order_by(self::comments::dsl::id.desc());
+            if let Some(after_id_param) = search.after_id {
+                query = query.filter(self::comments::dsl::id.lt(after_id_param));
+            }
+        }
+        let all: Vec<CommentSearchResult> = query
+            .get_results::<(i32, String, i32, Base32, String, NaiveDateTime, i32, String, Option<i32>, Option<i32>, i32, bool)>(conn)?
+            .into_iter()
+            .map(|t| tuple_to_comment_search_results(conn, t))
             .collect();
         Ok(all)
     }
@@ -2459,19 +3724,43 @@ impl MoreInterestingConn {
     }
     fn add_blocked_regex_(conn: &PgConnection, new_blocked_regex: NewBlockedRegex) -> Result<(), DieselError> {
         use self::blocked_regexes::dsl::*;
-        diesel::insert_into(blocked_regexes)
-            .values(new_blocked_regex)
-            .execute(conn)
-            .map(|_| ())
+        conn.transaction(|| {
+            diesel::insert_into(blocked_regexes)
+                .values(new_blocked_regex)
+                .execute(conn)?;
+            Self::bump_blocked_regex_generation_(conn)
+        })
     }
     pub async fn delete_blocked_regex(&self, id: i32) -> Result<(), DieselError> {
         self.run(move |conn| Self::delete_blocked_regex_(conn, id)).await
     }
     fn delete_blocked_regex_(conn: &PgConnection, id_: i32) -> Result<(), DieselError> {
         use self::blocked_regexes::dsl::*;
-        diesel::delete(blocked_regexes.filter(id.eq(id_)))
-            .execute(conn)?;
-        Ok(())
+        conn.transaction(|| {
+            diesel::delete(blocked_regexes.filter(id.eq(id_)))
+                .execute(conn)?;
+            Self::bump_blocked_regex_generation_(conn)
+        })
+    }
+    /// Call this in the same transaction as any change to the blocked regexes,
+    /// so every server process loads them again. See `blocked_regex::store`.
+    fn bump_blocked_regex_generation_(conn: &PgConnection) -> Result<(), DieselError> {
+        diesel::sql_query("UPDATE blocked_regex_generation SET generation = generation + 1")
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn get_blocked_regex_generation(&self) -> Result<i64, DieselError> {
+        self.run(move |conn| Self::get_blocked_regex_generation_(conn)).await
+    }
+    fn get_blocked_regex_generation_(conn: &PgConnection) -> Result<i64, DieselError> {
+        #[derive(QueryableByName)]
+        struct Generation {
+            #[sql_type="sql_types::BigInt"]
+            generation: i64,
+        }
+        diesel::sql_query("SELECT generation FROM blocked_regex_generation")
+            .get_result::<Generation>(conn)
+            .map(|Generation { generation }| generation)
     }
     pub async fn get_all_blocked_regexes(&self) -> Result<Vec<BlockedRegex>, DieselError> {
         self.run(move |conn| Self::get_all_blocked_regexes_(conn)).await
@@ -2479,8 +3768,58 @@ impl MoreInterestingConn {
     fn get_all_blocked_regexes_(conn: &PgConnection) -> Result<Vec<BlockedRegex>, DieselError> {
         use self::blocked_regexes::dsl::*;
         blocked_regexes
+            .order_by(id)
             .get_results::<BlockedRegex>(conn)
     }
+    pub async fn record_blocked_regex_hits(&self, ids: Vec<i32>) -> Result<(), DieselError> {
+        self.run(move |conn| Self::record_blocked_regex_hits_(conn, ids)).await
+    }
+    fn record_blocked_regex_hits_(conn: &PgConnection, ids: Vec<i32>) -> Result<(), DieselError> {
+        use self::blocked_regexes::dsl::*;
+        diesel::update(blocked_regexes.filter(id.eq_any(ids)))
+            .set((hits.eq(hits + 1), last_hit_at.eq(Utc::now().naive_utc())))
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn get_content_samples(&self, scope: blocked_regex::Scope, limit: i64) -> Result<Vec<ContentSample>, DieselError> {
+        self.run(move |conn| Self::get_content_samples_(conn, scope, limit)).await
+    }
+    /// The newest titles, excerpts, URLs, comments or usernames, leaving out private messages.
+    fn get_content_samples_(conn: &PgConnection, scope: blocked_regex::Scope, limit: i64) -> Result<Vec<ContentSample>, DieselError> {
+        use crate::blocked_regex::Scope;
+        let post_samples = |column: Vec<(Base32, Option<String>)>| -> Vec<ContentSample> {
+            column.into_iter()
+                .filter_map(|(uuid, text)| Some(ContentSample { href: uuid.to_string(), text: text? }))
+                .collect()
+        };
+        let recent_posts = posts::table
+            .filter(posts::private.eq(false))
+            .order_by(posts::created_at.desc())
+            .limit(limit);
+        Ok(match scope {
+            Scope::Title => post_samples(recent_posts.select((posts::uuid, posts::title.nullable())).get_results(conn)?),
+            Scope::Excerpt => post_samples(recent_posts.select((posts::uuid, posts::excerpt)).get_results(conn)?),
+            Scope::Url => post_samples(recent_posts.select((posts::uuid, posts::url)).get_results(conn)?),
+            Scope::Comment => comments::table
+                .inner_join(posts::table)
+                .filter(posts::private.eq(false))
+                .order_by(comments::created_at.desc())
+                .limit(limit)
+                .select((posts::uuid, comments::id, comments::text))
+                .get_results::<(Base32, i32, String)>(conn)?
+                .into_iter()
+                .map(|(uuid, comment_id, text)| ContentSample { href: format!("{}?comment={}#{}", uuid, comment_id, comment_id), text })
+                .collect(),
+            Scope::Username => users::table
+                .order_by(users::created_at.desc())
+                .limit(limit)
+                .select(users::username)
+                .get_results::<String>(conn)?
+                .into_iter()
+                .map(|username| ContentSample { href: format!("@{}", username), text: username })
+                .collect(),
+        })
+    }
     pub async fn get_all_tags(&self) -> Result<Vec<Tag>, DieselError> {
         self.run(move |conn| Self::get_all_tags_(conn)).await
     }
@@ -2542,34 +3881,22 @@ impl MoreInterestingConn {
             .execute(conn)
             .map(|_| ())
     }
-    pub async fn get_mod_log_recent(&self) -> Result<Vec<ModerationInfo>, DieselError> {
-        self.run(move |conn| Self::get_mod_log_recent_(conn)).await
+    pub async fn set_notify_on_mention(&self, user_id_value: i32, notify_on_mention_value: bool) -> Result<(), DieselError> {
+        self.run(move |conn| Self::set_notify_on_mention_(conn, user_id_value, notify_on_mention_value)).await
     }
-    fn get_mod_log_recent_(conn: &PgConnection) -> Result<Vec<ModerationInfo>, DieselError> {
-        use self::moderation::dsl::*;
-        Ok(moderation
-            .inner_join(users::table)
-            .select((
-                id,
-                payload,
-                created_at,
-                created_by,
-                self::users::dsl::username,
-            ))
-            .order_by(id.desc())
-            .limit(50)
-            .get_results::<(i32, json::Value, NaiveDateTime, i32, String)>(conn)?
-            .into_iter()
-            .map(|t| tuple_to_moderation(t))
-            .collect()
-        )
+    fn set_notify_on_mention_(conn: &PgConnection, user_id_value: i32, notify_on_mention_value: bool) -> Result<(), DieselError> {
+        use self::users::dsl::*;
+        diesel::update(users.find(user_id_value))
+            .set(notify_on_mention.eq(notify_on_mention_value))
+            .execute(conn)
+            .map(|_| ())
     }
-    pub async fn get_mod_log_starting_with(&self, starting_with_id: i32) -> Result<Vec<ModerationInfo>, DieselError> {
-        self.run(move |conn| Self::get_mod_log_starting_with_(conn, starting_with_id)).await
+    pub async fn search_mod_log(&self, search: ModLogSearch) -> Result<Vec<ModerationInfo>, DieselError> {
+        self.run(move |conn| Self::search_mod_log_(conn, &search)).await
     }
-    fn get_mod_log_starting_with_(conn: &PgConnection, starting_with_id: i32) -> Result<Vec<ModerationInfo>, DieselError> {
+    fn search_mod_log_(conn: &PgConnection, search: &ModLogSearch) -> Result<Vec<ModerationInfo>, DieselError> {
         use self::moderation::dsl::*;
-        Ok(moderation
+        let mut query = moderation
             .inner_join(users::table)
             .select((
                 id,
@@ -2577,343 +3904,71 @@ impl MoreInterestingConn {
                 created_at,
                 created_by,
                 self::users::dsl::username,
+                action,
+                target_user_id,
             ))
-            .filter(id.lt(starting_with_id))
             .order_by(id.desc())
-            .limit(50)
-            .get_results::<(i32, json::Value, NaiveDateTime, i32, String)>(conn)?
+            .limit(search.limit)
+            .into_boxed();
+        if search.kind != "" {
+            query = query.filter(action.eq(search.kind.clone()));
+        }
+        if let Some(created_by_value) = search.created_by {
+            query = query.filter(created_by.eq(created_by_value));
+        }
+        if let Some(target_user_id_value) = search.target_user_id {
+            query = query.filter(target_user_id.eq(target_user_id_value));
+        }
+        if let Some(before_id) = search.before_id {
+            query = query.filter(id.lt(before_id));
+        }
+        let mut before_date = search.before_date;
+        let mut after_date = search.after_date;
+        if before_date < after_date && before_date.is_some() && after_date.is_some() {
+            mem::swap(&mut after_date, &mut before_date);
+        }
+        if let Some(before_date) = before_date {
+            let midnight = NaiveTime::from_hms(23, 59, 59);
+            query = query.filter(created_at.lt(before_date.and_time(midnight)));
+        }
+        if let Some(after_date) = after_date {
+            let midnight = NaiveTime::from_hms(0, 0, 0);
+            query = query.filter(created_at.gt(after_date.and_time(midnight)));
+        }
+        let mut log: Vec<ModerationInfo> = query
+            .get_results::<(i32, json::Value, NaiveDateTime, i32, String, String, Option<i32>)>(conn)?
             .into_iter()
             .map(|t| tuple_to_moderation(t))
-            .collect()
-        )
-    }
-    pub async fn mod_log_edit_comment(
-        &self,
-        user_id_value: i32,
-        comment_id_value: i32,
-        post_uuid_value: Base32,
-        old_text_value: String,
-        new_text_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_edit_comment_(conn, user_id_value, comment_id_value, post_uuid_value, old_text_value, new_text_value)).await
-    }
-    fn mod_log_edit_comment_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        comment_id_value: i32,
-        post_uuid_value: Base32,
-        old_text_value: String,
-        new_text_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "edit_comment",
-                    "comment_id": comment_id_value,
-                    "post_uuid": post_uuid_value,
-                    "old_text": old_text_value,
-                    "new_text": new_text_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_edit_post(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        old_title_value: String,
-        new_title_value: String,
-        old_url_value: String,
-        new_url_value: String,
-        old_excerpt_value: String,
-        new_excerpt_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_edit_post_(conn, user_id_value, post_uuid_value, old_title_value, new_title_value, old_url_value, new_url_value, old_excerpt_value, new_excerpt_value)).await
-    }
-    fn mod_log_edit_post_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        old_title_value: String,
-        new_title_value: String,
-        old_url_value: String,
-        new_url_value: String,
-        old_excerpt_value: String,
-        new_excerpt_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "edit_post",
-                    "post_uuid": post_uuid_value,
-                    "old_title": old_title_value,
-                    "new_title": new_title_value,
-                    "old_url": old_url_value,
-                    "new_url": new_url_value,
-                    "old_excerpt": old_excerpt_value,
-                    "new_excerpt": new_excerpt_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_delete_comment(
-        &self,
-        user_id_value: i32,
-        comment_id_value: i32,
-        post_uuid_value: Base32,
-        old_text_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_delete_comment_(conn, user_id_value, comment_id_value, post_uuid_value, old_text_value)).await
-    }
-    fn mod_log_delete_comment_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        comment_id_value: i32,
-        post_uuid_value: Base32,
-        old_text_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "delete_comment",
-                    "comment_id": comment_id_value,
-                    "post_uuid": post_uuid_value,
-                    "old_text": old_text_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_delete_post(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        old_title_value: String,
-        old_url_value: String,
-        old_excerpt_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_delete_post_(conn, user_id_value, post_uuid_value, old_title_value, old_url_value, old_excerpt_value)).await
-    }
-    fn mod_log_delete_post_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        old_title_value: String,
-        old_url_value: String,
-        old_excerpt_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "delete_post",
-                    "post_uuid": post_uuid_value,
-                    "old_title": old_title_value,
-                    "old_url": old_url_value,
-                    "old_excerpt": old_excerpt_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_approve_comment(
-        &self,
-        user_id_value: i32,
-        comment_id_value: i32,
-        post_uuid_value: Base32,
-        new_text_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_approve_comment_(conn, user_id_value, comment_id_value, post_uuid_value, new_text_value)).await
-    }
-    fn mod_log_approve_comment_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        comment_id_value: i32,
-        post_uuid_value: Base32,
-        new_text_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "approve_comment",
-                    "comment_id": comment_id_value,
-                    "post_uuid": post_uuid_value,
-                    "new_text": new_text_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_approve_post(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        old_title_value: String,
-        old_url_value: String,
-        old_excerpt_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_approve_post_(conn, user_id_value, post_uuid_value, old_title_value, old_url_value, old_excerpt_value)).await
-    }
-    fn mod_log_approve_post_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        new_title_value: String,
-        new_url_value: String,
-        new_excerpt_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "approve_post",
-                    "post_uuid": post_uuid_value,
-                    "new_title": new_title_value,
-                    "new_url": new_url_value,
-                    "new_excerpt": new_excerpt_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_poll_post(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        poll_title_value: String,
-        poll_value: i32,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_poll_post_(conn, user_id_value, post_uuid_value, poll_title_value, poll_value)).await
-    }
-    fn mod_log_poll_post_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        poll_title_value: String,
-        poll_value: i32,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "poll_post",
-                    "post_uuid": post_uuid_value,
-                    "poll_title": poll_title_value,
-                    "poll": poll_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_close_poll(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        poll_value: i32,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_close_poll_(conn, user_id_value, post_uuid_value, poll_value)).await
-    }
-    fn mod_log_close_poll_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        poll_value: i32,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "poll_close",
-                    "post_uuid": post_uuid_value,
-                    "poll": poll_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_banner_post(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        banner_title_value: String,
-        banner_desc_value: String,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_banner_post_(conn, user_id_value, post_uuid_value, banner_title_value, banner_desc_value)).await
-    }
-    fn mod_log_banner_post_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        banner_title_value: String,
-        banner_desc_value: String,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "banner_post",
-                    "post_uuid": post_uuid_value,
-                    "banner_title": banner_title_value,
-                    "banner_desc": banner_desc_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_lock(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        locked_value: bool,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_lock_(conn, user_id_value, post_uuid_value, locked_value)).await
-    }
-    fn mod_log_lock_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        locked_value: bool,
-    ) -> Result<(), DieselError> {
-        diesel::insert_into(moderation::table)
-            .values(CreateModeration{
-                payload: json!{{
-                    "type": "lock",
-                    "post_uuid": post_uuid_value,
-                    "locked": locked_value,
-                }},
-                created_by: user_id_value,
-            })
-            .execute(conn)
-            .map(|_| ())
-    }
-    pub async fn mod_log_noindex(
-        &self,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        noindex_value: bool,
-    ) -> Result<(), DieselError> {
-        self.run(move |conn| Self::mod_log_noindex_(conn, user_id_value, post_uuid_value, noindex_value)).await
-    }
-    fn mod_log_noindex_(
-        conn: &PgConnection,
-        user_id_value: i32,
-        post_uuid_value: Base32,
-        noindex_value: bool,
-    ) -> Result<(), DieselError> {
+            .collect();
+        let target_user_ids: Vec<i32> = log.iter().filter_map(|entry| entry.target_user_id).collect::<HashSet<i32>>().into_iter().collect();
+        let target_usernames: HashMap<i32, String> = users::table
+            .filter(users::id.eq_any(target_user_ids))
+            .select((users::id, users::username))
+            .get_results::<(i32, String)>(conn)?
+            .into_iter()
+            .collect();
+        for entry in &mut log {
+            entry.target_username = entry.target_user_id.and_then(|target| target_usernames.get(&target).cloned());
+        }
+        Ok(log)
+    }
+    pub async fn mod_log(&self, user_id_value: i32, action_value: ModAction) -> Result<(), DieselError> {
+        self.run(move |conn| Self::mod_log_(conn, user_id_value, action_value)).await
+    }
+    fn mod_log_(conn: &PgConnection, user_id_value: i32, action_value: ModAction) -> Result<(), DieselError> {
+        // Filtering by whose stuff got moderated needs this worked out ahead of time.
+        let target_user_id = match action_value.target() {
+            Target::Comment(comment_id_value) => Self::get_comment_by_id_(conn, comment_id_value).ok().map(|comment| comment.created_by),
+            Target::Post(post_uuid_value) => Self::get_post_by_uuid_(conn, post_uuid_value).ok().map(|post| post.submitted_by),
+            Target::User(user_id) => Some(user_id),
+            Target::Domain(_) => None,
+        };
         diesel::insert_into(moderation::table)
             .values(CreateModeration{
-                payload: json!{{
-                    "type": "noindex",
-                    "post_uuid": post_uuid_value,
-                    "noindex": noindex_value,
-                }},
+                payload: action_value.to_payload(),
                 created_by: user_id_value,
+                action: action_value.kind(),
+                target_user_id,
             })
             .execute(conn)
             .map(|_| ())
@@ -2971,6 +4026,7 @@ impl MoreInterestingConn {
             .into_iter()
             .map(|t| tuple_to_post_info(&mut data, t, Self::get_current_stellar_time_(conn)))
             .collect();
+        Self::rank_posts_(conn, &mut all, &*ranking::current())?;
         all.sort_by_key(|info| OrderedFloat(-info.hotness));
         all.truncate(10);
         Ok(all)
@@ -3002,30 +4058,50 @@ impl MoreInterestingConn {
                 self::comment_hides::dsl::comment_id.nullable(),
                 self::users::dsl::username,
                 self::users::dsl::identicon,
+                self::comments::dsl::parent_id,
             ))
             .filter(visible.eq(false))
             .filter(rejected.eq(false))
             .filter(self::users::dsl::trust_level.gt(-3))
             .order_by(self::comments::dsl::created_at.asc())
             .limit(50)
-            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32)>(conn)?
+            .get_results::<(i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32, Option<i32>)>(conn)?
             .into_iter()
             .map(|t| tuple_to_comment_info(conn, t))
             .collect();
         all.truncate(10);
         Ok(all)
     }
-    pub async fn approve_post(&self, post_id_value: i32) -> Result<(), DieselError> {
-        self.run(move |conn| Self::approve_post_(conn, post_id_value)).await
+    pub async fn approve_post(&self, post_id_value: i32, body_format: BodyFormat) -> Result<(), DieselError> {
+        self.run(move |conn| Self::approve_post_(conn, post_id_value, body_format)).await
     }
-    fn approve_post_(conn: &PgConnection, post_id_value: i32) -> Result<(), DieselError> {
+    fn approve_post_(conn: &PgConnection, post_id_value: i32, body_format: BodyFormat) -> Result<(), DieselError> {
         use self::posts::dsl::*;
-        diesel::update(posts.find(post_id_value))
+        let post = diesel::update(posts.find(post_id_value))
             .set((
                 visible.eq(true),
                 initial_stellar_time.eq(Self::get_current_stellar_time_(conn)),
             ))
-            .execute(conn)?;
+            .get_result::<Post>(conn)?;
+        // A moderator has dealt with these flags, so they shouldn't count toward hiding it again.
+        diesel::delete(flags::table.filter(flags::post_id.eq(post_id_value))).execute(conn)?;
+        if let Err(e) = Self::notify_saved_searches_(conn, &post) {
+            warn!("Failed to check saved searches for approved post {}: {:?}", post.id, e);
+        }
+        // The mentions weren't saved, so find them again.
+        let uuid_string = post.uuid.to_string();
+        let url_str = post.url.as_ref().map(|u| &u[..]).unwrap_or(&uuid_string);
+        let mut usernames = crate::prettify::prettify_title(&post.title, url_str, &mut PrettifyData::new(conn, 0), post.blog_post).usernames;
+        if let Some(excerpt_value) = &post.excerpt {
+            let body = match body_format {
+                BodyFormat::Plain => crate::prettify::prettify_body(excerpt_value, &mut PrettifyData::new(conn, 0)),
+                BodyFormat::BBCode => crate::prettify::prettify_body_bbcode(excerpt_value, &mut PrettifyData::new(conn, 0)),
+            };
+            usernames.extend(body.usernames);
+        }
+        if let Err(e) = Self::notify_mentions_(conn, post.id, post.submitted_by, &usernames) {
+            warn!("Failed to notify mentions in approved post {}: {:?}", post.id, e);
+        }
         Ok(())
     }
     pub async fn lock_post(&self, post_id_value: i32, locked: bool) -> Result<(), DieselError> {
@@ -3065,17 +4141,31 @@ impl MoreInterestingConn {
             .execute(conn)?;
         Ok(())
     }
-    pub async fn approve_comment(&self, comment_id_value: i32) -> Result<(), DieselError> {
-        self.run(move |conn| Self::approve_comment_(conn, comment_id_value)).await
+    pub async fn approve_comment(&self, comment_id_value: i32, body_format: BodyFormat) -> Result<(), DieselError> {
+        let comment = self.run(move |conn| Self::approve_comment_(conn, comment_id_value, body_format)).await?;
+        crate::live::send(comment.post_id, comment.id);
+        Ok(())
     }
-    fn approve_comment_(conn: &PgConnection, comment_id_value: i32) -> Result<(), DieselError> {
+    fn approve_comment_(conn: &PgConnection, comment_id_value: i32, body_format: BodyFormat) -> Result<Comment, DieselError> {
         use self::comments::dsl::*;
-        diesel::update(comments.find(comment_id_value))
+        let comment = diesel::update(comments.find(comment_id_value))
             .set((
                 visible.eq(true),
             ))
-            .execute(conn)?;
-        Ok(())
+            .get_result::<Comment>(conn)?;
+        diesel::delete(comment_flags::table.filter(comment_flags::comment_id.eq(comment_id_value))).execute(conn)?;
+        // The replies and mentions weren't saved, so find them again.
+        let html_and_stuff = match body_format {
+            BodyFormat::Plain => crate::prettify::prettify_body(&comment.text, &mut PrettifyData::new(conn, comment.post_id)),
+            BodyFormat::BBCode => crate::prettify::prettify_body_bbcode(&comment.text, &mut PrettifyData::new(conn, comment.post_id)),
+        };
+        if let Err(e) = Self::notify_replies_(conn, &comment, &html_and_stuff.comment_refs) {
+            warn!("Failed to notify replies to approved comment {}: {:?}", comment.id, e);
+        }
+        if let Err(e) = Self::notify_mentions_(conn, comment.post_id, comment.created_by, &html_and_stuff.usernames) {
+            warn!("Failed to notify mentions in approved comment {}: {:?}", comment.id, e);
+        }
+        Ok(comment)
     }
     pub async fn invisible_post(&self, post_id_value: i32) -> Result<(), DieselError> {
         self.run(move |conn| Self::invisible_post_(conn, post_id_value)).await
@@ -3244,11 +4334,596 @@ impl MoreInterestingConn {
         use self::user_sessions::dsl::*;
         user_sessions.filter(uuid.eq(base32.into_i64())).get_result(conn)
     }
+    pub async fn get_user_totp(&self, user_id_value: i32) -> Result<UserTotp, DieselError> {
+        self.run(move |conn| Self::get_user_totp_(conn, user_id_value)).await
+    }
+    fn get_user_totp_(conn: &PgConnection, user_id_value: i32) -> Result<UserTotp, DieselError> {
+        use self::user_totp::dsl::*;
+        user_totp.find(user_id_value).get_result(conn)
+    }
+    pub async fn has_totp_enabled(&self, user_id_value: i32) -> bool {
+        self.run(move |conn| Self::has_totp_enabled_(conn, user_id_value)).await
+    }
+    fn has_totp_enabled_(conn: &PgConnection, user_id_value: i32) -> bool {
+        Self::get_user_totp_(conn, user_id_value).map(|totp| totp.enabled).unwrap_or(false)
+    }
+    /// Generate a fresh secret for the user to put into their authenticator app.
+    ///
+    /// Two-factor auth isn't turned on until they prove that they did, with `confirm_totp_enrollment`.
+    pub async fn begin_totp_enrollment(&self, user_id_value: i32) -> Result<UserTotp, DieselError> {
+        self.run(move |conn| Self::begin_totp_enrollment_(conn, user_id_value)).await
+    }
+    fn begin_totp_enrollment_(conn: &PgConnection, user_id_value: i32) -> Result<UserTotp, DieselError> {
+        use self::user_totp::dsl::*;
+        conn.transaction(|| {
+            if Self::has_totp_enabled_(conn, user_id_value) {
+                return Err(DieselError::RollbackTransaction);
+            }
+            diesel::delete(user_totp.find(user_id_value)).execute(conn)?;
+            diesel::insert_into(user_totp)
+                .values(CreateUserTotp {
+                    user_id: user_id_value,
+                    secret: crate::totp::generate_secret(),
+                })
+                .get_result(conn)
+        })
+    }
+    /// Turn on two-factor auth, if the code matches the pending secret.
+    ///
+    /// On success, this returns a fresh set of recovery codes, which can only be shown once.
+    pub async fn confirm_totp_enrollment(&self, user_id_value: i32, code: String) -> Option<Vec<String>> {
+        self.run(move |conn| Self::confirm_totp_enrollment_(conn, user_id_value, &code)).await
+    }
+    fn confirm_totp_enrollment_(conn: &PgConnection, user_id_value: i32, code: &str) -> Option<Vec<String>> {
+        use self::user_totp::dsl::*;
+        let totp = Self::get_user_totp_(conn, user_id_value).ok()?;
+        if totp.enabled {
+            return None;
+        }
+        let now = Utc::now().timestamp() as u64;
+        let step = crate::totp::verify(&totp.secret, code, now, None)?;
+        diesel::update(user_totp.find(user_id_value))
+            .set((enabled.eq(true), last_used_step.eq(step as i64)))
+            .execute(conn)
+            .ok()?;
+        Self::regenerate_recovery_codes_(conn, user_id_value).ok()
+    }
+    pub async fn disable_totp(&self, user_id_value: i32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::disable_totp_(conn, user_id_value)).await
+    }
+    fn disable_totp_(conn: &PgConnection, user_id_value: i32) -> Result<(), DieselError> {
+        conn.transaction(|| {
+            diesel::delete(totp_recovery_codes::table.filter(totp_recovery_codes::user_id.eq(user_id_value))).execute(conn)?;
+            diesel::delete(user_totp::table.find(user_id_value)).execute(conn)?;
+            Ok(())
+        })
+    }
+    pub async fn regenerate_recovery_codes(&self, user_id_value: i32) -> Result<Vec<String>, DieselError> {
+        self.run(move |conn| Self::regenerate_recovery_codes_(conn, user_id_value)).await
+    }
+    fn regenerate_recovery_codes_(conn: &PgConnection, user_id_value: i32) -> Result<Vec<String>, DieselError> {
+        use self::totp_recovery_codes::dsl::*;
+        let codes: Vec<String> = (0..10).map(|_| crate::totp::generate_recovery_code()).collect();
+        conn.transaction(|| {
+            diesel::delete(totp_recovery_codes.filter(user_id.eq(user_id_value))).execute(conn)?;
+            let new_codes: Vec<CreateRecoveryCode> = codes.iter().map(|code| CreateRecoveryCode {
+                user_id: user_id_value,
+                code_hash: crate::totp::hash_recovery_code(code),
+            }).collect();
+            diesel::insert_into(totp_recovery_codes)
+                .values(&new_codes[..])
+                .execute(conn)?;
+            Ok(codes)
+        })
+    }
+    /// Check the second factor during login.
+    ///
+    /// This accepts either a code from the authenticator app, or one of the recovery codes.
+    /// Either way, the code is used up and won't work a second time.
+    pub async fn verify_totp(&self, user_id_value: i32, code: String) -> bool {
+        self.run(move |conn| Self::verify_totp_(conn, user_id_value, &code)).await
+    }
+    fn verify_totp_(conn: &PgConnection, user_id_value: i32, code: &str) -> bool {
+        let totp = match Self::get_user_totp_(conn, user_id_value) {
+            Ok(totp) if totp.enabled => totp,
+            _ => return false,
+        };
+        let now = Utc::now().timestamp() as u64;
+        if let Some(step) = crate::totp::verify(&totp.secret, code, now, totp.last_used_step.map(|step| step as u64)) {
+            return diesel::update(user_totp::table.find(user_id_value))
+                .set(user_totp::last_used_step.eq(step as i64))
+                .execute(conn)
+                .is_ok();
+        }
+        let code_hash = crate::totp::hash_recovery_code(code);
+        diesel::delete(totp_recovery_codes::table
+                .filter(totp_recovery_codes::user_id.eq(user_id_value))
+                .filter(totp_recovery_codes::code_hash.eq(&code_hash)))
+            .execute(conn)
+            .map(|k| k == 1)
+            .unwrap_or(false)
+    }
+    pub async fn count_recovery_codes(&self, user_id_value: i32) -> Result<i64, DieselError> {
+        self.run(move |conn| Self::count_recovery_codes_(conn, user_id_value)).await
+    }
+    fn count_recovery_codes_(conn: &PgConnection, user_id_value: i32) -> Result<i64, DieselError> {
+        use self::totp_recovery_codes::dsl::*;
+        totp_recovery_codes.filter(user_id.eq(user_id_value)).count().get_result(conn)
+    }
+    /// Remember that a user got their password right, but still needs to give a second factor.
+    pub async fn create_pending_login(&self, user_id_value: i32) -> Result<Base32, DieselError> {
+        self.run(move |conn| Self::create_pending_login_(conn, user_id_value)).await
+    }
+    fn create_pending_login_(conn: &PgConnection, user_id_value: i32) -> Result<Base32, DieselError> {
+        use self::pending_logins::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::minutes(PENDING_LOGIN_MINUTES);
+        diesel::delete(pending_logins.filter(created_at.lt(cutoff))).execute(conn)?;
+        let uuid_value: i64 = ::rand::random();
+        diesel::insert_into(pending_logins)
+            .values(CreatePendingLogin {
+                uuid: uuid_value,
+                user_id: user_id_value,
+            })
+            .execute(conn)?;
+        Ok(Base32::from(uuid_value))
+    }
+    pub async fn get_pending_login(&self, uuid_value: Base32) -> Result<User, DieselError> {
+        self.run(move |conn| Self::get_pending_login_(conn, uuid_value)).await
+    }
+    fn get_pending_login_(conn: &PgConnection, uuid_value: Base32) -> Result<User, DieselError> {
+        use self::pending_logins::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::minutes(PENDING_LOGIN_MINUTES);
+        let user_id_value = pending_logins
+            .find(uuid_value.into_i64())
+            .filter(created_at.gt(cutoff))
+            .select(user_id)
+            .get_result::<i32>(conn)?;
+        Self::get_user_by_id_(conn, user_id_value)
+    }
+    pub async fn delete_pending_login(&self, uuid_value: Base32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::delete_pending_login_(conn, uuid_value)).await
+    }
+    fn delete_pending_login_(conn: &PgConnection, uuid_value: Base32) -> Result<(), DieselError> {
+        use self::pending_logins::dsl::*;
+        diesel::delete(pending_logins.find(uuid_value.into_i64())).execute(conn).map(|_| ())
+    }
+    pub async fn list_sessions(&self, user_id_value: i32) -> Result<Vec<UserSession>, DieselError> {
+        self.run(move |conn| Self::list_sessions_(conn, user_id_value)).await
+    }
+    fn list_sessions_(conn: &PgConnection, user_id_value: i32) -> Result<Vec<UserSession>, DieselError> {
+        use self::user_sessions::dsl::*;
+        user_sessions
+            .filter(user_id.eq(user_id_value))
+            .order_by(last_seen_at.desc())
+            .get_results(conn)
+    }
+    pub async fn rename_session(&self, user_id_value: i32, uuid_value: Base32, name_value: Option<String>) -> Result<(), DieselError> {
+        self.run(move |conn| Self::rename_session_(conn, user_id_value, uuid_value, name_value)).await
+    }
+    fn rename_session_(conn: &PgConnection, user_id_value: i32, uuid_value: Base32, name_value: Option<String>) -> Result<(), DieselError> {
+        use self::user_sessions::dsl::*;
+        diesel::update(user_sessions.find(uuid_value.into_i64()).filter(user_id.eq(user_id_value)))
+            .set(name.eq(name_value))
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn revoke_session(&self, user_id_value: i32, uuid_value: Base32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::revoke_session_(conn, user_id_value, uuid_value)).await
+    }
+    fn revoke_session_(conn: &PgConnection, user_id_value: i32, uuid_value: Base32) -> Result<(), DieselError> {
+        use self::user_sessions::dsl::*;
+        diesel::delete(user_sessions.find(uuid_value.into_i64()).filter(user_id.eq(user_id_value)))
+            .execute(conn)
+            .map(|_| ())
+    }
+    /// Log the user out everywhere, except for the session they're using right now.
+    ///
+    /// Pass `Base32::zero()` to log them out everywhere.
+    pub async fn revoke_other_sessions(&self, user_id_value: i32, keep_uuid: Base32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::revoke_other_sessions_(conn, user_id_value, keep_uuid)).await
+    }
+    fn revoke_other_sessions_(conn: &PgConnection, user_id_value: i32, keep_uuid: Base32) -> Result<(), DieselError> {
+        use self::user_sessions::dsl::*;
+        diesel::delete(user_sessions.filter(user_id.eq(user_id_value)).filter(uuid.ne(keep_uuid.into_i64())))
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn record_login_attempt(&self, new: NewLoginAttempt) -> Result<(), DieselError> {
+        self.run(move |conn| Self::record_login_attempt_(conn, new)).await
+    }
+    fn record_login_attempt_(conn: &PgConnection, new: NewLoginAttempt) -> Result<(), DieselError> {
+        use self::login_attempts::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::days(LOGIN_ATTEMPT_RETENTION_DAYS);
+        diesel::delete(login_attempts.filter(created_at.lt(cutoff))).execute(conn)?;
+        diesel::insert_into(login_attempts)
+            .values(CreateLoginAttempt {
+                kind: new.kind.as_str(),
+                username: &new.username,
+                user_id: new.user_id,
+                ip_address: &new.ip_address,
+                succeeded: new.succeeded,
+            })
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn get_login_failures(&self, username_param: Option<String>, ip_address_param: String, since: NaiveDateTime) -> Result<LoginFailures, DieselError> {
+        self.run(move |conn| Self::get_login_failures_(conn, username_param, &ip_address_param, since)).await
+    }
+    fn get_login_failures_(conn: &PgConnection, username_param: Option<String>, ip_address_param: &str, since: NaiveDateTime) -> Result<LoginFailures, DieselError> {
+        use self::login_attempts::dsl::*;
+        let account = if let Some(username_param) = username_param {
+            // A successful login wipes the slate clean for the account,
+            // so the real owner doesn't stay locked out after they get in.
+            let last_success = login_attempts
+                .filter(username.eq(&username_param))
+                .filter(succeeded.eq(true))
+                .filter(created_at.gt(since))
+                .select(diesel::dsl::max(created_at))
+                .get_result::<Option<NaiveDateTime>>(conn)?;
+            login_attempts
+                .filter(username.eq(&username_param))
+                .filter(succeeded.eq(false))
+                .filter(kind.ne(LoginAttemptKind::PasswordReset.as_str()))
+                .filter(created_at.gt(last_success.unwrap_or(since)))
+                .select(created_at)
+                .order_by(created_at.asc())
+                .get_results(conn)?
+        } else {
+            Vec::new()
+        };
+        // It doesn't work that way for IP addresses, or an attacker could log into their own account
+        // every few tries to reset the count.
+        let ip_address_failures = login_attempts
+            .filter(ip_address.eq(ip_address_param))
+            .filter(succeeded.eq(false))
+            .filter(kind.ne(LoginAttemptKind::PasswordReset.as_str()))
+            .filter(created_at.gt(since))
+            .select(created_at)
+            .order_by(created_at.asc())
+            .get_results(conn)?;
+        Ok(LoginFailures {
+            account,
+            ip_address: ip_address_failures,
+        })
+    }
+    /// Password reset requests, counted the same way as login failures but separately,
+    /// so asking for reset links can't lock the real owner out of logging in.
+    pub async fn get_password_reset_requests(&self, username_param: String, ip_address_param: String, since: NaiveDateTime) -> Result<LoginFailures, DieselError> {
+        self.run(move |conn| Self::get_password_reset_requests_(conn, &username_param, &ip_address_param, since)).await
+    }
+    fn get_password_reset_requests_(conn: &PgConnection, username_param: &str, ip_address_param: &str, since: NaiveDateTime) -> Result<LoginFailures, DieselError> {
+        use self::login_attempts::dsl::*;
+        let account = login_attempts
+            .filter(username.eq(username_param))
+            .filter(kind.eq(LoginAttemptKind::PasswordReset.as_str()))
+            .filter(created_at.gt(since))
+            .select(created_at)
+            .order_by(created_at.asc())
+            .get_results(conn)?;
+        let ip_address_requests = login_attempts
+            .filter(ip_address.eq(ip_address_param))
+            .filter(kind.eq(LoginAttemptKind::PasswordReset.as_str()))
+            .filter(created_at.gt(since))
+            .select(created_at)
+            .order_by(created_at.asc())
+            .get_results(conn)?;
+        Ok(LoginFailures {
+            account,
+            ip_address: ip_address_requests,
+        })
+    }
+    pub async fn list_failed_login_attempts(&self, search: String) -> Result<Vec<LoginAttempt>, DieselError> {
+        self.run(move |conn| Self::list_failed_login_attempts_(conn, &search)).await
+    }
+    fn list_failed_login_attempts_(conn: &PgConnection, search: &str) -> Result<Vec<LoginAttempt>, DieselError> {
+        use self::login_attempts::dsl::*;
+        let mut query = login_attempts
+            .filter(succeeded.eq(false))
+            .filter(kind.ne(LoginAttemptKind::PasswordReset.as_str()))
+            .order_by(created_at.desc())
+            .limit(200)
+            .into_boxed();
+        if search != "" {
+            query = query.filter(username.eq(search).or(ip_address.eq(search)));
+        }
+        query.get_results(conn)
+    }
+    pub async fn create_pending_oidc_login(&self, state_value: String, nonce_value: String, code_verifier_value: String, invite_token_value: Option<Base32>, link_user_id_value: Option<i32>) -> Result<(), DieselError> {
+        self.run(move |conn| Self::create_pending_oidc_login_(conn, &state_value, &nonce_value, &code_verifier_value, invite_token_value, link_user_id_value)).await
+    }
+    fn create_pending_oidc_login_(conn: &PgConnection, state_value: &str, nonce_value: &str, code_verifier_value: &str, invite_token_value: Option<Base32>, link_user_id_value: Option<i32>) -> Result<(), DieselError> {
+        use self::pending_oidc_logins::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::minutes(PENDING_OIDC_LOGIN_MINUTES);
+        diesel::delete(pending_oidc_logins.filter(created_at.lt(cutoff))).execute(conn)?;
+        diesel::insert_into(pending_oidc_logins)
+            .values(CreatePendingOidcLogin {
+                state: state_value,
+                nonce: nonce_value,
+                code_verifier: code_verifier_value,
+                invite_token: invite_token_value.map(Base32::into_i64),
+                link_user_id: link_user_id_value,
+            })
+            .execute(conn)
+            .map(|_| ())
+    }
+    /// Look up a pending single sign-on login and delete it, so the callback can't be replayed.
+    pub async fn take_pending_oidc_login(&self, state_value: String) -> Result<PendingOidcLogin, DieselError> {
+        self.run(move |conn| Self::take_pending_oidc_login_(conn, &state_value)).await
+    }
+    fn take_pending_oidc_login_(conn: &PgConnection, state_value: &str) -> Result<PendingOidcLogin, DieselError> {
+        use self::pending_oidc_logins::dsl::*;
+        let pending: PendingOidcLogin = diesel::delete(pending_oidc_logins.find(state_value)).get_result(conn)?;
+        if pending.created_at < Utc::now().naive_utc() - Duration::minutes(PENDING_OIDC_LOGIN_MINUTES) {
+            return Err(DieselError::NotFound);
+        }
+        Ok(pending)
+    }
+    pub async fn get_user_by_external_identity(&self, issuer_value: String, subject_value: String) -> Result<User, DieselError> {
+        self.run(move |conn| Self::get_user_by_external_identity_(conn, &issuer_value, &subject_value)).await
+    }
+    fn get_user_by_external_identity_(conn: &PgConnection, issuer_value: &str, subject_value: &str) -> Result<User, DieselError> {
+        use self::external_identities::dsl::*;
+        let user_id_value = external_identities
+            .filter(issuer.eq(issuer_value))
+            .filter(subject.eq(subject_value))
+            .select(user_id)
+            .get_result::<i32>(conn)?;
+        Self::get_user_by_id_(conn, user_id_value)
+    }
+    pub async fn link_external_identity(&self, user_id_value: i32, issuer_value: String, subject_value: String) -> Result<(), DieselError> {
+        self.run(move |conn| Self::link_external_identity_(conn, user_id_value, &issuer_value, &subject_value)).await
+    }
+    fn link_external_identity_(conn: &PgConnection, user_id_value: i32, issuer_value: &str, subject_value: &str) -> Result<(), DieselError> {
+        #[derive(Insertable)]
+        #[table_name="external_identities"]
+        struct CreateExternalIdentity<'a> {
+            user_id: i32,
+            issuer: &'a str,
+            subject: &'a str,
+        }
+        diesel::insert_into(external_identities::table)
+            .values(CreateExternalIdentity {
+                user_id: user_id_value,
+                issuer: issuer_value,
+                subject: subject_value,
+            })
+            .execute(conn)
+            .map(|_| ())
+    }
+    pub async fn list_external_identities(&self, user_id_value: i32) -> Result<Vec<ExternalIdentity>, DieselError> {
+        self.run(move |conn| Self::list_external_identities_(conn, user_id_value)).await
+    }
+    fn list_external_identities_(conn: &PgConnection, user_id_value: i32) -> Result<Vec<ExternalIdentity>, DieselError> {
+        use self::external_identities::dsl::*;
+        external_identities
+            .filter(user_id.eq(user_id_value))
+            .order_by(created_at.asc())
+            .get_results(conn)
+    }
+    pub async fn unlink_external_identity(&self, user_id_value: i32, id_value: i32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::unlink_external_identity_(conn, user_id_value, id_value)).await
+    }
+    fn unlink_external_identity_(conn: &PgConnection, user_id_value: i32, id_value: i32) -> Result<(), DieselError> {
+        use self::external_identities::dsl::*;
+        diesel::delete(external_identities.find(id_value).filter(user_id.eq(user_id_value)))
+            .execute(conn)
+            .map(|_| ())
+    }
+    /// Find a username that isn't taken yet, by adding a number to the end of `base` if needed.
+    pub async fn find_available_username(&self, base: String) -> Result<String, DieselError> {
+        self.run(move |conn| Self::find_available_username_(conn, &base)).await
+    }
+    fn find_available_username_(conn: &PgConnection, base: &str) -> Result<String, DieselError> {
+        use self::users::dsl::*;
+        let taken: HashSet<String> = users
+            .filter(username.like(format!("{}%", Self::escape_like_query(base))))
+            .select(username)
+            .get_results::<String>(conn)?
+            .into_iter()
+            .collect();
+        let mut candidate = base.to_owned();
+        let mut suffix = 2;
+        while taken.contains(&candidate) {
+            candidate = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        Ok(candidate)
+    }
+    pub async fn get_user_by_email(&self, email_param: String) -> Result<User, DieselError> {
+        self.run(move |conn| Self::get_user_by_email_(conn, &email_param)).await
+    }
+    fn get_user_by_email_(conn: &PgConnection, email_param: &str) -> Result<User, DieselError> {
+        use self::users::dsl::*;
+        users.filter(email.eq(normalize_email(email_param))).get_result(conn)
+    }
+    pub async fn remove_email(&self, user_id_value: i32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::remove_email_(conn, user_id_value)).await
+    }
+    fn remove_email_(conn: &PgConnection, user_id_value: i32) -> Result<(), DieselError> {
+        use self::users::dsl::*;
+        diesel::update(users.find(user_id_value)).set(email.eq(None::<String>))
+            .execute(conn)
+            .map(|_| ())
+    }
+    /// Start changing a user's email address.
+    ///
+    /// The address isn't stored on the user until the token gets consumed,
+    /// so an unverified address can never be used for a password reset.
+    ///
+    /// Like every token that goes out by mail, only a hash of it is stored.
+    pub async fn create_email_verification_token(&self, user_id_value: i32, email_value: String) -> Result<String, DieselError> {
+        self.run(move |conn| Self::create_email_verification_token_(conn, user_id_value, &email_value)).await
+    }
+    fn create_email_verification_token_(conn: &PgConnection, user_id_value: i32, email_value: &str) -> Result<String, DieselError> {
+        use self::email_verification_tokens::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::hours(EMAIL_VERIFICATION_HOURS);
+        diesel::delete(email_verification_tokens.filter(created_at.lt(cutoff).or(user_id.eq(user_id_value)))).execute(conn)?;
+        let token = crate::password::generate_token();
+        diesel::insert_into(email_verification_tokens)
+            .values(CreateEmailVerificationToken {
+                token_hash: crate::password::token_hash(&token),
+                user_id: user_id_value,
+                email: &normalize_email(email_value),
+            })
+            .execute(conn)?;
+        Ok(token)
+    }
+    /// Use up an email verification token, and set the address it was for on the user.
+    pub async fn consume_email_verification_token(&self, token: String) -> Result<User, DieselError> {
+        self.run(move |conn| Self::consume_email_verification_token_(conn, &token)).await
+    }
+    fn consume_email_verification_token_(conn: &PgConnection, token: &str) -> Result<User, DieselError> {
+        use self::email_verification_tokens::dsl::*;
+        conn.transaction(|| {
+            let cutoff = Utc::now().naive_utc() - Duration::hours(EMAIL_VERIFICATION_HOURS);
+            let (user_id_value, email_value, created_at_value) = diesel::delete(email_verification_tokens.find(crate::password::token_hash(token)))
+                .returning((user_id, email, created_at))
+                .get_result::<(i32, String, NaiveDateTime)>(conn)?;
+            if created_at_value < cutoff {
+                return Err(DieselError::NotFound);
+            }
+            {
+                use self::users::dsl::*;
+                diesel::update(users.find(user_id_value)).set(email.eq(Some(email_value)))
+                    .get_result(conn)
+            }
+        })
+    }
+    /// Create a single-use token that lets someone set a new password without knowing the old one.
+    pub async fn create_password_reset_token(&self, user_id_value: i32) -> Result<String, DieselError> {
+        self.run(move |conn| Self::create_password_reset_token_(conn, user_id_value)).await
+    }
+    fn create_password_reset_token_(conn: &PgConnection, user_id_value: i32) -> Result<String, DieselError> {
+        use self::password_reset_tokens::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::minutes(PASSWORD_RESET_MINUTES);
+        diesel::delete(password_reset_tokens.filter(created_at.lt(cutoff).or(user_id.eq(user_id_value)))).execute(conn)?;
+        let token = crate::password::generate_token();
+        diesel::insert_into(password_reset_tokens)
+            .values(CreatePasswordResetToken {
+                token_hash: crate::password::token_hash(&token),
+                user_id: user_id_value,
+            })
+            .execute(conn)?;
+        Ok(token)
+    }
+    /// Check a password reset token without using it up, so the form can say it's expired up front.
+    pub async fn check_password_reset_token(&self, token: String) -> Result<User, DieselError> {
+        self.run(move |conn| Self::check_password_reset_token_(conn, &token)).await
+    }
+    fn check_password_reset_token_(conn: &PgConnection, token: &str) -> Result<User, DieselError> {
+        use self::password_reset_tokens::dsl::*;
+        let cutoff = Utc::now().naive_utc() - Duration::minutes(PASSWORD_RESET_MINUTES);
+        let user_id_value = password_reset_tokens
+            .find(crate::password::token_hash(token))
+            .filter(created_at.gt(cutoff))
+            .select(user_id)
+            .get_result::<i32>(conn)?;
+        Self::get_user_by_id_(conn, user_id_value)
+    }
+    /// Use up a password reset token and set the new password.
+    ///
+    /// This also logs the user out everywhere, since whoever had the old password shouldn't keep a session.
+    pub async fn consume_password_reset_token(&self, token: String, password: String) -> Result<User, DieselError> {
+        self.run(move |conn| Self::consume_password_reset_token_(conn, &token, &password)).await
+    }
+    fn consume_password_reset_token_(conn: &PgConnection, token: &str, password: &str) -> Result<User, DieselError> {
+        use self::password_reset_tokens::dsl::*;
+        conn.transaction(|| {
+            let cutoff = Utc::now().naive_utc() - Duration::minutes(PASSWORD_RESET_MINUTES);
+            let (user_id_value, created_at_value) = diesel::delete(password_reset_tokens.find(crate::password::token_hash(token)))
+                .returning((user_id, created_at))
+                .get_result::<(i32, NaiveDateTime)>(conn)?;
+            if created_at_value < cutoff {
+                return Err(DieselError::NotFound);
+            }
+            Self::change_user_password_(conn, user_id_value, password)?;
+            Self::revoke_other_sessions_(conn, user_id_value, Base32::zero())?;
+            Self::get_user_by_id_(conn, user_id_value)
+        })
+    }
+    /// Create a new API token, returning it along with the secret string to give to the user.
+    ///
+    /// The secret is only ever shown once; like passwords, the database only has a hash of it.
+    pub async fn create_api_token(&self, new: NewApiToken) -> Result<(ApiToken, String), DieselError> {
+        self.run(move |conn| Self::create_api_token_(conn, new)).await
+    }
+    fn create_api_token_(conn: &PgConnection, new: NewApiToken) -> Result<(ApiToken, String), DieselError> {
+        #[derive(Insertable)]
+        #[table_name="api_tokens"]
+        struct CreateApiToken<'a> {
+            uuid: i64,
+            user_id: i32,
+            name: &'a str,
+            token_hash: Vec<u8>,
+            scope_read: bool,
+            scope_vote: bool,
+            scope_post: bool,
+            scope_moderate: bool,
+        }
+        let secret = crate::password::generate_token();
+        let token = diesel::insert_into(api_tokens::table)
+            .values(CreateApiToken {
+                uuid: ::rand::random(),
+                user_id: new.user_id,
+                name: &new.name,
+                token_hash: crate::password::token_hash(&secret),
+                scope_read: new.scope_read,
+                scope_vote: new.scope_vote,
+                scope_post: new.scope_post,
+                scope_moderate: new.scope_moderate,
+            })
+            .get_result::<ApiToken>(conn)?;
+        let token_string = api_token::token_string(token.uuid, &secret);
+        Ok((token, token_string))
+    }
+    pub async fn list_api_tokens(&self, user_id_value: i32) -> Result<Vec<ApiToken>, DieselError> {
+        self.run(move |conn| Self::list_api_tokens_(conn, user_id_value)).await
+    }
+    fn list_api_tokens_(conn: &PgConnection, user_id_value: i32) -> Result<Vec<ApiToken>, DieselError> {
+        use self::api_tokens::dsl::*;
+        api_tokens
+            .filter(user_id.eq(user_id_value))
+            .order_by(created_at.desc())
+            .get_results(conn)
+    }
+    pub async fn revoke_api_token(&self, user_id_value: i32, uuid_value: Base32) -> Result<(), DieselError> {
+        self.run(move |conn| Self::revoke_api_token_(conn, user_id_value, uuid_value)).await
+    }
+    fn revoke_api_token_(conn: &PgConnection, user_id_value: i32, uuid_value: Base32) -> Result<(), DieselError> {
+        use self::api_tokens::dsl::*;
+        let count = diesel::delete(api_tokens.find(uuid_value.into_i64()).filter(user_id.eq(user_id_value)))
+            .execute(conn)?;
+        if count == 0 {
+            Err(DieselError::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+    /// Look up the token and its owner from the string sent in an `Authorization: Bearer` header.
+    pub async fn authenticate_api_token(&self, token_string: &str) -> Option<(ApiToken, User)> {
+        let token_string = token_string.to_owned();
+        self.run(move |conn| Self::authenticate_api_token_(conn, &token_string)).await
+    }
+    fn authenticate_api_token_(conn: &PgConnection, token_string: &str) -> Option<(ApiToken, User)> {
+        use self::api_tokens::dsl::*;
+        let (uuid_value, secret) = api_token::parse(token_string)?;
+        let token: ApiToken = api_tokens.find(uuid_value.into_i64()).get_result(conn).ok()?;
+        if !api_token::verify_secret(secret, &token.token_hash) {
+            return None;
+        }
+        let user = Self::get_user_by_id_(conn, token.user_id).ok()?;
+        let now = Utc::now().naive_utc();
+        let _ = diesel::update(api_tokens.find(uuid_value.into_i64()))
+            .set(last_used_at.eq(now))
+            .execute(conn);
+        Some((token, user))
+    }
 }
 
-fn tuple_to_notification_info((post_uuid, post_title, comment_count, from_username): (Base32, String, i32, String)) -> NotificationInfo {
+fn tuple_to_notification_info((post_uuid, post_title, comment_count, from_username, saved_search_name, comment_id, from_user_id, submitted_by, anon): (Base32, String, i32, String, Option<String>, Option<i32>, i32, i32, bool)) -> NotificationInfo {
+    let from_username = crate::notification::sender_name(from_username, from_user_id, submitted_by, anon);
     NotificationInfo {
-        post_uuid, post_title, comment_count, from_username,
+        post_uuid, post_title, comment_count, from_username, saved_search_name, comment_id,
     }
 }
 
@@ -3280,22 +4955,26 @@ fn tuple_to_post_info(data: &mut PrettifyData, (id, uuid, title, title_html, url
     };
     let created_at_relative = relative_date(&created_at);
     let submitted_by_username_urlencode = utf8_percent_encode(&submitted_by_username, NON_ALPHANUMERIC).to_string();
-    PostInfo {
+    let mut info = PostInfo {
         id, uuid, title, url, visible, private, score, blog_post,
         submitted_by, submitted_by_username, comment_count, title_html,
         comment_readpoint,
         excerpt_html, banner_title, banner_desc,
+        headline_html: None,
         created_at, created_at_relative,
         starred_by_me: starred_post_id.is_some(),
         flagged_by_me: flagged_post_id.is_some(),
         hidden_by_me: hidden_post_id.is_some(),
         submitted_by_username_urlencode,
         noindex, locked, anon,
-        hotness: compute_hotness(initial_stellar_time, current_stellar_time, score, blog_post)
-    }
+        stellar_age: current_stellar_time - initial_stellar_time,
+        hotness: 0.0,
+    };
+    info.hotness = ranking::current().hotness(&rank_input(&info, 0));
+    info
 }
 
-fn tuple_to_comment_info(conn: &PgConnection, (id, text, html, visible, post_id, created_at, created_by, starred_comment_id, flagged_comment_id, hidden_comment_id, created_by_username, created_by_identicon): (i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32)) -> CommentInfo {
+fn tuple_to_comment_info(conn: &PgConnection, (id, text, html, visible, post_id, created_at, created_by, starred_comment_id, flagged_comment_id, hidden_comment_id, created_by_username, created_by_identicon, parent_id): (i32, String, String, bool, i32, NaiveDateTime, i32, Option<i32>, Option<i32>, Option<i32>, String, i32, Option<i32>)) -> CommentInfo {
     let created_at_relative = relative_date(&created_at);
     let created_by_username_urlencode = utf8_percent_encode(&created_by_username, NON_ALPHANUMERIC).to_string();
     let created_by_identicon = Base32::from(created_by_identicon as i64);
@@ -3308,9 +4987,42 @@ fn tuple_to_comment_info(conn: &PgConnection, (id, text, html, visible, post_id,
         starred_by_me: starred_comment_id.is_some(),
         flagged_by_me: flagged_comment_id.is_some(),
         hidden_by_me: hidden_comment_id.is_some(),
+        parent_id,
+        parent_username: None,
+        depth: 0,
+        opens_replies: false,
+        reply_count: 0,
+        closes_replies: 0,
     }
 }
 
+fn arrange_comments(ids: &[(i32, Option<i32>)], order: CommentOrder, star_counts: &HashMap<i32, i64>) -> Vec<crate::comment_thread::Position> {
+    crate::comment_thread::arrange_by_key(ids, |index| match order {
+        CommentOrder::Oldest => (0, index as i64),
+        CommentOrder::Newest => (0, -(index as i64)),
+        CommentOrder::MostStarred => (-star_counts.get(&ids[index].0).cloned().unwrap_or(0), index as i64),
+    })
+}
+
+/// Put the comments in reply order, for showing a nested thread. They need to start out oldest first.
+fn thread_comments(comments: Vec<CommentInfo>, order: CommentOrder, star_counts: &HashMap<i32, i64>) -> Vec<CommentInfo> {
+    let ids: Vec<(i32, Option<i32>)> = comments.iter().map(|comment| (comment.id, comment.parent_id)).collect();
+    let usernames: HashMap<i32, String> = comments.iter().map(|comment| (comment.id, comment.created_by_username.clone())).collect();
+    let positions = arrange_comments(&ids, order, star_counts);
+    let mut comments: Vec<Option<CommentInfo>> = comments.into_iter().map(Some).collect();
+    positions.into_iter()
+        .map(|position| {
+            let mut comment = comments[position.index].take().expect("each comment is arranged once");
+            comment.parent_username = comment.parent_id.and_then(|parent_id| usernames.get(&parent_id).cloned());
+            comment.depth = position.depth;
+            comment.opens_replies = position.opens_replies;
+            comment.reply_count = position.reply_count;
+            comment.closes_replies = position.closes_replies;
+            comment
+        })
+        .collect()
+}
+
 fn tuple_to_comment_search_results(conn: &PgConnection, (id, html, post_id, post_uuid, post_title, created_at, created_by, created_by_username, starred_comment_id, flagged_comment_id, created_by_identicon, post_locked): (i32, String, i32, Base32, String, NaiveDateTime, i32, String, Option<i32>, Option<i32>, i32, bool)) -> CommentSearchResult {
     let created_at_relative = relative_date(&created_at);
     let created_by_identicon = Base32::from(created_by_identicon as i64);
@@ -3325,21 +5037,30 @@ fn tuple_to_comment_search_results(conn: &PgConnection, (id, html, post_id, post
     }
 }
 
-fn tuple_to_moderation((id, payload, created_at, created_by, created_by_username): (i32, json::Value, NaiveDateTime, i32, String)) -> ModerationInfo {
+fn tuple_to_moderation((id, payload, created_at, created_by, created_by_username, kind, target_user_id): (i32, json::Value, NaiveDateTime, i32, String, String, Option<i32>)) -> ModerationInfo {
     ModerationInfo {
         id,
+        action: ModAction::from_payload(&payload),
+        kind,
         payload,
         created_at,
         created_by,
         created_by_username,
+        target_user_id,
+        target_username: None,
     }
 }
 
-fn compute_hotness(initial_stellar_time: i32, current_stellar_time: i32, score: i32, blog_post: bool) -> f64 {
-    let gravity = 1.33;
-    let boost = if blog_post { 0.33 } else { 0.0 };
-    let stellar_age = max(current_stellar_time - initial_stellar_time, 0) as f64;
-    (boost + (score as f64) + 1.0) / (stellar_age + 1.0).powf(gravity)
+fn rank_input(post: &PostInfo, flag_count: i32) -> RankInput {
+    let age = Utc::now().naive_utc().signed_duration_since(post.created_at);
+    RankInput {
+        score: post.score,
+        comment_count: post.comment_count,
+        flag_count,
+        blog_post: post.blog_post,
+        stellar_age: post.stellar_age,
+        age_hours: age.num_seconds() as f64 / 3600.0,
+    }
 }
 
 pub fn relative_date(dt: &NaiveDateTime) -> String {
@@ -3358,6 +5079,11 @@ pub fn relative_date(dt: &NaiveDateTime) -> String {
     v_htmlescape::escape(&h.to_text_en(Accuracy::Rough, Tense::Present)).to_string()
 }
 
+/// Email addresses are compared case-insensitively, so they're stored lowercase.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
 pub struct PrettifyData<'a> {
     conn: &'a PgConnection,
     post_id: i32,
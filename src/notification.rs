@@ -0,0 +1,33 @@
+/*!
+What a notification says about who caused it.
+
+Mentions in a post notify people as soon as it's submitted, with the submitter as the sender.
+An anonymous post doesn't show who submitted it, so the notification can't either.
+*/
+
+/// The name to show as the notification's sender.
+pub fn sender_name(username: String, sender_id: i32, post_submitted_by: i32, post_anon: bool) -> String {
+    if post_anon && sender_id == post_submitted_by {
+        String::from("anonymous")
+    } else {
+        username
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn mention_in_anon_post() {
+        assert_eq!(sender_name(String::from("author"), 7, 7, true), "anonymous");
+    }
+    #[test]
+    fn mention_in_signed_post() {
+        assert_eq!(sender_name(String::from("author"), 7, 7, false), "author");
+    }
+    #[test]
+    fn someone_else_on_anon_post() {
+        // A comment on an anonymous post still shows who wrote the comment.
+        assert_eq!(sender_name(String::from("commenter"), 8, 7, true), "commenter");
+    }
+}
@@ -0,0 +1,328 @@
+/*!
+Log in through an OpenID Connect identity provider.
+
+This is the authorization code flow, with PKCE and a nonce. The ID token comes straight from
+the provider's token endpoint over a TLS connection we opened, which OpenID Connect Core §3.1.3.7
+allows in place of checking its signature, so there's no JWKS handling here. The claims still
+get checked for issuer, audience, expiry, and nonce.
+
+That only holds up over `https`, so the issuer and every endpoint it lists have to use it.
+`oidc_allow_http` turns that off for testing against a local mock provider; never set it on a real site.
+
+Any provider that publishes `/.well-known/openid-configuration` should work.
+*/
+
+use ring::digest;
+use ring::rand::{self, SecureRandom};
+use serde::Deserialize;
+use std::fmt::{self, Display, Formatter};
+use url::Url;
+
+#[derive(Debug)]
+pub enum OidcError {
+    Http(String),
+    Insecure(String),
+    BadResponse(String),
+    BadToken(&'static str),
+}
+
+impl Display for OidcError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            OidcError::Http(e) => write!(f, "failed to reach identity provider: {}", e),
+            OidcError::Insecure(url) => write!(f, "identity provider URL is not https: {}", url),
+            OidcError::BadResponse(e) => write!(f, "bad response from identity provider: {}", e),
+            OidcError::BadToken(e) => write!(f, "invalid ID token: {}", e),
+        }
+    }
+}
+
+impl From<reqwest::Error> for OidcError {
+    fn from(e: reqwest::Error) -> OidcError {
+        OidcError::Http(format!("{}", e))
+    }
+}
+
+/// The parts of the discovery document that the code flow needs.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    /// Whatever `discover` was called with, so the token request follows the same rules.
+    #[serde(skip)]
+    pub allow_http: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Claims {
+    pub iss: String,
+    pub sub: String,
+    pub aud: Audience,
+    pub exp: i64,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(default)]
+    pub preferred_username: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+}
+
+/// `aud` can be a single string or a list of them.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Audience {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Audience {
+    fn contains(&self, client_id: &str) -> bool {
+        match self {
+            Audience::One(aud) => aud == client_id,
+            Audience::Many(auds) => auds.iter().any(|aud| aud == client_id),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+/// The values needed to finish a login, kept server-side between the redirect and the callback.
+pub struct Challenge {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+}
+
+fn random_string() -> String {
+    let mut bytes = [0; 32];
+    rand::SystemRandom::new().fill(&mut bytes[..]).unwrap();
+    base64::encode_config(&bytes[..], base64::URL_SAFE_NO_PAD)
+}
+
+impl Challenge {
+    pub fn new() -> Challenge {
+        Challenge {
+            state: random_string(),
+            nonce: random_string(),
+            code_verifier: random_string(),
+        }
+    }
+}
+
+/// PKCE with the S256 method, from RFC 7636.
+fn code_challenge(code_verifier: &str) -> String {
+    let hash = digest::digest(&digest::SHA256, code_verifier.as_bytes());
+    base64::encode_config(hash.as_ref(), base64::URL_SAFE_NO_PAD)
+}
+
+/// Refuse to talk to the provider over anything but `https`, unless `allow_http` is set.
+pub fn check_url(url: &str, allow_http: bool) -> Result<(), OidcError> {
+    match Url::parse(url) {
+        Ok(parsed) if parsed.scheme() == "https" => Ok(()),
+        Ok(parsed) if parsed.scheme() == "http" && allow_http => Ok(()),
+        _ => Err(OidcError::Insecure(url.to_owned())),
+    }
+}
+
+/// This also won't follow a redirect from `https` to `http`.
+fn client(allow_http: bool) -> Result<reqwest::Client, OidcError> {
+    Ok(reqwest::Client::builder().https_only(!allow_http).build()?)
+}
+
+pub async fn discover(issuer: &str, allow_http: bool) -> Result<ProviderMetadata, OidcError> {
+    check_url(issuer, allow_http)?;
+    let url = format!("{}/.well-known/openid-configuration", issuer.trim_end_matches('/'));
+    let mut metadata: ProviderMetadata = client(allow_http)?.get(&url).send().await?.error_for_status()?.json().await?;
+    if metadata.issuer.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        return Err(OidcError::BadResponse(format!("discovery document is for {}", metadata.issuer)));
+    }
+    check_url(&metadata.authorization_endpoint, allow_http)?;
+    check_url(&metadata.token_endpoint, allow_http)?;
+    metadata.allow_http = allow_http;
+    Ok(metadata)
+}
+
+pub fn authorization_url(metadata: &ProviderMetadata, client_id: &str, redirect_uri: &str, scopes: &str, challenge: &Challenge) -> Result<Url, OidcError> {
+    let mut url = Url::parse(&metadata.authorization_endpoint)
+        .map_err(|e| OidcError::BadResponse(format!("{}", e)))?;
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", client_id)
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("scope", scopes)
+        .append_pair("state", &challenge.state)
+        .append_pair("nonce", &challenge.nonce)
+        .append_pair("code_challenge", &code_challenge(&challenge.code_verifier))
+        .append_pair("code_challenge_method", "S256");
+    Ok(url)
+}
+
+/// Trade the code from the callback for an ID token, and check it.
+pub async fn exchange_code(metadata: &ProviderMetadata, client_id: &str, client_secret: &str, redirect_uri: &str, code: &str, challenge: &Challenge, now: i64) -> Result<Claims, OidcError> {
+    let response: TokenResponse = client(metadata.allow_http)?
+        .post(&metadata.token_endpoint)
+        .basic_auth(client_id, Some(client_secret))
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("code_verifier", &challenge.code_verifier),
+        ])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let claims = decode_id_token(&response.id_token)?;
+    validate_claims(&claims, &metadata.issuer, client_id, &challenge.nonce, now)?;
+    Ok(claims)
+}
+
+/// Read the payload out of a JWT, without checking the signature. See the module docs for why that's okay.
+fn decode_id_token(id_token: &str) -> Result<Claims, OidcError> {
+    let payload = id_token.split('.').nth(1).ok_or(OidcError::BadToken("not a JWT"))?;
+    let payload = base64::decode_config(payload, base64::URL_SAFE_NO_PAD)
+        .map_err(|_| OidcError::BadToken("payload is not base64"))?;
+    serde_json::from_slice(&payload).map_err(|_| OidcError::BadToken("payload is not a claims object"))
+}
+
+fn validate_claims(claims: &Claims, issuer: &str, client_id: &str, nonce: &str, now: i64) -> Result<(), OidcError> {
+    if claims.iss.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        return Err(OidcError::BadToken("wrong issuer"));
+    }
+    if !claims.aud.contains(client_id) {
+        return Err(OidcError::BadToken("wrong audience"));
+    }
+    if claims.exp <= now {
+        return Err(OidcError::BadToken("expired"));
+    }
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(OidcError::BadToken("wrong nonce"));
+    }
+    if claims.sub == "" {
+        return Err(OidcError::BadToken("missing subject"));
+    }
+    Ok(())
+}
+
+/// Why a callback can't go on, before the provider even gets asked about the code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CallbackError {
+    /// The provider sent the user back with an error instead of a code.
+    Refused(String),
+    /// Something's missing, or the state doesn't match the browser's cookie.
+    Expired,
+}
+
+/// Check the query parameters of the callback, returning the code and state.
+///
+/// The state has to match the cookie set when the login started, which ties the callback to the
+/// browser that started it, so nobody can trick you into logging in as them.
+pub fn check_callback(code: Option<String>, state: Option<String>, error: Option<String>, cookie_state: Option<&str>) -> Result<(String, String), CallbackError> {
+    if let Some(error) = error {
+        return Err(CallbackError::Refused(error));
+    }
+    match (code, state) {
+        (Some(code), Some(state)) if cookie_state == Some(&state[..]) => Ok((code, state)),
+        _ => Err(CallbackError::Expired),
+    }
+}
+
+/// Whether removing one single sign-on login still leaves the user some way to log in.
+pub fn can_unlink(has_password: bool, identity_count: usize) -> bool {
+    has_password || identity_count > 1
+}
+
+/// Come up with a username for a new account, based on what the provider told us.
+///
+/// The result might already be taken; the caller is responsible for adding a suffix if so.
+pub fn suggest_username(claims: &Claims) -> String {
+    let from_email = claims.email.as_ref().and_then(|email| email.split('@').next());
+    let candidate = claims.preferred_username.as_deref().or(from_email).unwrap_or("");
+    let username: String = candidate.chars()
+        .filter(|&c| c != '#' && c != '@' && !c.is_whitespace())
+        .take(32)
+        .collect();
+    if username == "" || username == "anonymous" {
+        String::from("user")
+    } else {
+        username
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn claims(json: &str) -> Claims {
+        serde_json::from_str(json).unwrap()
+    }
+    #[test]
+    fn test_code_challenge() {
+        // From RFC 7636 appendix B.
+        assert_eq!(code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"), "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+    }
+    #[test]
+    fn test_decode_id_token() {
+        let payload = base64::encode_config(br#"{"iss":"http://localhost:8080/default","sub":"alice","aud":"mi","exp":100,"nonce":"n"}"#, base64::URL_SAFE_NO_PAD);
+        let claims = decode_id_token(&format!("e30.{}.sig", payload)).unwrap();
+        assert_eq!(claims.sub, "alice");
+        assert!(decode_id_token("nope").is_err());
+        assert!(decode_id_token("e30.!!!.sig").is_err());
+    }
+    #[test]
+    fn test_validate_claims() {
+        let issuer = "http://localhost:8080/default";
+        let good = claims(r#"{"iss":"http://localhost:8080/default","sub":"alice","aud":["other","mi"],"exp":100,"nonce":"n"}"#);
+        assert!(validate_claims(&good, issuer, "mi", "n", 50).is_ok());
+        assert!(validate_claims(&good, "http://localhost:8080/default/", "mi", "n", 50).is_ok());
+        assert!(validate_claims(&good, "http://evil", "mi", "n", 50).is_err());
+        assert!(validate_claims(&good, issuer, "someone-else", "n", 50).is_err());
+        assert!(validate_claims(&good, issuer, "mi", "replayed", 50).is_err());
+        assert!(validate_claims(&good, issuer, "mi", "n", 100).is_err());
+        let no_nonce = claims(r#"{"iss":"http://localhost:8080/default","sub":"alice","aud":"mi","exp":100}"#);
+        assert!(validate_claims(&no_nonce, issuer, "mi", "n", 50).is_err());
+    }
+    #[test]
+    fn test_check_url() {
+        assert!(check_url("https://id.example.com/realm", false).is_ok());
+        assert!(check_url("http://localhost:8080/default", false).is_err());
+        assert!(check_url("http://localhost:8080/default", true).is_ok());
+        assert!(check_url("ftp://id.example.com", true).is_err());
+        assert!(check_url("not a url", true).is_err());
+    }
+    #[test]
+    fn test_check_callback() {
+        let some = |s: &str| Some(s.to_owned());
+        assert_eq!(check_callback(some("c"), some("s"), None, Some("s")), Ok((String::from("c"), String::from("s"))));
+        assert_eq!(check_callback(None, some("s"), some("access_denied"), Some("s")), Err(CallbackError::Refused(String::from("access_denied"))));
+        assert_eq!(check_callback(some("c"), some("s"), None, Some("other")), Err(CallbackError::Expired));
+        assert_eq!(check_callback(some("c"), some("s"), None, None), Err(CallbackError::Expired));
+        assert_eq!(check_callback(None, some("s"), None, Some("s")), Err(CallbackError::Expired));
+        assert_eq!(check_callback(some("c"), None, None, Some("s")), Err(CallbackError::Expired));
+    }
+    #[test]
+    fn test_can_unlink() {
+        assert!(can_unlink(true, 1));
+        assert!(can_unlink(false, 2));
+        assert!(!can_unlink(false, 1));
+    }
+    #[test]
+    fn test_suggest_username() {
+        let checks = &[
+            (r#"{"iss":"i","sub":"s","aud":"a","exp":0,"preferred_username":"alice"}"#, "alice"),
+            (r#"{"iss":"i","sub":"s","aud":"a","exp":0,"preferred_username":"Alice Smith#1"}"#, "AliceSmith1"),
+            (r#"{"iss":"i","sub":"s","aud":"a","exp":0,"email":"bob@example.com"}"#, "bob"),
+            (r#"{"iss":"i","sub":"s","aud":"a","exp":0,"preferred_username":"anonymous"}"#, "user"),
+            (r#"{"iss":"i","sub":"s","aud":"a","exp":0}"#, "user"),
+        ][..];
+        for &(input, expected) in checks {
+            assert_eq!(suggest_username(&claims(input)), expected);
+        }
+    }
+}
@@ -1,55 +1,138 @@
 use ring::{digest, pbkdf2, rand};
 use ring::rand::SecureRandom;
+use std::convert::TryInto;
+use std::fmt::{self, Display, Formatter};
 use std::num::NonZeroU32;
+use std::sync::RwLock;
+use lazy_static::lazy_static;
 
 static PBKDF2_ALG: pbkdf2::Algorithm = pbkdf2::PBKDF2_HMAC_SHA256;
 const CREDENTIAL_LEN: usize = digest::SHA256_OUTPUT_LEN;
 const PBKDF2_ITER: u32 = 100_000;
 
+/// The version that new hashes are created with.
+const CURRENT_VERSION: u8 = 2;
+
+/// Argon2id cost parameters.
+///
+/// These get stored alongside each hash, so changing them doesn't break existing passwords;
+/// old hashes get upgraded the next time their owner logs in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Argon2Params {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Params {
+    /// The minimum recommended by OWASP's password storage cheat sheet.
+    fn default() -> Self {
+        Argon2Params {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+lazy_static! {
+    static ref ARGON2_PARAMS: RwLock<Argon2Params> = RwLock::new(Argon2Params::default());
+}
+
+/// Set the parameters used for new hashes. Call this once at startup.
+pub fn configure(params: Argon2Params) -> Result<(), PasswordError> {
+    argon2::Params::new(params.memory_kib, params.iterations, params.parallelism, Some(CREDENTIAL_LEN))
+        .map_err(|_| PasswordError::InvalidParams)?;
+    *ARGON2_PARAMS.write().unwrap() = params;
+    Ok(())
+}
+
+fn current_params() -> Argon2Params {
+    *ARGON2_PARAMS.read().unwrap()
+}
+
 fn get_salt(out: &mut [u8]) {
     assert_eq!(out.len(), CREDENTIAL_LEN);
     let r = rand::SystemRandom::new();
     r.fill(out).unwrap();
 }
 
-/// Passwords are stored with three byte fields:
+/// Version 1 passwords are stored with three byte fields:
+///
+///     [8 bit version (1)] <> [256 bit password hash ] <> [256 bit salt]
+///
+/// Version 2 adds the Argon2id cost parameters, each as a big-endian u32:
 ///
-///     [8 bit version (always 1 for now)] <> [256 bit password hash ] <> [256 bit salt]
+///     [8 bit version (2)] <> [memory KiB] <> [iterations] <> [parallelism] <> [256 bit password hash ] <> [256 bit salt]
 ///
 /// We don't do the breaking up of them in the DB itself,
 /// because we don't want to mess with migrating old hashes if this format needs to change.
 struct Parts<'a> {
     version: &'a mut [u8],
+    params: &'a mut [u8],
     hash: &'a mut [u8],
     salt: &'a mut [u8],
 }
 
-fn parts(whole: &mut [u8]) -> Parts {
+const PARAMS_LEN: usize = 12;
+
+fn params_len(version: u8) -> Option<usize> {
+    match version {
+        1 => Some(0),
+        2 => Some(PARAMS_LEN),
+        _ => None,
+    }
+}
+
+fn parts(whole: &mut [u8]) -> Result<Parts, PasswordError> {
+    let version = *whole.get(0).ok_or(PasswordError::Malformed)?;
+    let params_len = params_len(version).ok_or(PasswordError::UnknownVersion(version))?;
+    if whole.len() != 1 + params_len + (2 * CREDENTIAL_LEN) {
+        return Err(PasswordError::Malformed);
+    }
     let (version, whole_) = whole.split_at_mut(1);
+    let (params, whole_) = whole_.split_at_mut(params_len);
     let (hash, salt) = whole_.split_at_mut(CREDENTIAL_LEN);
-    assert_eq!(version.len(), 1);
-    assert_eq!(hash.len(), CREDENTIAL_LEN);
-    assert_eq!(salt.len(), CREDENTIAL_LEN);
-    Parts {
-        version, hash, salt
+    Ok(Parts {
+        version, params, hash, salt
+    })
+}
+
+fn encode_params(params: Argon2Params, out: &mut [u8]) {
+    out[0..4].copy_from_slice(&params.memory_kib.to_be_bytes());
+    out[4..8].copy_from_slice(&params.iterations.to_be_bytes());
+    out[8..12].copy_from_slice(&params.parallelism.to_be_bytes());
+}
+
+fn decode_params(bytes: &[u8]) -> Argon2Params {
+    Argon2Params {
+        memory_kib: u32::from_be_bytes(bytes[0..4].try_into().unwrap()),
+        iterations: u32::from_be_bytes(bytes[4..8].try_into().unwrap()),
+        parallelism: u32::from_be_bytes(bytes[8..12].try_into().unwrap()),
     }
 }
 
+fn argon2_derive(params: Argon2Params, salt: &[u8], password: &str, out: &mut [u8]) -> Result<(), PasswordError> {
+    use argon2::{Algorithm, Argon2, Params, Version};
+    let params = Params::new(params.memory_kib, params.iterations, params.parallelism, Some(CREDENTIAL_LEN))
+        .map_err(|_| PasswordError::InvalidParams)?;
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, out)
+        .map_err(|_| PasswordError::Malformed)
+}
+
 /// A password hashing function.
 /// The resulting "hash" includes a salt and a version number,
 /// so it can be upgraded without having to screw with any of the old passwords.
 pub fn password_hash(password: &str) -> Vec<u8> {
-    let mut result = vec![0; 1 + (2 * CREDENTIAL_LEN)];
-    let parts = parts(&mut result[..]);
-    parts.version[0] = 1;
+    let mut result = vec![0; 1 + PARAMS_LEN + (2 * CREDENTIAL_LEN)];
+    result[0] = CURRENT_VERSION;
+    let parts = parts(&mut result[..]).expect("freshly allocated hash has the right length");
+    let params = current_params();
+    encode_params(params, parts.params);
     get_salt(parts.salt);
-    pbkdf2::derive(
-        PBKDF2_ALG,
-        NonZeroU32::new(PBKDF2_ITER).unwrap(),
-        parts.salt,
-        password.as_bytes(),
-        parts.hash,
-    );
+    argon2_derive(params, parts.salt, password, parts.hash)
+        .expect("Argon2 parameters should be checked at startup");
     result
 }
 
@@ -59,28 +142,118 @@ pub enum PasswordResult {
     Failed,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PasswordError {
+    UnknownVersion(u8),
+    Malformed,
+    InvalidParams,
+}
+
+impl Display for PasswordError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            PasswordError::UnknownVersion(version) => write!(f, "unknown password hash version {}", version),
+            PasswordError::Malformed => write!(f, "malformed password hash"),
+            PasswordError::InvalidParams => write!(f, "invalid Argon2 parameters"),
+        }
+    }
+}
+
 /// A password verification function.
 /// The provided "hash" includes a salt and a version number,
 /// so it can be upgraded without having to screw with any of the old passwords.
 /// Do not attempt to compare the hash for equality yourself;
 /// that's a great way to get timing attacks.
-pub fn password_verify(password: &str, password_hash: &mut [u8]) -> PasswordResult {
-    match password_hash[0] {
+pub fn password_verify(password: &str, password_hash: &mut [u8]) -> Result<PasswordResult, PasswordError> {
+    let parts = parts(password_hash)?;
+    let passed = match parts.version[0] {
         1 => {
-            let parts = parts(password_hash);
-            let result = pbkdf2::verify(
+            pbkdf2::verify(
                 PBKDF2_ALG,
                 NonZeroU32::new(PBKDF2_ITER).unwrap(),
                 parts.salt,
                 password.as_bytes(),
                 parts.hash,
-            );
-            if result.is_err() {
-                PasswordResult::Failed
-            } else {
-                PasswordResult::Passed
-            }
+            ).is_ok()
+        }
+        2 => {
+            let mut computed = [0; CREDENTIAL_LEN];
+            argon2_derive(decode_params(parts.params), parts.salt, password, &mut computed[..])?;
+            ring::constant_time::verify_slices_are_equal(&computed[..], parts.hash).is_ok()
+        }
+        version => return Err(PasswordError::UnknownVersion(version)),
+    };
+    Ok(if passed { PasswordResult::Passed } else { PasswordResult::Failed })
+}
+
+/// Check if a hash was made with an old version or old parameters,
+/// and should be replaced next time the plaintext is available.
+pub fn password_needs_rehash(password_hash: &[u8]) -> bool {
+    match password_hash.get(0) {
+        Some(&CURRENT_VERSION) if password_hash.len() > PARAMS_LEN => {
+            decode_params(&password_hash[1..1 + PARAMS_LEN]) != current_params()
         }
-        _ => panic!("unknown password hash version?!"),
+        _ => true,
+    }
+}
+
+/// A random token to put in a link or a header: 32 bytes, written out in hex.
+///
+/// Nobody picks these, so they're too long to guess,
+/// and a plain SHA-256 is enough to store them instead of the slow hash passwords need.
+pub fn generate_token() -> String {
+    let mut bytes = [0; 32];
+    rand::SystemRandom::new().fill(&mut bytes[..]).unwrap();
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn token_hash(token: &str) -> Vec<u8> {
+    digest::digest(&digest::SHA256, token.as_bytes()).as_ref().to_vec()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn version_1_hash(password: &str) -> Vec<u8> {
+        let mut result = vec![0; 1 + (2 * CREDENTIAL_LEN)];
+        result[0] = 1;
+        let (hash, salt) = result[1..].split_at_mut(CREDENTIAL_LEN);
+        get_salt(salt);
+        pbkdf2::derive(PBKDF2_ALG, NonZeroU32::new(PBKDF2_ITER).unwrap(), salt, password.as_bytes(), hash);
+        result
+    }
+    #[test]
+    fn test_round_trip() {
+        let mut hash = password_hash("correct horse");
+        assert_eq!(hash[0], CURRENT_VERSION);
+        assert!(!password_needs_rehash(&hash));
+        assert_eq!(password_verify("correct horse", &mut hash[..]), Ok(PasswordResult::Passed));
+        assert_eq!(password_verify("battery staple", &mut hash[..]), Ok(PasswordResult::Failed));
+    }
+    #[test]
+    fn test_version_1() {
+        let mut hash = version_1_hash("correct horse");
+        assert!(password_needs_rehash(&hash));
+        assert_eq!(password_verify("correct horse", &mut hash[..]), Ok(PasswordResult::Passed));
+        assert_eq!(password_verify("battery staple", &mut hash[..]), Ok(PasswordResult::Failed));
+    }
+    #[test]
+    fn test_bad_hashes() {
+        let mut hash = password_hash("correct horse");
+        hash[0] = 99;
+        assert_eq!(password_verify("correct horse", &mut hash[..]), Err(PasswordError::UnknownVersion(99)));
+        let mut hash = password_hash("correct horse");
+        hash.pop();
+        assert_eq!(password_verify("correct horse", &mut hash[..]), Err(PasswordError::Malformed));
+        assert_eq!(password_verify("correct horse", &mut [][..]), Err(PasswordError::Malformed));
+    }
+    #[test]
+    fn test_token() {
+        let token = generate_token();
+        assert_eq!(token.len(), 64);
+        assert!(token.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_ne!(token, generate_token());
+        assert_eq!(token_hash(&token).len(), CREDENTIAL_LEN);
+        assert_ne!(token_hash(&token), token_hash(&generate_token()));
     }
 }
@@ -1059,6 +1059,79 @@ impl Display for Output {
     }
 }
 
+/// Where `ts_headline` should mark its matches. These are private-use characters,
+/// so they don't turn up in real posts, and HTML escaping leaves them alone.
+pub const HEADLINE_START: char = '\u{E000}';
+pub const HEADLINE_STOP: char = '\u{E001}';
+
+/// Get back the text that a reader sees in rendered HTML, to feed to `ts_headline`.
+///
+/// Markup gets dropped, so link targets don't show up in snippets, and block elements
+/// are replaced with spaces so words in different paragraphs don't run together.
+pub fn html_to_text(html: &str) -> String {
+    lazy_static! {
+        static ref TAG: Regex = Regex::new(r"<(/?)([a-zA-Z0-9]*)[^>]*>").unwrap();
+        static ref ENTITY: Regex = Regex::new(r"&(#[xX][0-9a-fA-F]+|#[0-9]+|[a-zA-Z]+);").unwrap();
+    }
+    const BLOCKS: &[&str] = &["p", "br", "blockquote", "pre", "li", "ul", "ol", "div", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "table", "tr", "td", "th"];
+    let text = TAG.replace_all(html, |caps: &regex::Captures| {
+        if BLOCKS.contains(&&caps[2].to_ascii_lowercase()[..]) { " " } else { "" }
+    });
+    ENTITY.replace_all(&text, |caps: &regex::Captures| {
+        let entity = &caps[1];
+        let c = if let Some(hex) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+            u32::from_str_radix(hex, 16).ok().and_then(std::char::from_u32)
+        } else if let Some(decimal) = entity.strip_prefix('#') {
+            decimal.parse().ok().and_then(std::char::from_u32)
+        } else {
+            match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => None,
+            }
+        };
+        c.map(String::from).unwrap_or_else(|| caps[0].to_owned())
+    }).into_owned()
+}
+
+/// Turn the plain text that comes out of `ts_headline` into HTML with the matches highlighted.
+///
+/// Everything except the markers gets escaped. Returns `None` if there's nothing highlighted,
+/// which happens when the only match was in the title.
+pub fn prettify_headline(headline: &str) -> Option<String> {
+    if !headline.contains(HEADLINE_START) {
+        return None;
+    }
+    let mut ret_val = String::with_capacity(headline.len() + 32);
+    ret_val.push_str("<p>");
+    let mut open = false;
+    let mut start = 0;
+    for (i, c) in headline.char_indices() {
+        if c != HEADLINE_START && c != HEADLINE_STOP {
+            continue;
+        }
+        ret_val.push_str(&escape(&headline[start..i]).to_string());
+        start = i + c.len_utf8();
+        if c == HEADLINE_START && !open {
+            ret_val.push_str("<mark>");
+            open = true;
+        } else if c == HEADLINE_STOP && open {
+            ret_val.push_str("</mark>");
+            open = false;
+        }
+    }
+    ret_val.push_str(&escape(&headline[start..]).to_string());
+    if open {
+        ret_val.push_str("</mark>");
+    }
+    ret_val.push_str("</p>");
+    Some(ret_val)
+}
+
 fn find_matching_square_bracket(text: &str) -> Option<usize> {
     assert_eq!(text.as_bytes().get(0), Some(&b'['));
     let mut depth = 0;
@@ -1230,6 +1303,28 @@ mod test {
         assert_eq!(prettify_body(comment, &mut MyData).string, CLEANER.clean(html).to_string());
     }
     #[test]
+    fn test_usernames() {
+        struct MyData;
+        impl Data for MyData {
+            fn check_comment_ref(&mut self, _id: i32) -> bool {
+                false
+            }
+            fn check_hash_tag(&mut self, _tag: &str) -> bool {
+                false
+            }
+            fn check_username(&mut self, username: &str) -> bool {
+                username == "alice" || username == "bob"
+            }
+            fn get_domain_canonical(&mut self, hostname: &str) -> String {
+                hostname.to_owned()
+            }
+        }
+        let comment = "@alice and @carol, ask @bob";
+        assert_eq!(prettify_body(comment, &mut MyData).usernames, vec!["alice", "bob"]);
+        assert_eq!(prettify_body_bbcode(comment, &mut MyData).usernames, vec!["alice", "bob"]);
+        assert_eq!(prettify_title(comment, "url", &mut MyData, false).usernames, vec!["alice", "bob"]);
+    }
+    #[test]
     fn test_unicode() {
         let comment = "finger— inciting the two officers to fire";
         let html = "<p>finger— inciting the two officers to fire";
@@ -1335,6 +1430,31 @@ mod test {
         assert_eq!(prettify_body_bbcode(comment, &mut MyData).string, CLEANER.clean(html).to_string());
     }
     #[test]
+    fn test_headline() {
+        let checks = &[
+            ("no match here", None),
+            ("a \u{E000}match\u{E001} here", Some("<p>a <mark>match</mark> here</p>")),
+            ("\u{E000}<b>\u{E001} & \u{E000}x", Some("<p><mark>&lt;b&gt;</mark> &amp; <mark>x</mark></p>")),
+            ("\u{E001}stray\u{E000}\u{E000}twice\u{E001}", Some("<p>stray<mark>twice</mark></p>")),
+        ][..];
+        for &(headline, expected) in checks {
+            assert_eq!(prettify_headline(headline).as_deref(), expected);
+        }
+    }
+    #[test]
+    fn test_html_to_text() {
+        let checks = &[
+            ("<p>plain</p>", " plain "),
+            ("<p>one</p><p>two</p>", " one  two "),
+            (r#"<p>see <a href="https://example.com/secret" rel=nofollow>this</a>, ok</p>"#, " see this, ok "),
+            ("<p>a &amp; b &lt;i&gt; &quot;c&quot; &#x27;d&#x27; &#x2f; &#39;</p>", " a & b <i> \"c\" 'd' / ' "),
+            ("&bogus; &#xZZ;", "&bogus; &#xZZ;"),
+        ][..];
+        for &(html, expected) in checks {
+            assert_eq!(html_to_text(html), expected);
+        }
+    }
+    #[test]
     fn test_bbcode_quote2() {
         let comment = "[quote]ok[/quote]test";
         let html = "<p></p><blockquote><p>ok</p></blockquote><p>test</p>";
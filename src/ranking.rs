@@ -0,0 +1,236 @@
+/*!
+Ways to order the front page.
+
+Every strategy turns a post into a "hotness" number, and the front page shows the hottest first.
+The original one measures age in "stellar time", which is the number of stars given out site-wide
+since the post was submitted. That keeps the page still when nobody is voting, but it also means
+a burst of stars on one post ages every other post at once. The others measure age in hours.
+
+Points are the same for all of them: stars, plus a bit for blog posts, plus optional weights for
+comments and flags. The flag and comment weights default to zero, which keeps the original formula.
+*/
+
+use lazy_static::lazy_static;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::sync::{Arc, RwLock};
+
+/// Everything a strategy is allowed to look at.
+#[derive(Clone, Copy, Debug)]
+pub struct RankInput {
+    pub score: i32,
+    pub comment_count: i32,
+    pub flag_count: i32,
+    pub blog_post: bool,
+    /// Stars given out site-wide since this post was submitted or approved.
+    pub stellar_age: i32,
+    pub age_hours: f64,
+}
+
+pub trait Ranking: Debug + Send + Sync {
+    fn hotness(&self, post: &RankInput) -> f64;
+    /// Whether `hotness` looks at `flag_count`. If it doesn't, the flags don't need to be counted.
+    fn uses_flags(&self) -> bool;
+    /// The front page is ranked from the newest posts. If this returns a number of days,
+    /// the top scoring posts from that far back are ranked along with them.
+    fn top_posts_window_days(&self) -> Option<i64> {
+        None
+    }
+}
+
+/// Long enough that a post older than this can't outrank a new one under the hour-based strategies.
+const TOP_POSTS_WINDOW_DAYS: i64 = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RankingParams {
+    pub stellar_gravity: f64,
+    pub wall_clock_gravity: f64,
+    pub blog_boost: f64,
+    pub comment_weight: f64,
+    pub flag_weight: f64,
+    /// How sure the Wilson score has to be. 1.96 is 95% confidence.
+    pub wilson_z: f64,
+    /// How long it takes the Wilson score to fall by half.
+    pub wilson_half_life_hours: f64,
+}
+
+impl Default for RankingParams {
+    fn default() -> Self {
+        RankingParams {
+            stellar_gravity: 1.33,
+            wall_clock_gravity: 1.8,
+            blog_boost: 0.33,
+            comment_weight: 0.0,
+            flag_weight: 0.0,
+            wilson_z: 1.96,
+            wilson_half_life_hours: 24.0,
+        }
+    }
+}
+
+/// The names that can be used in the config file and the moderator preview parameter.
+pub const STRATEGIES: &[&str] = &["stellar", "wall-clock", "wilson"];
+
+pub fn by_name(name: &str, params: RankingParams) -> Option<Arc<dyn Ranking>> {
+    match name {
+        "" | "stellar" => Some(Arc::new(StellarDecay(params))),
+        "wall-clock" => Some(Arc::new(WallClockDecay(params))),
+        "wilson" => Some(Arc::new(Wilson(params))),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownStrategy(pub String);
+
+impl Display for UnknownStrategy {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "unknown ranking strategy \"{}\"; try one of {}", self.0, STRATEGIES.join(", "))
+    }
+}
+
+lazy_static! {
+    static ref CURRENT: RwLock<Arc<dyn Ranking>> = RwLock::new(Arc::new(StellarDecay(RankingParams::default())));
+}
+
+/// Set the strategy used for the front page. Call this once at startup.
+pub fn configure(name: &str, params: RankingParams) -> Result<(), UnknownStrategy> {
+    let ranking = by_name(name, params).ok_or_else(|| UnknownStrategy(name.to_owned()))?;
+    *CURRENT.write().unwrap() = ranking;
+    Ok(())
+}
+
+pub fn current() -> Arc<dyn Ranking> {
+    CURRENT.read().unwrap().clone()
+}
+
+fn points(params: &RankingParams, post: &RankInput) -> f64 {
+    let boost = if post.blog_post { params.blog_boost } else { 0.0 };
+    let points = boost + (post.score as f64) + 1.0
+        + params.comment_weight * (post.comment_count as f64)
+        - params.flag_weight * (post.flag_count as f64);
+    points.max(0.0)
+}
+
+#[derive(Debug)]
+pub struct StellarDecay(pub RankingParams);
+
+impl Ranking for StellarDecay {
+    fn hotness(&self, post: &RankInput) -> f64 {
+        let stellar_age = post.stellar_age.max(0) as f64;
+        points(&self.0, post) / (stellar_age + 1.0).powf(self.0.stellar_gravity)
+    }
+    fn uses_flags(&self) -> bool {
+        self.0.flag_weight != 0.0
+    }
+}
+
+/// The Hacker News formula.
+#[derive(Debug)]
+pub struct WallClockDecay(pub RankingParams);
+
+impl Ranking for WallClockDecay {
+    fn hotness(&self, post: &RankInput) -> f64 {
+        points(&self.0, post) / (post.age_hours.max(0.0) + 2.0).powf(self.0.wall_clock_gravity)
+    }
+    fn uses_flags(&self) -> bool {
+        self.0.flag_weight != 0.0
+    }
+    fn top_posts_window_days(&self) -> Option<i64> {
+        Some(TOP_POSTS_WINDOW_DAYS)
+    }
+}
+
+/// The lower bound of the Wilson score interval, treating stars as up votes and flags as down votes,
+/// halved every `wilson_half_life_hours`. Comments count as `comment_weight` up votes each.
+///
+/// This favors posts that a lot of people starred and nobody flagged, over posts with a few stars.
+#[derive(Debug)]
+pub struct Wilson(pub RankingParams);
+
+impl Ranking for Wilson {
+    fn hotness(&self, post: &RankInput) -> f64 {
+        let boost = if post.blog_post { self.0.blog_boost } else { 0.0 };
+        let comments = self.0.comment_weight * post.comment_count.max(0) as f64;
+        let positive = (post.score.max(0) as f64 + boost + comments).max(0.0);
+        let n = positive + post.flag_count.max(0) as f64;
+        if n <= 0.0 {
+            return 0.0;
+        }
+        let z = self.0.wilson_z;
+        let p = positive / n;
+        let lower_bound = (p + z * z / (2.0 * n) - z * ((p * (1.0 - p) + z * z / (4.0 * n)) / n).sqrt()) / (1.0 + z * z / n);
+        let decay = if self.0.wilson_half_life_hours > 0.0 {
+            0.5f64.powf(post.age_hours.max(0.0) / self.0.wilson_half_life_hours)
+        } else {
+            1.0
+        };
+        lower_bound * decay
+    }
+    fn uses_flags(&self) -> bool {
+        true
+    }
+    fn top_posts_window_days(&self) -> Option<i64> {
+        Some(TOP_POSTS_WINDOW_DAYS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn post(score: i32, flag_count: i32, stellar_age: i32, age_hours: f64) -> RankInput {
+        RankInput { score, comment_count: 0, flag_count, blog_post: false, stellar_age, age_hours }
+    }
+    #[test]
+    fn test_stellar_matches_original_formula() {
+        let ranking = by_name("stellar", RankingParams::default()).unwrap();
+        let checks = &[(0, 0, false), (5, 10, false), (5, 10, true), (100, 3, false)][..];
+        for &(score, stellar_age, blog_post) in checks {
+            let boost = if blog_post { 0.33 } else { 0.0 };
+            let expected = (boost + (score as f64) + 1.0) / ((stellar_age as f64) + 1.0).powf(1.33);
+            let input = RankInput { blog_post, ..post(score, 0, stellar_age, 0.0) };
+            assert!((ranking.hotness(&input) - expected).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn test_wall_clock_ignores_stars_elsewhere() {
+        let ranking = by_name("wall-clock", RankingParams::default()).unwrap();
+        assert_eq!(ranking.hotness(&post(5, 0, 0, 3.0)), ranking.hotness(&post(5, 0, 1000, 3.0)));
+        assert!(ranking.hotness(&post(5, 0, 0, 1.0)) > ranking.hotness(&post(5, 0, 0, 10.0)));
+    }
+    #[test]
+    fn test_wilson() {
+        let ranking = by_name("wilson", RankingParams::default()).unwrap();
+        assert_eq!(ranking.hotness(&post(0, 0, 0, 0.0)), 0.0);
+        // More evidence beats the same ratio with less.
+        assert!(ranking.hotness(&post(50, 0, 0, 0.0)) > ranking.hotness(&post(5, 0, 0, 0.0)));
+        // Flags count against.
+        assert!(ranking.hotness(&post(10, 5, 0, 0.0)) < ranking.hotness(&post(10, 0, 0, 0.0)));
+        // The half life works.
+        let fresh = ranking.hotness(&post(10, 0, 0, 0.0));
+        assert!((ranking.hotness(&post(10, 0, 0, 24.0)) - fresh / 2.0).abs() < 1e-9);
+    }
+    #[test]
+    fn test_wilson_comment_weight() {
+        let discussed = RankInput { comment_count: 10, ..post(5, 2, 0, 0.0) };
+        let ignored = by_name("wilson", RankingParams::default()).unwrap();
+        assert_eq!(ignored.hotness(&discussed), ignored.hotness(&post(5, 2, 0, 0.0)));
+        let weighted = by_name("wilson", RankingParams { comment_weight: 0.5, ..RankingParams::default() }).unwrap();
+        assert!(weighted.hotness(&discussed) > ignored.hotness(&discussed));
+    }
+    #[test]
+    fn test_uses_flags() {
+        assert!(!by_name("stellar", RankingParams::default()).unwrap().uses_flags());
+        assert!(!by_name("wall-clock", RankingParams::default()).unwrap().uses_flags());
+        assert!(by_name("wilson", RankingParams::default()).unwrap().uses_flags());
+        let flagged = RankingParams { flag_weight: 1.0, ..RankingParams::default() };
+        assert!(by_name("stellar", flagged).unwrap().uses_flags());
+    }
+    #[test]
+    fn test_by_name() {
+        for name in STRATEGIES {
+            assert!(by_name(name, RankingParams::default()).is_some());
+        }
+        assert!(by_name("reddit", RankingParams::default()).is_none());
+        assert!(configure("reddit", RankingParams::default()).is_err());
+    }
+}
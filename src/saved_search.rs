@@ -0,0 +1,129 @@
+/*!
+Check saved searches against a post that just became visible.
+
+Running every saved search as its own query would cost a query per saved search for every new
+post, so everything except the keywords gets checked here, against the post that's already loaded.
+Only Postgres knows how `websearch_to_tsquery` stems words, so the searches with keywords that
+pass this check still go to the database, all in one query.
+
+This mirrors the filters in `search_posts_`. Searches that use `before:`, `after:` or `is:locked`
+can't be saved, so those aren't checked.
+*/
+
+use crate::models::{Post, SavedSearch};
+
+/// Whether the post matches everything in the saved search except its keywords.
+pub fn matches_except_keywords(search: &SavedSearch, post: &Post, post_tags: &[i32]) -> bool {
+    if !post.visible || post.private {
+        return false;
+    }
+    if !search.or_domains.is_empty() && !post.domain_id.map_or(false, |domain_id| search.or_domains.contains(&domain_id)) {
+        return false;
+    }
+    if !search.or_tags.is_empty() && !search.or_tags.iter().any(|tag_id| post_tags.contains(tag_id)) {
+        return false;
+    }
+    if !search.and_tags.iter().all(|tag_id| post_tags.contains(tag_id)) {
+        return false;
+    }
+    if search.hide_tags.iter().any(|tag_id| post_tags.contains(tag_id)) {
+        return false;
+    }
+    if search.for_user_id != 0 && (post.submitted_by != search.for_user_id || post.anon) {
+        return false;
+    }
+    // Postgres `LIKE` is case-sensitive, too.
+    if search.title != "" && !post.title.contains(&search.title[..]) {
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use more_interesting_base32::Base32;
+    fn post() -> Post {
+        Post {
+            id: 1,
+            uuid: Base32::from(1u64),
+            title: "Moon landing was faked".to_owned(),
+            url: Some("https://example.com/moon".to_owned()),
+            visible: true,
+            initial_stellar_time: 0,
+            score: 0,
+            comment_count: 0,
+            created_at: NaiveDateTime::from_timestamp(0, 0),
+            submitted_by: 7,
+            excerpt: None,
+            excerpt_html: None,
+            updated_at: NaiveDateTime::from_timestamp(0, 0),
+            rejected: false,
+            domain_id: Some(3),
+            banner_title: None,
+            banner_desc: None,
+            private: false,
+            title_html: None,
+            blog_post: false,
+            noindex: false,
+            locked: false,
+            anon: false,
+        }
+    }
+    fn search() -> SavedSearch {
+        SavedSearch {
+            id: 1,
+            user_id: 2,
+            name: "test".to_owned(),
+            search_params: String::new(),
+            keywords: String::new(),
+            title: String::new(),
+            and_tags: vec![],
+            or_tags: vec![],
+            hide_tags: vec![],
+            or_domains: vec![],
+            for_user_id: 0,
+            created_at: NaiveDateTime::from_timestamp(0, 0),
+        }
+    }
+    #[test]
+    fn hidden_posts() {
+        assert!(matches_except_keywords(&search(), &post(), &[]));
+        assert!(!matches_except_keywords(&search(), &Post { visible: false, .. post() }, &[]));
+        assert!(!matches_except_keywords(&search(), &Post { private: true, .. post() }, &[]));
+    }
+    #[test]
+    fn tags() {
+        let or_tags = SavedSearch { or_tags: vec![1, 2], .. search() };
+        assert!(matches_except_keywords(&or_tags, &post(), &[2, 5]));
+        assert!(!matches_except_keywords(&or_tags, &post(), &[5]));
+        let and_tags = SavedSearch { and_tags: vec![1, 2], .. search() };
+        assert!(matches_except_keywords(&and_tags, &post(), &[1, 2, 5]));
+        assert!(!matches_except_keywords(&and_tags, &post(), &[1, 5]));
+        let hide_tags = SavedSearch { hide_tags: vec![1], .. search() };
+        assert!(matches_except_keywords(&hide_tags, &post(), &[2]));
+        assert!(!matches_except_keywords(&hide_tags, &post(), &[1, 2]));
+    }
+    #[test]
+    fn domains() {
+        let or_domains = SavedSearch { or_domains: vec![3, 4], .. search() };
+        assert!(matches_except_keywords(&or_domains, &post(), &[]));
+        assert!(!matches_except_keywords(&or_domains, &Post { domain_id: Some(5), .. post() }, &[]));
+        assert!(!matches_except_keywords(&or_domains, &Post { domain_id: None, .. post() }, &[]));
+    }
+    #[test]
+    fn submitter() {
+        let for_user = SavedSearch { for_user_id: 7, .. search() };
+        assert!(matches_except_keywords(&for_user, &post(), &[]));
+        assert!(!matches_except_keywords(&for_user, &Post { submitted_by: 8, .. post() }, &[]));
+        assert!(!matches_except_keywords(&for_user, &Post { anon: true, .. post() }, &[]));
+    }
+    #[test]
+    fn title() {
+        assert!(matches_except_keywords(&SavedSearch { title: "landing".to_owned(), .. search() }, &post(), &[]));
+        assert!(!matches_except_keywords(&SavedSearch { title: "Landing".to_owned(), .. search() }, &post(), &[]));
+        assert!(matches_except_keywords(&SavedSearch { title: "100%".to_owned(), .. search() }, &Post { title: "100% real".to_owned(), .. post() }, &[]));
+        assert!(!matches_except_keywords(&SavedSearch { title: "1_0".to_owned(), .. search() }, &Post { title: "100".to_owned(), .. post() }, &[]));
+    }
+}
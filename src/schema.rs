@@ -1,9 +1,31 @@
+table! {
+    use crate::sql_types::*;
+
+    api_tokens (uuid) {
+        uuid -> Int8,
+        user_id -> Int4,
+        name -> Varchar,
+        token_hash -> Bytea,
+        scope_read -> Bool,
+        scope_vote -> Bool,
+        scope_post -> Bool,
+        scope_moderate -> Bool,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
     blocked_regexes (id) {
         id -> Int4,
         regex -> Varchar,
+        scope -> Varchar,
+        action -> Varchar,
+        message -> Varchar,
+        hits -> Int4,
+        last_hit_at -> Nullable<Timestamp>,
     }
 }
 
@@ -14,6 +36,8 @@ table! {
         user_id -> Int4,
         comment_id -> Int4,
         created_at -> Timestamp,
+        reason -> Varchar,
+        note -> Nullable<Varchar>,
     }
 }
 
@@ -36,6 +60,15 @@ table! {
     }
 }
 
+table! {
+    use crate::sql_types::*;
+
+    comment_search_index (comment_id) {
+        comment_id -> Int4,
+        search_index -> Tsvector,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
@@ -59,6 +92,7 @@ table! {
         created_by -> Int4,
         updated_at -> Timestamp,
         rejected -> Bool,
+        parent_id -> Nullable<Int4>,
     }
 }
 
@@ -92,6 +126,29 @@ table! {
     }
 }
 
+table! {
+    use crate::sql_types::*;
+
+    email_verification_tokens (token_hash) {
+        token_hash -> Bytea,
+        user_id -> Int4,
+        email -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    use crate::sql_types::*;
+
+    external_identities (id) {
+        id -> Int4,
+        user_id -> Int4,
+        issuer -> Varchar,
+        subject -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
@@ -99,6 +156,8 @@ table! {
         user_id -> Int4,
         post_id -> Int4,
         created_at -> Timestamp,
+        reason -> Varchar,
+        note -> Nullable<Varchar>,
     }
 }
 
@@ -126,6 +185,20 @@ table! {
     }
 }
 
+table! {
+    use crate::sql_types::*;
+
+    login_attempts (id) {
+        id -> Int4,
+        kind -> Varchar,
+        username -> Varchar,
+        user_id -> Nullable<Int4>,
+        ip_address -> Varchar,
+        succeeded -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
@@ -134,6 +207,8 @@ table! {
         payload -> Jsonb,
         created_at -> Timestamp,
         created_by -> Int4,
+        action -> Varchar,
+        target_user_id -> Nullable<Int4>,
     }
 }
 
@@ -146,6 +221,41 @@ table! {
         post_id -> Int4,
         created_at -> Timestamp,
         created_by -> Int4,
+        saved_search_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+    }
+}
+
+table! {
+    use crate::sql_types::*;
+
+    password_reset_tokens (token_hash) {
+        token_hash -> Bytea,
+        user_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    use crate::sql_types::*;
+
+    pending_logins (uuid) {
+        uuid -> Int8,
+        user_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    use crate::sql_types::*;
+
+    pending_oidc_logins (state) {
+        state -> Varchar,
+        nonce -> Varchar,
+        code_verifier -> Varchar,
+        invite_token -> Nullable<Int8>,
+        link_user_id -> Nullable<Int4>,
+        created_at -> Timestamp,
     }
 }
 
@@ -252,6 +362,35 @@ table! {
     }
 }
 
+table! {
+    use crate::sql_types::*;
+
+    private_feed_tokens (uuid) {
+        uuid -> Int8,
+        user_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    use crate::sql_types::*;
+
+    saved_searches (id) {
+        id -> Int4,
+        user_id -> Int4,
+        name -> Varchar,
+        search_params -> Varchar,
+        keywords -> Varchar,
+        title -> Varchar,
+        and_tags -> Array<Int4>,
+        or_tags -> Array<Int4>,
+        hide_tags -> Array<Int4>,
+        or_domains -> Array<Int4>,
+        for_user_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
@@ -283,6 +422,21 @@ table! {
     }
 }
 
+table! {
+    use crate::sql_types::*;
+
+    suspensions (id) {
+        id -> Int4,
+        user_id -> Int4,
+        starts_at -> Timestamp,
+        ends_at -> Timestamp,
+        reason -> Varchar,
+        created_by -> Int4,
+        lifted_at -> Nullable<Timestamp>,
+        lifted_by -> Nullable<Int4>,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
@@ -295,6 +449,16 @@ table! {
     }
 }
 
+table! {
+    use crate::sql_types::*;
+
+    totp_recovery_codes (id) {
+        id -> Int4,
+        user_id -> Int4,
+        code_hash -> Bytea,
+    }
+}
+
 table! {
     use crate::sql_types::*;
 
@@ -304,6 +468,19 @@ table! {
         user_agent -> Text,
         user_id -> Int4,
         last_seen_at -> Timestamp,
+        name -> Nullable<Varchar>,
+    }
+}
+
+table! {
+    use crate::sql_types::*;
+
+    user_totp (user_id) {
+        user_id -> Int4,
+        secret -> Bytea,
+        enabled -> Bool,
+        last_used_step -> Nullable<Int8>,
+        created_at -> Timestamp,
     }
 }
 
@@ -321,27 +498,39 @@ table! {
         dark_mode -> Bool,
         big_mode -> Bool,
         identicon -> Int4,
+        email -> Nullable<Varchar>,
+        notify_on_mention -> Bool,
     }
 }
 
+joinable!(api_tokens -> users (user_id));
 joinable!(comment_flags -> comments (comment_id));
 joinable!(comment_flags -> users (user_id));
 joinable!(comment_hides -> comments (comment_id));
 joinable!(comment_hides -> users (user_id));
 joinable!(comment_readpoints -> posts (post_id));
 joinable!(comment_readpoints -> users (user_id));
+joinable!(comment_search_index -> comments (comment_id));
 joinable!(comment_stars -> comments (comment_id));
 joinable!(comment_stars -> users (user_id));
 joinable!(comments -> posts (post_id));
 joinable!(comments -> users (created_by));
 joinable!(domain_restrictions -> domains (domain_id));
 joinable!(domain_synonyms -> domains (to_domain_id));
+joinable!(email_verification_tokens -> users (user_id));
+joinable!(external_identities -> users (user_id));
 joinable!(flags -> posts (post_id));
 joinable!(flags -> users (user_id));
 joinable!(invite_tokens -> users (invited_by));
 joinable!(legacy_comments -> posts (post_id));
+joinable!(login_attempts -> users (user_id));
 joinable!(moderation -> users (created_by));
+joinable!(notifications -> comments (comment_id));
 joinable!(notifications -> posts (post_id));
+joinable!(notifications -> saved_searches (saved_search_id));
+joinable!(password_reset_tokens -> users (user_id));
+joinable!(pending_logins -> users (user_id));
+joinable!(pending_oidc_logins -> users (link_user_id));
 joinable!(poll_choices -> polls (poll_id));
 joinable!(poll_choices -> users (created_by));
 joinable!(poll_votes -> poll_choices (choice_id));
@@ -355,26 +544,38 @@ joinable!(post_tagging -> posts (post_id));
 joinable!(post_tagging -> tags (tag_id));
 joinable!(posts -> domains (domain_id));
 joinable!(posts -> users (submitted_by));
+joinable!(private_feed_tokens -> users (user_id));
+joinable!(saved_searches -> users (user_id));
 joinable!(stars -> posts (post_id));
 joinable!(stars -> users (user_id));
 joinable!(subscriptions -> posts (post_id));
+joinable!(totp_recovery_codes -> users (user_id));
 joinable!(user_sessions -> users (user_id));
+joinable!(user_totp -> users (user_id));
 
 allow_tables_to_appear_in_same_query!(
+    api_tokens,
     blocked_regexes,
     comment_flags,
     comment_hides,
     comment_readpoints,
+    comment_search_index,
     comment_stars,
     comments,
     domain_restrictions,
     domain_synonyms,
     domains,
+    email_verification_tokens,
+    external_identities,
     flags,
     invite_tokens,
     legacy_comments,
+    login_attempts,
     moderation,
     notifications,
+    password_reset_tokens,
+    pending_logins,
+    pending_oidc_logins,
     poll_choices,
     poll_votes,
     polls,
@@ -383,10 +584,15 @@ allow_tables_to_appear_in_same_query!(
     post_tagging,
     post_word_freq,
     posts,
+    private_feed_tokens,
+    saved_searches,
     site_customization,
     stars,
     subscriptions,
+    suspensions,
     tags,
+    totp_recovery_codes,
     user_sessions,
+    user_totp,
     users,
 );
@@ -0,0 +1,221 @@
+/*!
+The query language for the main search box.
+
+A query is a list of terms separated by spaces. Most terms are keywords, which get handed to
+Postgres's `websearch_to_tsquery`, so `"quoted phrases"`, `OR`, and `-excluded` words work the
+way they do on most search engines. Terms that look like `name:value` are operators instead:
+
+* `tag:name` requires a tag, `tag:a|b` requires either one, and `-tag:name` hides a tag
+* `domain:example.com` (or `site:`) limits the search to posts linking there
+* `by:alice` (or `user:`) limits the search to one submitter
+* `title:word` searches titles, and `title:"some words"` works too
+* `before:2023-01-01` and `after:2023-01-01` limit the dates
+* `is:locked` and `-is:locked` look at whether the comments are locked
+
+Anything else shaped like an operator is an error, so typos don't silently turn into keywords.
+*/
+
+use chrono::NaiveDate;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Query {
+    pub keywords: String,
+    pub title: String,
+    pub and_tags: Vec<String>,
+    pub or_tags: Vec<String>,
+    pub hide_tags: Vec<String>,
+    pub domains: Vec<String>,
+    pub by: Option<String>,
+    pub before: Option<NaiveDate>,
+    pub after: Option<NaiveDate>,
+    pub locked: Option<bool>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QueryError {
+    UnknownOperator(String),
+    MissingValue(String),
+    CannotNegate(String),
+    BadDate(String),
+    UnknownFlag(String),
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            QueryError::UnknownOperator(name) => write!(f, "Unknown search operator \"{}:\"; try tag:, domain:, by:, title:, before:, after:, or is:locked", name),
+            QueryError::MissingValue(name) => write!(f, "The search operator \"{}:\" needs something after the colon", name),
+            QueryError::CannotNegate(name) => write!(f, "The search operator \"{}:\" can't be negated; only tag: and is: can", name),
+            QueryError::BadDate(date) => write!(f, "\"{}\" is not a date; write it like 2023-01-31", date),
+            QueryError::UnknownFlag(flag) => write!(f, "Unknown search flag \"is:{}\"; try is:locked", flag),
+        }
+    }
+}
+
+/// One space-separated piece of the query, with any quotes removed.
+#[derive(Debug)]
+struct Term<'a> {
+    negated: bool,
+    operator: Option<&'a str>,
+    value: &'a str,
+    quoted: bool,
+}
+
+/// Split off a quoted string, starting just after the opening quote.
+/// A missing closing quote runs to the end, since that's almost always what was meant.
+fn take_quoted(s: &str) -> (&str, &str) {
+    match s.find('"') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    }
+}
+
+fn is_operator_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase())
+}
+
+fn terms(input: &str) -> Vec<Term<'_>> {
+    let mut terms = Vec::new();
+    let mut rest = input.trim_start();
+    while !rest.is_empty() {
+        let negated = rest.starts_with('-');
+        let body = if negated { &rest[1..] } else { rest };
+        if let Some(quoted) = body.strip_prefix('"') {
+            let (value, next) = take_quoted(quoted);
+            terms.push(Term { negated, operator: None, value, quoted: true });
+            rest = next.trim_start();
+            continue;
+        }
+        let end = body.find(char::is_whitespace).unwrap_or(body.len());
+        let word = &body[..end];
+        let mut term = Term { negated, operator: None, value: word, quoted: false };
+        let mut next = &body[end..];
+        if let Some(colon) = word.find(':') {
+            let (name, value) = (&word[..colon], &word[colon + 1..]);
+            // URLs are keywords, not operators.
+            if is_operator_name(name) && !value.starts_with("//") {
+                term.operator = Some(name);
+                term.value = value;
+                if let Some(quoted) = body[colon + 1..].strip_prefix('"') {
+                    let (value, after) = take_quoted(quoted);
+                    term.value = value;
+                    term.quoted = true;
+                    next = after;
+                }
+            }
+        }
+        terms.push(term);
+        rest = next.trim_start();
+    }
+    terms
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, QueryError> {
+    value.parse().map_err(|_| QueryError::BadDate(value.to_owned()))
+}
+
+pub fn parse(input: &str) -> Result<Query, QueryError> {
+    let mut query = Query::default();
+    let mut keywords = Vec::new();
+    for term in terms(input) {
+        let name = match term.operator {
+            None => {
+                if term.value.is_empty() {
+                    continue;
+                }
+                let minus = if term.negated { "-" } else { "" };
+                if term.quoted {
+                    keywords.push(format!("{}\"{}\"", minus, term.value));
+                } else {
+                    keywords.push(format!("{}{}", minus, term.value));
+                }
+                continue;
+            }
+            Some(name) => name,
+        };
+        if term.value.is_empty() {
+            return Err(QueryError::MissingValue(name.to_owned()));
+        }
+        if term.negated && name != "tag" && name != "is" {
+            return Err(QueryError::CannotNegate(name.to_owned()));
+        }
+        match name {
+            "tag" if term.negated => query.hide_tags.extend(term.value.split('|').filter(|t| !t.is_empty()).map(str::to_owned)),
+            "tag" if term.value.contains('|') => query.or_tags.extend(term.value.split('|').filter(|t| !t.is_empty()).map(str::to_owned)),
+            "tag" => query.and_tags.push(term.value.to_owned()),
+            "domain" | "site" => query.domains.push(term.value.to_owned()),
+            "by" | "user" => query.by = Some(term.value.trim_start_matches('@').to_owned()),
+            "title" => {
+                if !query.title.is_empty() {
+                    query.title.push(' ');
+                }
+                query.title.push_str(term.value);
+            }
+            "before" => query.before = Some(parse_date(term.value)?),
+            "after" => query.after = Some(parse_date(term.value)?),
+            "is" => match term.value {
+                "locked" => query.locked = Some(!term.negated),
+                flag => return Err(QueryError::UnknownFlag(flag.to_owned())),
+            },
+            _ => return Err(QueryError::UnknownOperator(name.to_owned())),
+        }
+    }
+    query.keywords = keywords.join(" ");
+    Ok(query)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_keywords() {
+        let checks = &[
+            ("", ""),
+            ("hello world", "hello world"),
+            ("  hello   world ", "hello world"),
+            ("\"exact phrase\" other", "\"exact phrase\" other"),
+            ("-\"not this\" -that", "-\"not this\" -that"),
+            ("\"unclosed phrase", "\"unclosed phrase\""),
+            ("cats OR dogs", "cats OR dogs"),
+            ("see https://example.com/a:b", "see https://example.com/a:b"),
+            ("Re: something", "Re: something"),
+        ][..];
+        for &(input, expected) in checks {
+            assert_eq!(parse(input).unwrap().keywords, expected, "{}", input);
+        }
+    }
+    #[test]
+    fn test_operators() {
+        let query = parse("\"exact phrase\" tag:quotes -tag:meta domain:example.com by:alice before:2023-01-01 is:locked").unwrap();
+        assert_eq!(query, Query {
+            keywords: String::from("\"exact phrase\""),
+            and_tags: vec![String::from("quotes")],
+            hide_tags: vec![String::from("meta")],
+            domains: vec![String::from("example.com")],
+            by: Some(String::from("alice")),
+            before: Some(NaiveDate::from_ymd(2023, 1, 1)),
+            locked: Some(true),
+            .. Query::default()
+        });
+        let query = parse("tag:a|b title:\"two words\" -is:locked user:@bob after:2020-02-29").unwrap();
+        assert_eq!(query.or_tags, vec![String::from("a"), String::from("b")]);
+        assert_eq!(query.title, "two words");
+        assert_eq!(query.locked, Some(false));
+        assert_eq!(query.by.as_deref(), Some("bob"));
+        assert_eq!(query.after, Some(NaiveDate::from_ymd(2020, 2, 29)));
+    }
+    #[test]
+    fn test_errors() {
+        let checks = &[
+            ("tags:quotes", QueryError::UnknownOperator(String::from("tags"))),
+            ("hello tag:", QueryError::MissingValue(String::from("tag"))),
+            ("-by:alice", QueryError::CannotNegate(String::from("by"))),
+            ("before:yesterday", QueryError::BadDate(String::from("yesterday"))),
+            ("is:open", QueryError::UnknownFlag(String::from("open"))),
+        ][..];
+        for (input, expected) in checks {
+            assert_eq!(&parse(input).unwrap_err(), expected, "{}", input);
+        }
+    }
+}
@@ -3,8 +3,10 @@ use rocket::Request;
 use rocket::http::{Cookie, SameSite, Method, Status};
 use rocket::outcome::Outcome;
 use crate::models::*;
+use crate::SiteConfig;
 use more_interesting_base32::Base32;
 use std::mem;
+use chrono::{Duration, Utc};
 
 pub struct UserAgentString<'r> {
     pub user_agent: &'r str,
@@ -45,13 +47,48 @@ impl<'r> FromRequest<'r> for ReferrerString<'r> {
 pub struct LoginSession {
     pub session: UserSession,
     pub user: User,
+    /// If the user logged in with an `Authorization: Bearer` header instead of a cookie,
+    /// this is the token they used.
+    pub api_token: Option<ApiToken>,
 }
 
-#[rocket::async_trait]
-impl<'r> FromRequest<'r> for LoginSession {
-    type Error = ();
+/// Which scope an API token needs to have to be used on this route.
+///
+/// Anything that isn't listed here can only be done from a browser session.
+/// Pages like settings are GET requests too, and they show secrets, so read access is also by name.
+fn required_scope(request: &Request<'_>) -> Option<ApiScope> {
+    match request.route().and_then(|route| route.name.as_deref()) {
+        Some("api_index") | Some("api_blog_index") | Some("api_new") | Some("api_latest") | Some("api_top") | Some("api_random") |
+        Some("api_advanced_search") | Some("api_search_comments") | Some("api_user_posts") | Some("api_get_comments") |
+        Some("rss") | Some("atom") | Some("json_feed") | Some("blog_rss") | Some("blog_atom") | Some("blog_json_feed") |
+        Some("comments_rss") | Some("comments_atom") | Some("comments_json_feed") => Some(ApiScope::Read),
+        Some("vote") | Some("vote_comment") | Some("vote_poll") => Some(ApiScope::Vote),
+        Some("create") | Some("post_comment") | Some("create_message") | Some("post_subscriptions") => Some(ApiScope::Post),
+        _ => None,
+    }
+}
 
-    async fn from_request(request: &'r Request<'_>) -> Outcome<LoginSession, (Status, ()), ()> {
+impl LoginSession {
+    /// Authenticate the request, without checking whether an API token has the right scope.
+    async fn authenticate(request: &Request<'_>) -> Outcome<LoginSession, (Status, ()), ()> {
+        let bearer = request.headers().get("authorization").next()
+            .and_then(|header| header.strip_prefix("Bearer "));
+        if let Some(bearer) = bearer {
+            let conn = MoreInterestingConn::from_request(request).await.unwrap();
+            return match conn.authenticate_api_token(bearer.trim()).await {
+                Some((_, user)) if user.banned => Outcome::Failure((Status::Unauthorized, ())),
+                Some((_, user)) if conn.get_active_suspension(user.id).await.is_some() => Outcome::Failure((Status::Unauthorized, ())),
+                Some((api_token, user)) => Outcome::Success(LoginSession {
+                    session: UserSession::default(),
+                    api_token: Some(api_token),
+                    user,
+                }),
+                None => {
+                    warn!("Got invalid API token");
+                    Outcome::Failure((Status::Unauthorized, ()))
+                }
+            };
+        }
         let cookies = request.cookies();
         let session_uuid: Option<Base32> = cookies
             .get("U")
@@ -67,6 +104,13 @@ impl<'r> FromRequest<'r> for LoginSession {
         if let Some(session_uuid) = session_uuid {
             let conn = MoreInterestingConn::from_request(request).await.unwrap();
             if let Ok(session) = conn.get_session_by_uuid(session_uuid).await {
+                let inactivity_days = request.rocket().state::<SiteConfig>()
+                    .map(|config| config.session_inactivity_days)
+                    .unwrap_or(0);
+                if inactivity_days > 0 && Utc::now().naive_utc() - session.last_seen_at > Duration::days(inactivity_days) {
+                    let _ = conn.revoke_session(session.user_id, session_uuid).await;
+                    return Outcome::Failure((Status::Unauthorized, ()));
+                }
                 if let Ok(user) = conn.get_user_by_id(session.user_id).await {
                     if user.trust_level == -2 { 
                         let cookie = Cookie::build("B", "1").path("/").permanent().same_site(SameSite::None).finish(); 
@@ -77,8 +121,13 @@ impl<'r> FromRequest<'r> for LoginSession {
                     if user.banned {
                         return Outcome::Failure((Status::Unauthorized, ()));
                     }
+                    // Suspending someone logs them out, but they could have been partway through logging in.
+                    if conn.get_active_suspension(user.id).await.is_some() {
+                        let _ = conn.revoke_session(user.id, session_uuid).await;
+                        return Outcome::Failure((Status::Unauthorized, ()));
+                    }
                     let _ = conn.bump_last_seen_at(session_uuid).await;
-                    Outcome::Success(LoginSession { session, user })
+                    Outcome::Success(LoginSession { session, user, api_token: None })
                 } else {
                     Outcome::Failure((Status::Unauthorized, ()))
                 }
@@ -91,6 +140,27 @@ impl<'r> FromRequest<'r> for LoginSession {
     }
 }
 
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for LoginSession {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<LoginSession, (Status, ()), ()> {
+        match LoginSession::authenticate(request).await {
+            Outcome::Success(login_session) => {
+                if let Some(ref api_token) = login_session.api_token {
+                    match required_scope(request) {
+                        Some(scope) if api_token.has_scope(scope) => {},
+                        _ => return Outcome::Failure((Status::Forbidden, ())),
+                    }
+                }
+                Outcome::Success(login_session)
+            }
+            Outcome::Failure(f) => Outcome::Failure(f),
+            Outcome::Forward(f) => Outcome::Forward(f),
+        }
+    }
+}
+
 pub struct ModeratorSession {
     pub session: UserSession,
     pub user: User,
@@ -101,8 +171,23 @@ impl<'r> FromRequest<'r> for ModeratorSession {
     type Error = ();
 
     async fn from_request(request: &'r Request<'_>) -> Outcome<ModeratorSession, (Status, ()), ()> {
-        match LoginSession::from_request(request).await {
+        match LoginSession::authenticate(request).await {
+            Outcome::Success(ref login_session) if login_session.api_token.as_ref().map(|t| !t.has_scope(ApiScope::Moderate)).unwrap_or(false) => {
+                Outcome::Failure((Status::Forbidden, ()))
+            }
             Outcome::Success(ref mut login_session) if login_session.user.trust_level >= 3 => {
+                let require_2fa = request.rocket().state::<SiteConfig>()
+                    .map(|config| config.require_2fa_for_moderators)
+                    .unwrap_or(false);
+                // API tokens are made from a session that already passed two-factor authentication,
+                // so they don't ask for a code, but a moderation token stops working if it gets turned off.
+                if require_2fa {
+                    let conn = MoreInterestingConn::from_request(request).await.unwrap();
+                    if !conn.has_totp_enabled(login_session.user.id).await {
+                        warn!("Moderator tried to use moderator tools without two-factor authentication");
+                        return Outcome::Failure((Status::Forbidden, ()));
+                    }
+                }
                 let user = mem::replace(&mut login_session.user, User::default());
                 let session = mem::replace(&mut login_session.session, UserSession::default());
                 Outcome::Success(ModeratorSession { session, user })
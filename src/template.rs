@@ -1,8 +1,8 @@
 use askama::Template;
 use serde::{Serialize, Serializer};
 use crate::models::{Comment, CommentInfo, CommentSearchResult, LegacyCommentInfo, ModerationInfo, NotificationInfo, PostInfo, User, UserSession};
-use crate::models::{Tag, CommentFlagInfo, PollInfo, PostFlagInfo, SiteCustomization, DomainSynonymInfo};
-use crate::models::BlockedRegex;
+use crate::models::{Tag, CommentFlagInfo, PollInfo, PostFlagInfo, SiteCustomization, DomainStats, DomainSynonymInfo};
+use crate::models::{ApiToken, BlockedRegex, ContentSample, ExternalIdentity, LoginAttempt, SavedSearch, Suspension, UserSessionInfo};
 use crate::customization::Customization;
 use crate::SiteConfig;
 use more_interesting_base32::Base32;
@@ -255,6 +255,45 @@ pub struct Login {
     pub noindex: bool,
 }
 
+#[derive(Template)]
+#[template(path = "login-2fa.html")]
+pub struct LoginTotp {
+    pub title: String,
+    pub alert: String,
+    pub customization: Customization,
+    pub config: SiteConfig,
+    pub user: User, // default
+    pub session: UserSession, // default
+    pub notifications: Vec<NotificationInfo>, // always empty
+    pub noindex: bool,
+}
+
+#[derive(Template)]
+#[template(path = "forgot-password.html")]
+pub struct ForgotPassword {
+    pub title: String,
+    pub alert: String,
+    pub customization: Customization,
+    pub config: SiteConfig,
+    pub user: User, // default
+    pub session: UserSession, // default
+    pub notifications: Vec<NotificationInfo>, // always empty
+    pub noindex: bool,
+}
+
+#[derive(Template)]
+#[template(path = "reset-password.html")]
+pub struct ResetPassword {
+    pub title: String,
+    pub alert: String,
+    pub customization: Customization,
+    pub config: SiteConfig,
+    pub user: User, // default
+    pub session: UserSession, // default
+    pub notifications: Vec<NotificationInfo>, // always empty
+    pub noindex: bool,
+}
+
 #[derive(Template)]
 #[template(path = "search.html")]
 pub struct Search {
@@ -272,6 +311,9 @@ pub struct Search {
     pub domain: String,
     pub keywords_param: String,
     pub title_param: String,
+    pub posts: Vec<PostInfo>,
+    pub saved_searches: Vec<SavedSearch>,
+    pub can_save_search: bool,
     pub notifications: Vec<NotificationInfo>,
     pub noindex: bool,
 }
@@ -303,6 +345,12 @@ pub struct IndexComments {
     pub session: UserSession,
     pub noindex: bool,
     pub notifications: Vec<NotificationInfo>,
+    pub next_search_page: i32,
+    pub keywords_param: String,
+    pub tag_param: String,
+    pub order_param: &'static str,
+    pub before_date_param: Option<NaiveDate>,
+    pub after_date_param: Option<NaiveDate>,
 }
 
 #[derive(Template)]
@@ -318,6 +366,12 @@ pub struct ProfileComments {
     pub session: UserSession,
     pub noindex: bool,
     pub notifications: Vec<NotificationInfo>,
+    pub next_search_page: i32,
+    pub keywords_param: String,
+    pub tag_param: String,
+    pub order_param: &'static str,
+    pub before_date_param: Option<NaiveDate>,
+    pub after_date_param: Option<NaiveDate>,
 }
 
 #[derive(Template)]
@@ -411,6 +465,14 @@ pub struct ModLog {
     pub customization: Customization,
     pub config: SiteConfig,
     pub noindex: bool,
+    /// Every action type, its name, and whether it's the one being filtered by.
+    pub kinds: Vec<(&'static str, &'static str, bool)>,
+    pub moderator_param: String,
+    pub target_param: String,
+    pub after_date_param: String,
+    pub before_date_param: String,
+    /// The filters, ready to go after the `?` in a link to another page or an export.
+    pub filter_query: String,
 }
 
 #[derive(Template)]
@@ -428,6 +490,17 @@ pub struct ViewStarComment {
     pub customization: Customization,
 }
 
+/// One comment, sent to people who have the post open.
+#[derive(Template)]
+#[template(path = "view-comment.html")]
+pub struct ViewComment<'a> {
+    pub comment: CommentInfo,
+    pub post_info: &'a PostInfo,
+    pub user: &'a User,
+    pub session: &'a UserSession,
+    pub customization: &'a Customization,
+}
+
 #[derive(Template)]
 #[template(path = "tags.html")]
 pub struct Tags {
@@ -481,6 +554,15 @@ pub struct Settings {
     pub config: SiteConfig,
     pub title: String,
     pub noindex: bool,
+    pub api_tokens: Vec<ApiToken>,
+    pub private_feed_url: Option<String>,
+    pub totp_enabled: bool,
+    pub totp_pending_secret: Option<String>,
+    pub totp_pending_url: Option<String>,
+    pub totp_recovery_code_count: i64,
+    pub totp_required: bool,
+    pub sessions: Vec<UserSessionInfo>,
+    pub external_identities: Vec<ExternalIdentity>,
 }
 
 #[derive(Template)]
@@ -496,6 +578,20 @@ pub struct AdminTags {
     pub tags: Vec<Tag>,
 }
 
+#[derive(Template)]
+#[template(path = "admin/login-attempts.html")]
+pub struct AdminLoginAttempts {
+    pub title: String,
+    pub alert: String,
+    pub page: AdminPageId,
+    pub user: User,
+    pub session: UserSession,
+    pub customization: Customization,
+    pub config: SiteConfig,
+    pub search: String,
+    pub login_attempts: Vec<LoginAttempt>,
+}
+
 #[derive(Template)]
 #[template(path = "admin/comment-flags.html")]
 pub struct AdminCommentFlags {
@@ -506,7 +602,8 @@ pub struct AdminCommentFlags {
     pub session: UserSession,
     pub customization: Customization,
     pub config: SiteConfig,
-    pub comment_flags: Vec<CommentFlagInfo>,
+    /// Grouped by reason, as in `flag_reason::group_by_reason`.
+    pub comment_flags: Vec<(&'static str, Vec<CommentFlagInfo>)>,
 }
 
 #[derive(Template)]
@@ -535,6 +632,20 @@ pub struct AdminDomains {
     pub domain_synonyms: Vec<DomainSynonymInfo>,
 }
 
+#[derive(Template)]
+#[template(path = "admin/domain-restrictions.html")]
+pub struct AdminDomainRestrictions {
+    pub title: String,
+    pub alert: String,
+    pub page: AdminPageId,
+    pub user: User,
+    pub session: UserSession,
+    pub customization: Customization,
+    pub config: SiteConfig,
+    pub hostname: String,
+    pub domain_stats: Vec<DomainStats>,
+}
+
 #[derive(Template)]
 #[template(path = "admin/flags.html")]
 pub struct AdminFlags {
@@ -545,7 +656,8 @@ pub struct AdminFlags {
     pub session: UserSession,
     pub customization: Customization,
     pub config: SiteConfig,
-    pub post_flags: Vec<PostFlagInfo>,
+    /// Grouped by reason, as in `flag_reason::group_by_reason`.
+    pub post_flags: Vec<(&'static str, Vec<PostFlagInfo>)>,
 }
 
 #[derive(Template)]
@@ -560,6 +672,18 @@ pub struct AdminUsers {
     pub config: SiteConfig,
     pub username: String,
     pub users_list: Vec<User>,
+    pub suspensions: Vec<Suspension>,
+}
+
+impl AdminUsers {
+    /// When this user's suspension ends, or an empty string if they aren't suspended.
+    pub fn suspended_until(&self, user_id: i32) -> String {
+        self.suspensions.iter()
+            .filter(|suspension| suspension.user_id == user_id)
+            .map(|suspension| suspension.ends_at.format("%Y-%m-%d %H:%M").to_string())
+            .next()
+            .unwrap_or(String::new())
+    }
 }
 
 #[derive(Template)]
@@ -573,6 +697,12 @@ pub struct AdminBlockedRegexes {
     pub customization: Customization,
     pub config: SiteConfig,
     pub blocked_regexes: Vec<BlockedRegex>,
+    /// `(as_str, label, selected)` for each `blocked_regex::Scope`.
+    pub scopes: Vec<(&'static str, &'static str, bool)>,
+    pub actions: Vec<(&'static str, &'static str)>,
+    pub test_regex: String,
+    pub test_matches: Vec<ContentSample>,
+    pub test_sample_count: usize,
 }
 
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -621,6 +751,8 @@ pub enum AdminPageId {
     CommentFlags = 5,
     Users = 6,
     BlockedRegexes = 7,
+    LoginAttempts = 8,
+    DomainRestrictions = 9,
 }
 
 impl Serialize for AdminPageId {
@@ -643,6 +775,7 @@ impl Default for AdminPageId {
 pub struct Rss {
     pub config: SiteConfig,
     pub customization: Customization,
+    pub feed_title: String,
     pub link: String,
     pub posts: Vec<PostInfo>,
 }
@@ -652,10 +785,54 @@ pub struct Rss {
 pub struct BlogRss {
     pub config: SiteConfig,
     pub customization: Customization,
+    pub feed_title: String,
     pub link: String,
     pub posts: Vec<PostInfo>,
 }
 
+#[derive(Template)]
+#[template(path = "atom.xml")]
+pub struct Atom {
+    pub config: SiteConfig,
+    pub customization: Customization,
+    pub feed_title: String,
+    pub link: String,
+    pub updated: String,
+    pub posts: Vec<PostInfo>,
+}
+
+#[derive(Template)]
+#[template(path = "private-feed.rss.xml")]
+pub struct PrivateFeedRss {
+    pub config: SiteConfig,
+    pub customization: Customization,
+    pub link: String,
+    pub feed_user: User,
+    pub notifications: Vec<NotificationInfo>,
+    pub comments: Vec<CommentSearchResult>,
+}
+
+#[derive(Template)]
+#[template(path = "comments.rss.xml")]
+pub struct CommentsRss {
+    pub config: SiteConfig,
+    pub customization: Customization,
+    pub link: String,
+    pub post_info: PostInfo,
+    pub comments: Vec<CommentInfo>,
+}
+
+#[derive(Template)]
+#[template(path = "comments.atom.xml")]
+pub struct CommentsAtom {
+    pub config: SiteConfig,
+    pub customization: Customization,
+    pub link: String,
+    pub updated: String,
+    pub post_info: PostInfo,
+    pub comments: Vec<CommentInfo>,
+}
+
 #[derive(Template)]
 #[template(path = "comments.html")]
 pub struct Comments {
@@ -668,6 +845,12 @@ pub struct Comments {
     pub is_subscribed: bool,
     pub comment_preview_text: String,
     pub comment_preview_html: String,
+    /// Set when previewing a reply, so posting it keeps track of what it's replying to.
+    pub comment_parent_id: Option<i32>,
+    /// Counting from one.
+    pub comment_page_number: usize,
+    pub comment_page_count: usize,
+    pub comment_sort: &'static str,
 	pub post_info: PostInfo,
 	pub user: User,
 	pub session: UserSession,
@@ -711,8 +894,21 @@ pub fn replace<T: ToString>(template: &str, subst: T) -> String {
     template.replace("{}", &subst.to_string()).to_owned()
 }
 
+/// Whether a comment came in since the reader last looked at the post.
+/// Someone who's never opened the post doesn't get every comment marked.
+pub fn is_new_comment(comment_id: &i32, readpoint: &Option<i32>) -> bool {
+    readpoint.map_or(false, |readpoint| *comment_id > readpoint)
+}
+
+/// Where `#comments` should jump to: the first new comment in the order they're shown,
+/// which isn't always the oldest one once replies are threaded.
+pub fn first_new_comment_id(comments: &[CommentInfo], readpoint: &Option<i32>) -> Option<i32> {
+    comments.iter().map(|comment| comment.id).find(|id| is_new_comment(id, readpoint))
+}
+
 pub mod filters {
     use askama::Result as AskamaResult;
+    use chrono::NaiveDateTime;
     use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
     use lazy_static::lazy_static;
     use regex::Regex;
@@ -726,6 +922,10 @@ pub mod filters {
             Ok(String::new())
         }
     }
+    /// Atom and JSON Feed both want RFC 3339 timestamps. Everything in the database is UTC.
+    pub fn rfc3339(date: &NaiveDateTime) -> AskamaResult<String> {
+        Ok(date.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+    }
     pub fn urlencode(param: &str) -> AskamaResult<String> {
         Ok(utf8_percent_encode(&param.to_string(), NON_ALPHANUMERIC).to_string())
     }
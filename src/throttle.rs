@@ -0,0 +1,132 @@
+/*!
+Slow down password guessing.
+
+Every failed attempt is logged, and this decides whether the next attempt is allowed to happen,
+based on the failures that happened recently. The first few are free, so a typo doesn't hurt.
+After that, each failure doubles the delay before another try, and past a hard limit the key
+is locked out until the oldest failures age out of the window.
+*/
+
+use chrono::{Duration, NaiveDateTime};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Policy {
+    /// How many failures are allowed before any delay kicks in.
+    pub free_attempts: u32,
+    /// The first delay. Each failure after that doubles it.
+    pub backoff_seconds: i64,
+    /// Failures inside the window that cause a lockout. Zero means never lock out.
+    pub lockout_attempts: u32,
+    /// How long a failure counts against you.
+    pub window: Duration,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Allowed,
+    /// Too soon after the last failure.
+    Wait(Duration),
+    /// Too many failures in the window.
+    LockedOut(Duration),
+}
+
+impl Verdict {
+    pub fn is_allowed(self) -> bool {
+        self == Verdict::Allowed
+    }
+    /// Combine the verdicts for two different keys (like the account and the IP), keeping the stricter one.
+    pub fn and(self, other: Verdict) -> Verdict {
+        match (self, other) {
+            (Verdict::LockedOut(a), Verdict::LockedOut(b)) => Verdict::LockedOut(a.max(b)),
+            (Verdict::LockedOut(a), _) | (_, Verdict::LockedOut(a)) => Verdict::LockedOut(a),
+            (Verdict::Wait(a), Verdict::Wait(b)) => Verdict::Wait(a.max(b)),
+            (Verdict::Wait(a), _) | (_, Verdict::Wait(a)) => Verdict::Wait(a),
+            (Verdict::Allowed, Verdict::Allowed) => Verdict::Allowed,
+        }
+    }
+    /// Something to show the person who got throttled.
+    pub fn message(self) -> Option<String> {
+        fn describe(d: Duration) -> String {
+            let seconds = d.num_seconds().max(1);
+            if seconds < 60 {
+                format!("{} second{}", seconds, if seconds == 1 { "" } else { "s" })
+            } else {
+                let minutes = (seconds + 59) / 60;
+                format!("{} minute{}", minutes, if minutes == 1 { "" } else { "s" })
+            }
+        }
+        match self {
+            Verdict::Allowed => None,
+            Verdict::Wait(d) => Some(format!("Too many failed attempts; try again in {}", describe(d))),
+            Verdict::LockedOut(d) => Some(format!("Too many failed attempts; this is locked for {}", describe(d))),
+        }
+    }
+}
+
+/// Decide whether another attempt is allowed right now.
+///
+/// `failures` must be sorted oldest first. Failures outside the window are ignored.
+pub fn check(policy: &Policy, failures: &[NaiveDateTime], now: NaiveDateTime) -> Verdict {
+    let cutoff = now - policy.window;
+    let failures: Vec<NaiveDateTime> = failures.iter().copied().filter(|&f| f > cutoff).collect();
+    let count = failures.len() as u32;
+    if policy.lockout_attempts != 0 && count >= policy.lockout_attempts {
+        // The lockout lifts once enough failures fall out of the window to get back under the limit.
+        let unlocks_at = failures[(count - policy.lockout_attempts) as usize] + policy.window;
+        return Verdict::LockedOut(unlocks_at - now);
+    }
+    if count <= policy.free_attempts {
+        return Verdict::Allowed;
+    }
+    let doublings = (count - policy.free_attempts - 1).min(30);
+    let delay = Duration::seconds(policy.backoff_seconds.saturating_mul(1 << doublings)).min(policy.window);
+    let allowed_at = *failures.last().unwrap() + delay;
+    if now < allowed_at {
+        Verdict::Wait(allowed_at - now)
+    } else {
+        Verdict::Allowed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn policy() -> Policy {
+        Policy {
+            free_attempts: 3,
+            backoff_seconds: 2,
+            lockout_attempts: 6,
+            window: Duration::minutes(15),
+        }
+    }
+    fn at(seconds: i64) -> NaiveDateTime {
+        NaiveDateTime::from_timestamp(1_000_000 + seconds, 0)
+    }
+    #[test]
+    fn test_check() {
+        let checks = &[
+            (&[][..], 0, Verdict::Allowed),
+            (&[0, 1, 2][..], 3, Verdict::Allowed),
+            (&[0, 1, 2, 3][..], 3, Verdict::Wait(Duration::seconds(2))),
+            (&[0, 1, 2, 3][..], 5, Verdict::Allowed),
+            (&[0, 1, 2, 3, 10][..], 11, Verdict::Wait(Duration::seconds(3))),
+            (&[0, 1, 2, 3, 10][..], 14, Verdict::Allowed),
+            (&[0, 1, 2, 3, 10, 20][..], 21, Verdict::LockedOut(Duration::seconds(900 - 21))),
+            (&[0, 1, 2, 3, 10, 20][..], 901, Verdict::Allowed),
+            (&[0, 1, 2, 3][..], 3 + 900, Verdict::Allowed),
+        ][..];
+        for &(failures, now, expected) in checks {
+            let failures: Vec<NaiveDateTime> = failures.iter().map(|&s| at(s)).collect();
+            assert_eq!(check(&policy(), &failures, at(now)), expected);
+        }
+    }
+    #[test]
+    fn test_and() {
+        let wait = Verdict::Wait(Duration::seconds(5));
+        let locked = Verdict::LockedOut(Duration::seconds(1));
+        assert_eq!(Verdict::Allowed.and(Verdict::Allowed), Verdict::Allowed);
+        assert_eq!(Verdict::Allowed.and(wait), wait);
+        assert_eq!(wait.and(locked), locked);
+        assert_eq!(locked.and(Verdict::Allowed), locked);
+    }
+}
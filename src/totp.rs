@@ -0,0 +1,165 @@
+/*!
+Time-based one-time passwords, as described in RFC 6238.
+*/
+
+use ring::digest::{digest, SHA256};
+use ring::hmac;
+use ring::rand::{self, SecureRandom};
+
+/// These are the same parameters that every authenticator app uses by default:
+/// HMAC-SHA1, six digits, and a thirty second time step.
+/// Don't change them, or existing enrollments will stop working.
+const STEP: u64 = 30;
+const DIGITS: u32 = 6;
+const SECRET_LEN: usize = 20;
+const RECOVERY_CODE_LEN: usize = 16;
+
+/// Authenticator apps expect the secret in RFC 4648 base32,
+/// which isn't the same alphabet as `more_interesting_base32`.
+const RFC4648_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Recovery codes get typed in by hand, so they leave out characters that look alike.
+const RECOVERY_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+pub fn generate_secret() -> Vec<u8> {
+    let mut secret = vec![0; SECRET_LEN];
+    rand::SystemRandom::new().fill(&mut secret[..]).unwrap();
+    secret
+}
+
+pub fn generate_recovery_code() -> String {
+    let mut bytes = [0; RECOVERY_CODE_LEN];
+    rand::SystemRandom::new().fill(&mut bytes[..]).unwrap();
+    bytes.iter().map(|&b| RECOVERY_ALPHABET[b as usize % RECOVERY_ALPHABET.len()] as char).collect()
+}
+
+/// Recovery codes are random and long enough that, unlike passwords, they don't need a slow hash.
+pub fn hash_recovery_code(code: &str) -> Vec<u8> {
+    digest(&SHA256, code.trim().to_lowercase().as_bytes()).as_ref().to_vec()
+}
+
+/// HOTP, from RFC 4226, which TOTP is built on.
+fn hotp(secret: &[u8], counter: u64) -> u32 {
+    let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+    let tag = hmac::sign(&key, &counter.to_be_bytes());
+    let hash = tag.as_ref();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = ((hash[offset] as u32 & 0x7f) << 24)
+        | ((hash[offset + 1] as u32) << 16)
+        | ((hash[offset + 2] as u32) << 8)
+        | (hash[offset + 3] as u32);
+    binary % 10u32.pow(DIGITS)
+}
+
+pub fn time_step(unix_time: u64) -> u64 {
+    unix_time / STEP
+}
+
+/// Check a code that the user typed in.
+///
+/// To put up with clock drift, the code from one step before or after is also accepted.
+/// If it passes, this returns the time step that matched,
+/// which should be stored and passed back in as `last_used_step` to prevent replays.
+pub fn verify(secret: &[u8], code: &str, unix_time: u64, last_used_step: Option<u64>) -> Option<u64> {
+    let code = code.trim();
+    if code.len() != DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let code: u32 = code.parse().ok()?;
+    let current = time_step(unix_time);
+    for step in [current.saturating_sub(1), current, current + 1] {
+        if last_used_step.map(|last| step <= last).unwrap_or(false) {
+            continue;
+        }
+        if ring::constant_time::verify_slices_are_equal(&hotp(secret, step).to_be_bytes(), &code.to_be_bytes()).is_ok() {
+            return Some(step);
+        }
+    }
+    None
+}
+
+pub fn encode_secret(secret: &[u8]) -> String {
+    let mut result = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &byte in secret {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(RFC4648_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        result.push(RFC4648_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    result
+}
+
+/// The URL that authenticator apps use to enroll, usually shown as a QR code.
+pub fn otpauth_url(issuer: &str, username: &str, secret: &[u8]) -> String {
+    use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+    let issuer = utf8_percent_encode(issuer, NON_ALPHANUMERIC).to_string();
+    let username = utf8_percent_encode(username, NON_ALPHANUMERIC).to_string();
+    format!(
+        "otpauth://totp/{issuer}:{username}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={digits}&period={period}",
+        issuer = issuer,
+        username = username,
+        secret = encode_secret(secret),
+        digits = DIGITS,
+        period = STEP,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+    #[test]
+    fn test_rfc6238_vectors() {
+        let checks = &[
+            (59, 287082),
+            (1111111109, 81804),
+            (1111111111, 50471),
+            (1234567890, 5924),
+            (2000000000, 279037),
+            (20000000000, 353130),
+        ][..];
+        for &(time, expected) in checks {
+            assert_eq!(hotp(RFC_SECRET, time_step(time)), expected);
+        }
+    }
+    #[test]
+    fn test_verify() {
+        assert_eq!(verify(RFC_SECRET, "287082", 59, None), Some(1));
+        assert_eq!(verify(RFC_SECRET, " 287082 ", 59, None), Some(1));
+        assert_eq!(verify(RFC_SECRET, "287082", 89, None), Some(1));
+        assert_eq!(verify(RFC_SECRET, "287082", 59, Some(1)), None);
+        assert_eq!(verify(RFC_SECRET, "287083", 59, None), None);
+        assert_eq!(verify(RFC_SECRET, "28708", 59, None), None);
+        assert_eq!(verify(RFC_SECRET, "", 59, None), None);
+    }
+    #[test]
+    fn test_recovery_code() {
+        let code = generate_recovery_code();
+        assert_eq!(code.len(), RECOVERY_CODE_LEN);
+        assert!(code.bytes().all(|b| RECOVERY_ALPHABET.contains(&b)));
+        assert_eq!(hash_recovery_code(&code), hash_recovery_code(&format!(" {} ", code.to_uppercase())));
+        assert_ne!(hash_recovery_code(&code), hash_recovery_code(&generate_recovery_code()));
+    }
+    #[test]
+    fn test_encode_secret() {
+        let checks = &[
+            ("", ""),
+            ("f", "MY"),
+            ("fo", "MZXQ"),
+            ("foo", "MZXW6"),
+            ("foob", "MZXW6YQ"),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI"),
+        ][..];
+        for &(input, expected) in checks {
+            assert_eq!(encode_secret(input.as_bytes()), expected);
+        }
+    }
+}
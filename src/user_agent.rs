@@ -0,0 +1,62 @@
+/*!
+Turn a User-Agent header into something a person would recognize on the session list.
+
+This is deliberately crude. It only needs to be good enough for someone to tell
+"my phone" apart from "my laptop", so it doesn't try to report versions.
+*/
+
+/// Order matters here: Edge and Opera both claim to be Chrome, and Chrome claims to be Safari.
+const BROWSERS: &[(&str, &str)] = &[
+    ("Edg/", "Edge"),
+    ("OPR/", "Opera"),
+    ("Firefox/", "Firefox"),
+    ("FxiOS/", "Firefox"),
+    ("CriOS/", "Chrome"),
+    ("Chrome/", "Chrome"),
+    ("Safari/", "Safari"),
+    ("curl/", "curl"),
+];
+
+/// Same here: Android claims to be Linux, and iOS claims to be macOS.
+const PLATFORMS: &[(&str, &str)] = &[
+    ("Android", "Android"),
+    ("iPhone", "iOS"),
+    ("iPad", "iOS"),
+    ("Windows", "Windows"),
+    ("CrOS", "ChromeOS"),
+    ("Mac OS X", "macOS"),
+    ("Macintosh", "macOS"),
+    ("Linux", "Linux"),
+];
+
+pub fn describe(user_agent: &str) -> String {
+    let browser = BROWSERS.iter().find(|&&(needle, _)| user_agent.contains(needle)).map(|&(_, name)| name);
+    let platform = PLATFORMS.iter().find(|&&(needle, _)| user_agent.contains(needle)).map(|&(_, name)| name);
+    match (browser, platform) {
+        (Some(browser), Some(platform)) => format!("{} on {}", browser, platform),
+        (Some(browser), None) => browser.to_owned(),
+        (None, Some(platform)) => format!("Unknown browser on {}", platform),
+        (None, None) => String::from("Unknown browser"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_describe() {
+        let checks = &[
+            ("Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:109.0) Gecko/20100101 Firefox/115.0", "Firefox on Windows"),
+            ("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.5 Safari/605.1.15", "Safari on macOS"),
+            ("Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Mobile Safari/537.36", "Chrome on Android"),
+            ("Mozilla/5.0 (iPhone; CPU iPhone OS 16_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/114.0.5735.124 Mobile/15E148 Safari/604.1", "Chrome on iOS"),
+            ("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36 Edg/114.0.1823.67", "Edge on Windows"),
+            ("Mozilla/5.0 (X11; Linux x86_64; rv:109.0) Gecko/20100101 Firefox/115.0", "Firefox on Linux"),
+            ("curl/7.88.1", "curl"),
+            ("", "Unknown browser"),
+        ][..];
+        for &(input, expected) in checks {
+            assert_eq!(describe(input), expected);
+        }
+    }
+}